@@ -5,6 +5,7 @@ use axum::{
     response::{IntoResponse, Response},
     BoxError, Json,
 };
+use jito_distributor_sdk::SdkError;
 use jito_merkle_tree::error::MerkleTreeError;
 use serde_derive::{Deserialize, Serialize};
 use serde_json::json;
@@ -36,6 +37,9 @@ pub enum ApiError {
     #[error("Merkle Distributor Error")]
     MerkleDistributorError(String),
 
+    #[error("Sdk Error: {0}")]
+    SdkError(#[from] SdkError),
+
     #[error("Internal Error")]
     InternalError,
 }
@@ -77,6 +81,10 @@ impl IntoResponse for ApiError {
                 error!("Merkle Distributor error: {e}");
                 (StatusCode::INTERNAL_SERVER_ERROR, "Internal Server Error")
             }
+            ApiError::SdkError(e) => {
+                error!("Sdk error: {e}");
+                (StatusCode::INTERNAL_SERVER_ERROR, "Internal Server Error")
+            }
             ApiError::InternalError => (StatusCode::INTERNAL_SERVER_ERROR, "Internal Server Error"),
         };
         (
@@ -4,7 +4,8 @@ mod router;
 use std::{fmt::Debug, net::SocketAddr, path::PathBuf, str::FromStr, sync::Arc};
 
 use clap::Parser;
-use jito_merkle_tree::{airdrop_merkle_tree::AirdropMerkleTree, utils::get_merkle_distributor_pda};
+use jito_distributor_sdk::pda::get_merkle_distributor_pda;
+use jito_merkle_tree::airdrop_merkle_tree::AirdropMerkleTree;
 use router::RouterState;
 use solana_program::pubkey::Pubkey;
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
@@ -67,11 +68,21 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
 
     info!("distributor: {:?}", distributor);
 
+    // the tree is served by claimant, multi-mint trees have a leaf per claimant and mint
+    if distributor.multi_mint {
+        return Err(ApiError::MerkleDistributorError(
+            "multi-mint distributors are not supported".to_string(),
+        )
+        .into());
+    }
+
     let state = Arc::new(RouterState {
         tree: AirdropMerkleTree::new_from_file(&args.merkle_tree_path)?.convert_to_hashmap(),
         program_id: args.program_id,
         distributor_pubkey: merkle_distributor,
         rpc_client,
+        cumulative: distributor.cumulative,
+        claim_fee: distributor.claim_fee,
     });
 
     let app = router::get_routes(state, args.enable_proof_endpoint);
@@ -6,7 +6,6 @@ use std::{
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
-use anchor_lang::AccountDeserialize;
 use axum::{
     body::Body,
     error_handling::HandleErrorLayer,
@@ -16,10 +15,21 @@ use axum::{
     Json, Router,
 };
 use http::Request;
-use jito_merkle_tree::{tree_node::TreeNode, utils::get_claim_status_pda};
+use jito_distributor_sdk::{
+    accounts::{deserialize_account, deserialize_claim_bitmap, nonblocking::fetch_distributor},
+    pda::{
+        get_claim_bitmap_pda, get_claim_status_pda, get_closed_claims_pda,
+        get_cumulative_claim_status_pda,
+    },
+    SdkError,
+};
+use jito_merkle_tree::tree_node::TreeNode;
 use merkle_distributor::state::{
-    claim_status::ClaimStatus as MerkleDistributorClaimStatus,
-    merkle_distributor::MerkleDistributor,
+    asset_kind::AssetKind as MerkleDistributorAssetKind, claim_bitmap::ClaimBitmap,
+    claim_fee::ClaimFee as MerkleDistributorClaimFee,
+    claim_status::ClaimStatus as MerkleDistributorClaimStatus, closed_claims::ClosedClaims,
+    cumulative_claim_status::CumulativeClaimStatus, merkle_distributor::MerkleDistributor,
+    vesting_schedule::VestingSchedule as MerkleDistributorVestingSchedule,
 };
 use serde_derive::{Deserialize, Serialize};
 use solana_program::pubkey::Pubkey;
@@ -41,6 +51,10 @@ pub struct RouterState {
     pub program_id: Pubkey,
     pub rpc_client: RpcClient,
     pub tree: HashMap<Pubkey, TreeNode>,
+    /// Whether the distributor is cumulative, claims are then tracked in cumulative claim statuses
+    pub cumulative: bool,
+    /// Fee charged by new claims, fixed when the distributor is created
+    pub claim_fee: MerkleDistributorClaimFee,
 }
 
 impl Debug for RouterState {
@@ -108,6 +122,14 @@ async fn get_proof(
             .proof
             .to_owned()
             .ok_or(ApiError::ProofNotFound(user_pubkey.to_string()))?,
+        start_ts: node.start_ts,
+        end_ts: node.end_ts,
+        index: node.index,
+        claim_fee_lamports: state.claim_fee.lamports(),
+        claim_fee_amount: state
+            .claim_fee
+            .token_amount(node.amount_unlocked())
+            .map_err(|e| ApiError::MerkleDistributorError(e.to_string()))?,
     };
 
     Ok(Json(proof))
@@ -120,12 +142,28 @@ async fn get_claim_status(
     node: &TreeNode,
     user_pubkey: &Pubkey,
 ) -> Result<ClaimStatus> {
+    if let Some(index) = node.index {
+        return get_indexed_claim_status(state, node, index).await;
+    }
+    if state.cumulative {
+        return get_cumulative_claim_status(state, node, user_pubkey).await;
+    }
+
     let (claim_status_pda, _bump) =
         get_claim_status_pda(&state.program_id, user_pubkey, &state.distributor_pubkey);
+    let (closed_claims_pda, _bump) = get_closed_claims_pda(
+        &state.program_id,
+        &state.distributor_pubkey,
+        ClosedClaims::chunk_index_of(user_pubkey),
+    );
 
     let mut accounts = state
         .rpc_client
-        .get_multiple_accounts(&[claim_status_pda, state.distributor_pubkey])
+        .get_multiple_accounts(&[
+            claim_status_pda,
+            closed_claims_pda,
+            state.distributor_pubkey,
+        ])
         .await?;
 
     // Note: this method will return an error if the distributor isn't on-chain, even if the TreeNode exists.
@@ -135,19 +173,26 @@ async fn get_claim_status(
         .pop()
         .ok_or_else(|| ApiError::InternalError)?
         .ok_or_else(|| ApiError::MerkleDistributorError("distributor not found".into()))?;
-    let distributor = MerkleDistributor::try_deserialize(&mut distributor_account.data.as_slice())
-        .map_err(|e| {
+    let distributor: MerkleDistributor =
+        deserialize_account(&state.distributor_pubkey, &distributor_account.data).map_err(|e| {
             warn!("error deserializing MerkleDistributor: {:?}", e);
             ApiError::MerkleDistributorError("Error parsing MerkleDistributor".into())
         })?;
 
+    // the chunk doesn't exist until one of its claimants closes their claim
+    let closed_claims_account = accounts.pop().ok_or_else(|| ApiError::InternalError)?;
+    let claim_closed = closed_claims_account
+        .map(|account| ClosedClaims::is_claim_closed(&account.data, user_pubkey))
+        .unwrap_or_default();
+
     let claim_status_account = accounts.pop().ok_or_else(|| ApiError::InternalError)?;
 
     if distributor.clawed_back {
         match claim_status_account {
             Some(claim_status_account) => {
-                match MerkleDistributorClaimStatus::try_deserialize(
-                    &mut claim_status_account.data.as_slice(),
+                match deserialize_account::<MerkleDistributorClaimStatus>(
+                    &claim_status_pda,
+                    &claim_status_account.data,
                 ) {
                     // claimed some, but its expired now, so no more locked funds can be withdrawn
                     Ok(claim_status) => Ok(ClaimStatus {
@@ -195,8 +240,9 @@ async fn get_claim_status(
     } else {
         match claim_status_account {
             Some(claim_status_account) => {
-                match MerkleDistributorClaimStatus::try_deserialize(
-                    &mut claim_status_account.data.as_slice(),
+                match deserialize_account::<MerkleDistributorClaimStatus>(
+                    &claim_status_pda,
+                    &claim_status_account.data,
                 ) {
                     // claimed, but might still have some locked tokens
                     Ok(claim_status) => Ok(ClaimStatus {
@@ -207,16 +253,21 @@ async fn get_claim_status(
                         total_locked_searcher: node.total_locked_searcher,
                         total_unlocked_validator: node.total_unlocked_validator,
                         total_locked_validator: node.total_locked_validator,
-                        amount_locked_withdrawable: claim_status
-                            .amount_withdrawable(
-                                SystemTime::now()
-                                    .duration_since(UNIX_EPOCH)
-                                    .unwrap()
-                                    .as_secs() as i64,
-                                distributor.start_ts,
-                                distributor.end_ts,
-                            )
-                            .unwrap(),
+                        amount_locked_withdrawable: {
+                            let (start_ts, end_ts) =
+                                claim_status.lockup(distributor.start_ts, distributor.end_ts);
+                            claim_status
+                                .amount_withdrawable(
+                                    SystemTime::now()
+                                        .duration_since(UNIX_EPOCH)
+                                        .unwrap()
+                                        .as_secs() as i64,
+                                    start_ts,
+                                    end_ts,
+                                    &distributor.vesting_schedule,
+                                )
+                                .unwrap()
+                        },
                         amount_locked_withdrawn: claim_status.locked_amount_withdrawn,
                     }),
                     // error parsing account, assume didn't claim. might have locked tokens too, so simulate the amount withdrawable
@@ -230,32 +281,56 @@ async fn get_claim_status(
                             total_locked_searcher: node.total_locked_searcher,
                             total_unlocked_validator: node.total_unlocked_validator,
                             total_locked_validator: node.total_locked_validator,
-                            amount_locked_withdrawable: MerkleDistributorClaimStatus {
-                                locked_amount: node
-                                    .total_locked_staker
-                                    .checked_add(node.total_locked_searcher)
-                                    .unwrap()
-                                    .checked_add(node.total_locked_validator)
-                                    .unwrap(),
-                                // haven't claimed yet, so none withdrawn
-                                locked_amount_withdrawn: 0,
-                                // the rest of the fields don't matter
-                                ..MerkleDistributorClaimStatus::default()
-                            }
-                            .amount_withdrawable(
-                                SystemTime::now()
-                                    .duration_since(UNIX_EPOCH)
-                                    .unwrap()
-                                    .as_secs() as i64,
-                                distributor.start_ts,
-                                distributor.end_ts,
-                            )
-                            .unwrap(),
+                            amount_locked_withdrawable: distributor
+                                .vesting_schedule
+                                .unlocked_amount(
+                                    // haven't claimed yet, so none withdrawn
+                                    node.amount_locked(),
+                                    SystemTime::now()
+                                        .duration_since(UNIX_EPOCH)
+                                        .unwrap()
+                                        .as_secs() as i64,
+                                    node.start_ts.unwrap_or(distributor.start_ts),
+                                    node.end_ts.unwrap_or(distributor.end_ts),
+                                )
+                                .unwrap(),
                             amount_locked_withdrawn: 0, /* never withdrew any because account doesn't exist */
                         })
                     }
                 }
             }
+            // claimed everything and closed the claim status account to reclaim rent
+            None if claim_closed => Ok(ClaimStatus {
+                status: Status::Claimed,
+                total_unlocked_staker: node.total_unlocked_staker,
+                total_locked_staker: node.total_locked_staker,
+                total_unlocked_searcher: node.total_unlocked_searcher,
+                total_locked_searcher: node.total_locked_searcher,
+                total_unlocked_validator: node.total_unlocked_validator,
+                total_locked_validator: node.total_locked_validator,
+                amount_locked_withdrawable: 0,
+                amount_locked_withdrawn: node.amount_locked(),
+            }),
+            // never claimed before the claim deadline, let them know what could have been
+            None if distributor.claim_deadline_passed(
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs() as i64,
+            ) =>
+            {
+                Ok(ClaimStatus {
+                    status: Status::DeadlinePassed,
+                    total_unlocked_staker: node.total_unlocked_staker,
+                    total_locked_staker: node.total_locked_staker,
+                    total_unlocked_searcher: node.total_unlocked_searcher,
+                    total_locked_searcher: node.total_locked_searcher,
+                    total_unlocked_validator: node.total_unlocked_validator,
+                    total_locked_validator: node.total_locked_validator,
+                    amount_locked_withdrawable: 0,
+                    amount_locked_withdrawn: 0,
+                })
+            }
             None => {
                 // haven't claimed yet. might have some claimable tokens
                 Ok(ClaimStatus {
@@ -266,27 +341,19 @@ async fn get_claim_status(
                     total_locked_searcher: node.total_locked_searcher,
                     total_unlocked_validator: node.total_unlocked_validator,
                     total_locked_validator: node.total_locked_validator,
-                    amount_locked_withdrawable: MerkleDistributorClaimStatus {
-                        locked_amount: node
-                            .total_locked_staker
-                            .checked_add(node.total_locked_searcher)
-                            .unwrap()
-                            .checked_add(node.total_locked_validator)
-                            .unwrap(),
-                        // haven't claimed yet, so none withdrawn
-                        locked_amount_withdrawn: 0,
-                        // the rest of the fields don't matter
-                        ..MerkleDistributorClaimStatus::default()
-                    }
-                    .amount_withdrawable(
-                        SystemTime::now()
-                            .duration_since(UNIX_EPOCH)
-                            .unwrap()
-                            .as_secs() as i64,
-                        distributor.start_ts,
-                        distributor.end_ts,
-                    )
-                    .unwrap(),
+                    amount_locked_withdrawable: distributor
+                        .vesting_schedule
+                        .unlocked_amount(
+                            // haven't claimed yet, so none withdrawn
+                            node.amount_locked(),
+                            SystemTime::now()
+                                .duration_since(UNIX_EPOCH)
+                                .unwrap()
+                                .as_secs() as i64,
+                            node.start_ts.unwrap_or(distributor.start_ts),
+                            node.end_ts.unwrap_or(distributor.end_ts),
+                        )
+                        .unwrap(),
                     amount_locked_withdrawn: 0, // never withdrew any because account doesn't exist
                 })
             }
@@ -294,28 +361,161 @@ async fn get_claim_status(
     }
 }
 
+/// Get the claim status of an indexed leaf from its claim bitmap, Unclaimed if the bit isn't set
+#[instrument(skip(state, node), ret, err)]
+async fn get_indexed_claim_status(
+    state: &RouterState,
+    node: &TreeNode,
+    index: u64,
+) -> Result<ClaimStatus> {
+    let (claim_bitmap_pda, _bump) = get_claim_bitmap_pda(
+        &state.program_id,
+        &state.distributor_pubkey,
+        ClaimBitmap::chunk_index_of(index),
+    );
+
+    let mut accounts = state
+        .rpc_client
+        .get_multiple_accounts(&[claim_bitmap_pda, state.distributor_pubkey])
+        .await?;
+
+    let distributor_account = accounts
+        .pop()
+        .ok_or_else(|| ApiError::InternalError)?
+        .ok_or_else(|| ApiError::MerkleDistributorError("distributor not found".into()))?;
+    let distributor: MerkleDistributor =
+        deserialize_account(&state.distributor_pubkey, &distributor_account.data).map_err(|e| {
+            warn!("error deserializing MerkleDistributor: {:?}", e);
+            ApiError::MerkleDistributorError("Error parsing MerkleDistributor".into())
+        })?;
+
+    // a missing or unreadable bitmap can't have the leaf marked as claimed
+    let claimed = match accounts.pop().ok_or_else(|| ApiError::InternalError)? {
+        Some(claim_bitmap_account) => {
+            match deserialize_claim_bitmap(&claim_bitmap_pda, &claim_bitmap_account.data) {
+                Ok(claim_bitmap) => claim_bitmap.is_claimed(index),
+                Err(e) => {
+                    warn!("error reading ClaimBitmap: {:?}", e);
+                    false
+                }
+            }
+        }
+        None => false,
+    };
+
+    let status = if claimed {
+        Status::Claimed
+    } else if distributor.clawed_back {
+        Status::Expired
+    } else if distributor.claim_deadline_passed(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64,
+    ) {
+        Status::DeadlinePassed
+    } else {
+        Status::Unclaimed
+    };
+
+    // indexed leaves have no locked tokens
+    Ok(ClaimStatus {
+        status,
+        total_unlocked_staker: node.total_unlocked_staker,
+        total_locked_staker: node.total_locked_staker,
+        total_unlocked_searcher: node.total_unlocked_searcher,
+        total_locked_searcher: node.total_locked_searcher,
+        total_unlocked_validator: node.total_unlocked_validator,
+        total_locked_validator: node.total_locked_validator,
+        amount_locked_withdrawable: 0,
+        amount_locked_withdrawn: 0,
+    })
+}
+
+/// Get the claim status of a cumulative leaf. Claimed once everything in the current root has been paid,
+/// Unclaimed while some of it can still be claimed
+#[instrument(skip(state, node), ret, err)]
+async fn get_cumulative_claim_status(
+    state: &RouterState,
+    node: &TreeNode,
+    user_pubkey: &Pubkey,
+) -> Result<ClaimStatus> {
+    let (claim_status_pda, _bump) =
+        get_cumulative_claim_status_pda(&state.program_id, user_pubkey, &state.distributor_pubkey);
+
+    let mut accounts = state
+        .rpc_client
+        .get_multiple_accounts(&[claim_status_pda, state.distributor_pubkey])
+        .await?;
+
+    let distributor_account = accounts
+        .pop()
+        .ok_or_else(|| ApiError::InternalError)?
+        .ok_or_else(|| ApiError::MerkleDistributorError("distributor not found".into()))?;
+    let distributor: MerkleDistributor =
+        deserialize_account(&state.distributor_pubkey, &distributor_account.data).map_err(|e| {
+            warn!("error deserializing MerkleDistributor: {:?}", e);
+            ApiError::MerkleDistributorError("Error parsing MerkleDistributor".into())
+        })?;
+
+    // a missing or unreadable claim status hasn't been paid anything
+    let amount_claimed = match accounts.pop().ok_or_else(|| ApiError::InternalError)? {
+        Some(claim_status_account) => {
+            match deserialize_account::<CumulativeClaimStatus>(
+                &claim_status_pda,
+                &claim_status_account.data,
+            ) {
+                Ok(claim_status) => claim_status.amount_claimed,
+                Err(e) => {
+                    warn!("error reading CumulativeClaimStatus: {:?}", e);
+                    0
+                }
+            }
+        }
+        None => 0,
+    };
+
+    let status = if amount_claimed >= node.amount_unlocked() {
+        Status::Claimed
+    } else if distributor.clawed_back {
+        Status::Expired
+    } else if distributor.claim_deadline_passed(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64,
+    ) {
+        Status::DeadlinePassed
+    } else {
+        Status::Unclaimed
+    };
+
+    // cumulative leaves have no locked tokens
+    Ok(ClaimStatus {
+        status,
+        total_unlocked_staker: node.total_unlocked_staker,
+        total_locked_staker: node.total_locked_staker,
+        total_unlocked_searcher: node.total_unlocked_searcher,
+        total_locked_searcher: node.total_locked_searcher,
+        total_unlocked_validator: node.total_unlocked_validator,
+        total_locked_validator: node.total_locked_validator,
+        amount_locked_withdrawable: 0,
+        amount_locked_withdrawn: 0,
+    })
+}
+
 /// Fetch and deserialize merkle distributor information
 pub async fn read_distributor(
     rpc_client: &RpcClient,
     distributor: &Pubkey,
 ) -> Result<MerkleDistributor> {
-    return match rpc_client.get_account_data(distributor).await {
-        Ok(data) => {
-            return match MerkleDistributor::try_deserialize(&mut data.as_slice()) {
-                Ok(distributor) => Ok(distributor),
-                Err(e) => Err(ApiError::MerkleDistributorError(e.to_string())),
-            };
-        }
-        Err(e) => {
-            if e.kind.to_string().contains("AccountNotFound") {
-                Err(ApiError::MerkleDistributorError(
-                    "Merkle Distributor not found".to_string(),
-                ))
-            } else {
-                Err(ApiError::RpcError(e))
-            }
-        }
-    };
+    match fetch_distributor(rpc_client, distributor).await {
+        Ok(distributor) => Ok(distributor),
+        Err(SdkError::AccountNotFound(_)) => Err(ApiError::MerkleDistributorError(
+            "Merkle Distributor not found".to_string(),
+        )),
+        Err(e) => Err(e.into()),
+    }
 }
 
 #[instrument(ret)]
@@ -355,6 +555,8 @@ struct Distributor {
     pub start_ts: i64,
     /// Lockup time end (Unix Timestamp)
     pub end_ts: i64,
+    /// How locked tokens unlock between start_ts and end_ts
+    pub vesting_schedule: VestingSchedule,
     /// Clawback start (Unix Timestamp)
     pub clawback_start_ts: i64,
     /// Clawback receiver
@@ -363,6 +565,43 @@ struct Distributor {
     pub admin: Pubkey,
     /// Whether or not the distributor has been clawed back
     pub clawed_back: bool,
+    /// Whether anyone can claim on behalf of claimants
+    pub allow_claim_on_behalf: bool,
+    /// The admin can update the root before this time (Unix Timestamp), or before the first claim
+    pub root_lock_ts: i64,
+    /// Whether claims are paused by the admin
+    pub paused: bool,
+    /// Proposed admin wallet, default if none
+    pub pending_admin: Pubkey,
+    /// Proposed clawback receiver, default if none
+    pub pending_clawback_receiver: Pubkey,
+    /// Whether claims are tracked by leaf index in claim bitmaps
+    pub bitmap_claims: bool,
+    /// Total amount of tokens deposited with fund_distributor, net of transfer fees
+    pub total_amount_funded: u64,
+    /// Whether new claims are refused until the vault covers every remaining claim
+    pub require_full_funding: bool,
+    /// Whether leaves hold each claimant's cumulative entitlement
+    pub cumulative: bool,
+    /// New claims are refused from this time (Unix Timestamp), if any
+    pub claim_deadline_ts: Option<i64>,
+    /// Signs the attestations claimants need to claim, if any
+    pub attestation_signer: Option<Pubkey>,
+    /// Whether the distributor distributes tokens or native SOL
+    pub asset_kind: AssetKind,
+    /// Whether leaves commit to the index of one of several mints
+    pub multi_mint: bool,
+    /// Number of mints registered with a multi-mint distributor
+    pub num_mints: u8,
+    /// Fee charged when a claim is opened
+    pub claim_fee: ClaimFee,
+    /// Wallet collecting the claim fee, default if there is no claim fee
+    pub fee_receiver: Pubkey,
+    /// Seconds between the admin proposing and executing the recovery of a claim, if the admin
+    /// can recover claims
+    pub claim_recovery_delay: Option<i64>,
+    /// Whether the admin can revoke the unvested locked tokens of claims
+    pub revocable: bool,
 }
 
 async fn get_distributor(State(state): State<Arc<RouterState>>) -> Result<Json<Distributor>> {
@@ -380,10 +619,29 @@ async fn get_distributor(State(state): State<Arc<RouterState>>) -> Result<Json<D
         num_nodes_claimed: d.num_nodes_claimed,
         start_ts: d.start_ts,
         end_ts: d.end_ts,
+        vesting_schedule: d.vesting_schedule.into(),
         clawback_start_ts: d.clawback_start_ts,
         clawback_receiver: d.clawback_receiver,
         admin: d.admin,
         clawed_back: d.clawed_back,
+        allow_claim_on_behalf: d.allow_claim_on_behalf,
+        root_lock_ts: d.root_lock_ts,
+        paused: d.paused,
+        pending_admin: d.pending_admin,
+        pending_clawback_receiver: d.pending_clawback_receiver,
+        bitmap_claims: d.bitmap_claims,
+        total_amount_funded: d.total_amount_funded,
+        require_full_funding: d.require_full_funding,
+        cumulative: d.cumulative,
+        claim_deadline_ts: d.claim_deadline_ts,
+        attestation_signer: d.attestation_signer,
+        asset_kind: d.asset_kind.into(),
+        multi_mint: d.multi_mint,
+        num_mints: d.num_mints,
+        claim_fee: d.claim_fee.into(),
+        fee_receiver: d.fee_receiver,
+        claim_recovery_delay: d.claim_recovery_delay,
+        revocable: d.revocable,
     }))
 }
 
@@ -417,18 +675,91 @@ async fn root() -> impl IntoResponse {
     "Jito Airdrop API"
 }
 
+/// Mirrors [merkle_distributor::state::vesting_schedule::VestingSchedule]
+#[derive(Serialize, Deserialize, Debug)]
+enum VestingSchedule {
+    Linear,
+    Step {
+        period: i64,
+    },
+    CliffLinear {
+        cliff_offset: i64,
+        cliff_unlock_bps: u16,
+    },
+}
+
+impl From<MerkleDistributorVestingSchedule> for VestingSchedule {
+    fn from(vesting_schedule: MerkleDistributorVestingSchedule) -> Self {
+        match vesting_schedule {
+            MerkleDistributorVestingSchedule::Linear => VestingSchedule::Linear,
+            MerkleDistributorVestingSchedule::Step { period } => VestingSchedule::Step { period },
+            MerkleDistributorVestingSchedule::CliffLinear {
+                cliff_offset,
+                cliff_unlock_bps,
+            } => VestingSchedule::CliffLinear {
+                cliff_offset,
+                cliff_unlock_bps,
+            },
+        }
+    }
+}
+
+/// Mirrors [merkle_distributor::state::asset_kind::AssetKind]
+#[derive(Serialize, Deserialize, Debug)]
+enum AssetKind {
+    Token,
+    NativeSol,
+}
+
+impl From<MerkleDistributorAssetKind> for AssetKind {
+    fn from(asset_kind: MerkleDistributorAssetKind) -> Self {
+        match asset_kind {
+            MerkleDistributorAssetKind::Token => AssetKind::Token,
+            MerkleDistributorAssetKind::NativeSol => AssetKind::NativeSol,
+        }
+    }
+}
+
+/// Mirrors [merkle_distributor::state::claim_fee::ClaimFee]
+#[derive(Serialize, Deserialize, Debug)]
+enum ClaimFee {
+    None,
+    Lamports { amount: u64 },
+    Bps { bps: u16 },
+}
+
+impl From<MerkleDistributorClaimFee> for ClaimFee {
+    fn from(claim_fee: MerkleDistributorClaimFee) -> Self {
+        match claim_fee {
+            MerkleDistributorClaimFee::None => ClaimFee::None,
+            MerkleDistributorClaimFee::Lamports { amount } => ClaimFee::Lamports { amount },
+            MerkleDistributorClaimFee::Bps { bps } => ClaimFee::Bps { bps },
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 struct Proof {
     pub amount_locked: u64,
     pub amount_unlocked: u64,
     pub proof: Vec<[u8; 32]>,
+    /// Lockup committed in the leaf, passed to new_claim. None if the distributor's lockup is used
+    pub start_ts: Option<i64>,
+    pub end_ts: Option<i64>,
+    /// Leaf index passed to new_claim_indexed. None unless the distributor uses bitmap claims
+    pub index: Option<u64>,
+    /// Lamports new_claim charges whoever sends it, paid to the fee receiver
+    pub claim_fee_lamports: u64,
+    /// Tokens new_claim withholds from amount_unlocked, paid to the fee receiver
+    pub claim_fee_amount: u64,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 enum Status {
-    Unclaimed, // User has not yet claimed any tokens
-    Claimed,   // User already claimed unlocked tokens
-    Expired,   // claim period has expired
+    Unclaimed,      // User has not yet claimed any tokens
+    Claimed,        // User already claimed unlocked tokens
+    Expired,        // claim period has expired
+    DeadlinePassed, // User did not claim before the claim deadline, claimed tokens keep vesting
 }
 
 #[derive(Serialize, Deserialize, Debug)]
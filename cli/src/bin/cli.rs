@@ -3,25 +3,44 @@ extern crate merkle_distributor;
 
 use std::path::PathBuf;
 
-use anchor_lang::{prelude::Pubkey, AccountDeserialize, InstructionData, Key, ToAccountMetas};
-use anchor_spl::token;
+use anchor_lang::{prelude::Pubkey, AccountDeserialize, Key};
 use clap::{Parser, Subcommand};
-use jito_merkle_tree::{
-    airdrop_merkle_tree::AirdropMerkleTree,
-    utils::{get_claim_status_pda, get_merkle_distributor_pda},
+use jito_distributor_sdk::{
+    accounts::{
+        deserialize_account, fetch_claim_bitmap, fetch_claim_recovery, fetch_claim_status,
+        fetch_cumulative_claim_status, fetch_distributor, fetch_distributor_mint,
+        fetch_token_program,
+    },
+    pda::{get_merkle_distributor_pda, get_token_vault},
+    DistributorInstructions, NewDistributorParams,
+};
+use jito_merkle_tree::{airdrop_merkle_tree::AirdropMerkleTree, tree_node::TreeNode};
+use merkle_distributor::{
+    state::{
+        claim_bitmap::ClaimBitmap, claim_fee::ClaimFee, claim_status::ClaimStatus,
+        closed_claims::ClosedClaims, merkle_distributor::MerkleDistributor, sol_vault::SolVault,
+        vesting_schedule::VestingSchedule,
+    },
+    utils::attestation_message,
 };
-use merkle_distributor::state::merkle_distributor::MerkleDistributor;
 use solana_program::instruction::Instruction;
 use solana_rpc_client::rpc_client::RpcClient;
 use solana_sdk::{
-    account::Account, commitment_config::CommitmentConfig,
-    compute_budget::ComputeBudgetInstruction, signature::read_keypair_file, signer::Signer,
+    account::Account,
+    commitment_config::CommitmentConfig,
+    compute_budget::ComputeBudgetInstruction,
+    signature::{read_keypair_file, Keypair, Signature},
+    signer::Signer,
     transaction::Transaction,
 };
 use spl_associated_token_account::{
-    get_associated_token_address, instruction::create_associated_token_account,
+    get_associated_token_address_with_program_id,
+    instruction::{create_associated_token_account, create_associated_token_account_idempotent},
 };
 
+/// Number of closed claims chunks closed per transaction when closing a distributor.
+const CLOSE_CLOSED_CLAIMS_BATCH_SIZE: usize = 16;
+
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
 pub struct Args {
@@ -32,7 +51,7 @@ pub struct Args {
     #[clap(long, env, default_value_t = 0)]
     pub airdrop_version: u64,
 
-    /// SPL Mint address
+    /// SPL Mint address, 11111111111111111111111111111111 (the default pubkey) for native SOL
     #[clap(long, env)]
     pub mint: Pubkey,
 
@@ -58,14 +77,44 @@ pub struct Args {
 pub enum Commands {
     /// Claim unlocked tokens
     Claim(ClaimArgs),
+    /// Sign an attestation letting a claimant claim, as the distributor's attestation signer
+    SignAttestation(SignAttestationArgs),
+    /// Claim tokens on behalf of every claimant in the merkle tree, sending them to their ATAs
+    ClaimOnBehalf(ClaimOnBehalfArgs),
     /// Create a new instance of a merkle distributor
     NewDistributor(NewDistributorArgs),
+    /// Transfer tokens from the admin's token account into the distributor vault
+    FundDistributor(FundDistributorArgs),
+    /// Register the next mint of a multi-mint distributor, creating its vault
+    AddMint(AddMintArgs),
     /// Clawback tokens from merkle distributor
     #[clap(hide = true)]
     Clawback(ClawbackArgs),
+    /// Send the tokens in the vault beyond what can still be claimed to the clawback receiver
+    ClawbackSurplus,
+    /// Revoke the unvested locked tokens of a claimant, sending them to the clawback receiver
+    Revoke(RevokeArgs),
+    /// Move your claim, and its remaining vesting, to a new claimant
+    TransferClaim(TransferClaimArgs),
+    /// Recover the claim of a claimant to a new claimant, in two steps separated by the
+    /// distributor's claim recovery delay
+    RecoverClaim(RecoverClaimArgs),
+    /// Close the distributor and its token vault after clawback, reclaiming their rent
+    CloseDistributor(CloseDistributorArgs),
     /// Create a Merkle tree, given a CSV of recipients
     CreateMerkleTree(CreateMerkleTreeArgs),
+    /// Transfer the distributor admin, in two steps
     SetAdmin(SetAdminArgs),
+    /// Replace the merkle root of the distributor, given a new merkle tree
+    UpdateRoot(UpdateRootArgs),
+    /// Push the clawback start later or, before vesting starts, move the lockup
+    UpdateTimestamps(UpdateTimestampsArgs),
+    /// Pause claims from the distributor
+    Pause,
+    /// Resume claims from a paused distributor
+    Unpause,
+    /// Grow a distributor created by an earlier version of the program so it can be used again
+    MigrateDistributor,
 }
 
 // NewClaim and Claim subcommand args
@@ -74,12 +123,48 @@ pub struct ClaimArgs {
     /// Merkle distributor path
     #[clap(long, env)]
     pub merkle_tree_path: PathBuf,
+
+    /// Token account to send the claimed tokens to, can be owned by anyone.
+    /// Defaults to the claimant's ATA
+    #[clap(long, env)]
+    pub recipient_token_account: Option<Pubkey>,
+
+    /// Signature of the claimant's attestation by the distributor's attestation signer,
+    /// see sign-attestation. Required to claim from distributors with an attestation signer
+    #[clap(long, env, requires = "attestation_expiry_ts")]
+    pub attestation_signature: Option<Signature>,
+
+    /// Expiry of the attestation (unix timestamp)
+    #[clap(long, env, requires = "attestation_signature")]
+    pub attestation_expiry_ts: Option<i64>,
+}
+
+#[derive(Parser, Debug)]
+pub struct SignAttestationArgs {
+    /// Claimant allowed to claim
+    #[clap(long, env)]
+    pub claimant: Pubkey,
+
+    /// The attestation can't be used to claim from this time (unix timestamp)
+    #[clap(long, env)]
+    pub expiry_ts: i64,
+}
+
+#[derive(Parser, Debug)]
+pub struct ClaimOnBehalfArgs {
+    /// Merkle distributor path
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+
+    /// Number of claimants to claim for in a single transaction
+    #[clap(long, env, default_value_t = 4)]
+    pub batch_size: usize,
 }
 
 // NewDistributor subcommand args
 #[derive(Parser, Debug)]
 pub struct NewDistributorArgs {
-    /// Clawback receiver token account
+    /// Clawback receiver token account, or wallet for native SOL distributors
     #[clap(long, env)]
     pub clawback_receiver_token_account: Pubkey,
 
@@ -98,6 +183,103 @@ pub struct NewDistributorArgs {
     /// When to make the clawback period start. Must be at least a day after the end_vesting_ts
     #[clap(long, env)]
     pub clawback_start_ts: i64,
+
+    /// Optional step unlock period in seconds, tokens unlock in equal tranches every period
+    #[clap(long, env, conflicts_with = "cliff_offset")]
+    pub step_period: Option<i64>,
+
+    /// Optional lockup cliff, in seconds after the start of each lockup. Nothing unlocks before it
+    #[clap(long, env)]
+    pub cliff_offset: Option<i64>,
+
+    /// Portion of the locked tokens unlocked at the cliff, in basis points
+    #[clap(long, env, default_value_t = 0, requires = "cliff_offset")]
+    pub cliff_unlock_bps: u16,
+
+    /// Allow anyone to claim on behalf of claimants, sending tokens to their ATAs
+    #[clap(long, env)]
+    pub allow_claim_on_behalf: bool,
+
+    /// The admin can update the merkle root before this time (unix timestamp),
+    /// or at any time before the first claim
+    #[clap(long, env, default_value_t = 0)]
+    pub root_lock_ts: i64,
+
+    /// Refuse new claims until the vault holds every token that can still be claimed
+    #[clap(long, env)]
+    pub require_full_funding: bool,
+
+    /// Optional claim deadline (unix timestamp), new claims are refused from then on.
+    /// Claims opened before it keep vesting until clawback
+    #[clap(long, env)]
+    pub claim_deadline_ts: Option<i64>,
+
+    /// Optional attestation signer, claimants then need an unexpired attestation signed by it to claim
+    #[clap(long, env)]
+    pub attestation_signer: Option<Pubkey>,
+
+    /// Optional claim fee in lamports, paid by whoever sends a new claim to the fee receiver
+    #[clap(long, env, conflicts_with = "claim_fee_bps", requires = "fee_receiver")]
+    pub claim_fee_lamports: Option<u64>,
+
+    /// Optional claim fee in basis points of the unlocked tokens of a new claim, withheld from the
+    /// claimant and sent to the fee receiver's ATA
+    #[clap(long, env, requires = "fee_receiver")]
+    pub claim_fee_bps: Option<u16>,
+
+    /// Wallet collecting the claim fee
+    #[clap(long, env)]
+    pub fee_receiver: Option<Pubkey>,
+
+    /// Let the admin recover claims to new claimants, executed this many seconds after being
+    /// proposed
+    #[clap(long, env)]
+    pub claim_recovery_delay: Option<i64>,
+
+    /// Let the admin revoke the unvested locked tokens of claims
+    #[clap(long, env)]
+    pub revocable: bool,
+}
+
+#[derive(Parser, Debug)]
+pub struct FundDistributorArgs {
+    /// Amount of tokens to transfer, in base units. Defaults to the amount the vault is
+    /// missing to cover every remaining claim
+    #[clap(long, env)]
+    pub amount: Option<u64>,
+}
+
+#[derive(Parser, Debug)]
+pub struct AddMintArgs {
+    /// Mint to register, takes the next mint index. Register the distributor's own mint first to
+    /// use its vault as mint 0
+    #[clap(long, env)]
+    pub distributor_mint: Pubkey,
+
+    /// Maximum number of tokens of the mint that can ever be claimed, in base units
+    #[clap(long, env)]
+    pub max_total_claim: u64,
+}
+
+impl NewDistributorArgs {
+    fn vesting_schedule(&self) -> VestingSchedule {
+        match (self.step_period, self.cliff_offset) {
+            (Some(period), _) => VestingSchedule::Step { period },
+            (None, Some(cliff_offset)) => VestingSchedule::CliffLinear {
+                cliff_offset,
+                cliff_unlock_bps: self.cliff_unlock_bps,
+            },
+            (None, None) => VestingSchedule::Linear,
+        }
+    }
+
+    fn claim_fee(&self) -> ClaimFee {
+        match (self.claim_fee_lamports, self.claim_fee_bps) {
+            (Some(amount), _) => ClaimFee::Lamports { amount },
+            (None, Some(bps)) => ClaimFee::Bps { bps },
+            (None, None) => ClaimFee::None,
+        }
+    }
 }
 
 #[derive(Parser, Debug)]
@@ -106,6 +288,60 @@ pub struct ClawbackArgs {
     pub clawback_keypair_path: PathBuf,
 }
 
+#[derive(Parser, Debug)]
+pub struct RevokeArgs {
+    /// Claimant whose unvested tokens are revoked
+    #[clap(long, env)]
+    pub claimant: Pubkey,
+
+    /// Merkle distributor path, used to open the claim if the claimant has not claimed yet
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub struct TransferClaimArgs {
+    /// Keypair of the claimant the claim is moved to, must not be a claimant of the distributor
+    #[clap(long, env)]
+    pub new_claimant_keypair_path: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub struct RecoverClaimArgs {
+    #[clap(subcommand)]
+    pub command: RecoverClaimCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum RecoverClaimCommands {
+    /// Propose to move the claim of a claimant to a new claimant, signed by the admin
+    Propose {
+        #[clap(long, env)]
+        claimant: Pubkey,
+        #[clap(long, env)]
+        new_claimant: Pubkey,
+    },
+    /// Move the claim once the claim recovery delay has passed, signed by the admin and the new claimant
+    Execute {
+        #[clap(long, env)]
+        claimant: Pubkey,
+        #[clap(long, env)]
+        new_claimant_keypair_path: PathBuf,
+    },
+    /// Cancel the proposed recovery, signed by the admin
+    Cancel {
+        #[clap(long, env)]
+        claimant: Pubkey,
+    },
+}
+
+#[derive(Parser, Debug)]
+pub struct CloseDistributorArgs {
+    /// Account to send the rent to. Defaults to the admin keypair
+    #[clap(long, env)]
+    pub receiver: Option<Pubkey>,
+}
+
 #[derive(Parser, Debug)]
 pub struct CreateMerkleTreeArgs {
     /// CSV path
@@ -115,12 +351,61 @@ pub struct CreateMerkleTreeArgs {
     /// Merkle tree out path
     #[clap(long, env)]
     pub merkle_tree_path: PathBuf,
+
+    /// Index every leaf, for distributors tracking claims in bitmaps instead of a claim status per claimant.
+    /// Leaves can't have locked tokens
+    #[clap(long, env, conflicts_with = "cumulative")]
+    pub bitmap_claims: bool,
+
+    /// Build a tree for a cumulative distributor, where leaves hold each claimant's total entitlement
+    /// across every root. Leaves can't have locked tokens
+    #[clap(long, env)]
+    pub cumulative: bool,
+
+    /// Previous cumulative merkle tree, the CSV amounts are added on top of its leaves
+    #[clap(long, env, requires = "cumulative")]
+    pub previous_merkle_tree_path: Option<PathBuf>,
 }
 
 #[derive(Parser, Debug)]
-pub struct SetAdminArgs {
+pub struct UpdateRootArgs {
+    /// Merkle distributor path
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub struct UpdateTimestampsArgs {
+    /// New lockup start (unix timestamp). Defaults to the current one
+    #[clap(long, env)]
+    pub start_vesting_ts: Option<i64>,
+
+    /// New lockup end (unix timestamp). Defaults to the current one
+    #[clap(long, env)]
+    pub end_vesting_ts: Option<i64>,
+
+    /// New clawback start (unix timestamp), can only be later. Defaults to the current one
     #[clap(long, env)]
-    pub new_admin: Pubkey,
+    pub clawback_start_ts: Option<i64>,
+}
+
+#[derive(Parser, Debug)]
+pub struct SetAdminArgs {
+    #[clap(subcommand)]
+    pub command: SetAdminCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum SetAdminCommands {
+    /// Propose a new admin, signed by the current admin
+    Propose {
+        #[clap(long, env)]
+        new_admin: Pubkey,
+    },
+    /// Accept the admin role, signed by the proposed admin
+    Accept,
+    /// Cancel the pending admin proposal, signed by the current admin
+    Cancel,
 }
 
 fn main() {
@@ -130,16 +415,54 @@ fn main() {
         Commands::NewDistributor(new_distributor_args) => {
             process_new_distributor(&args, new_distributor_args);
         }
+        Commands::FundDistributor(fund_distributor_args) => {
+            process_fund_distributor(&args, fund_distributor_args);
+        }
+        Commands::AddMint(add_mint_args) => {
+            process_add_mint(&args, add_mint_args);
+        }
         Commands::Claim(claim_args) => {
             process_claim(&args, claim_args);
         }
+        Commands::SignAttestation(sign_attestation_args) => {
+            process_sign_attestation(&args, sign_attestation_args);
+        }
+        Commands::ClaimOnBehalf(claim_on_behalf_args) => {
+            process_claim_on_behalf(&args, claim_on_behalf_args);
+        }
         Commands::Clawback(clawback_args) => process_clawback(&args, clawback_args),
+        Commands::ClawbackSurplus => process_clawback_surplus(&args),
+        Commands::Revoke(revoke_args) => process_revoke(&args, revoke_args),
+        Commands::TransferClaim(transfer_claim_args) => {
+            process_transfer_claim(&args, transfer_claim_args);
+        }
+        Commands::RecoverClaim(recover_claim_args) => {
+            process_recover_claim(&args, recover_claim_args);
+        }
+        Commands::CloseDistributor(close_distributor_args) => {
+            process_close_distributor(&args, close_distributor_args);
+        }
         Commands::CreateMerkleTree(merkle_tree_args) => {
             process_create_merkle_tree(merkle_tree_args);
         }
         Commands::SetAdmin(set_admin_args) => {
             process_set_admin(&args, set_admin_args);
         }
+        Commands::UpdateRoot(update_root_args) => {
+            process_update_root(&args, update_root_args);
+        }
+        Commands::UpdateTimestamps(update_timestamps_args) => {
+            process_update_timestamps(&args, update_timestamps_args);
+        }
+        Commands::Pause => {
+            process_set_paused(&args, true);
+        }
+        Commands::Unpause => {
+            process_set_paused(&args, false);
+        }
+        Commands::MigrateDistributor => {
+            process_migrate_distributor(&args);
+        }
     }
 }
 
@@ -151,54 +474,50 @@ fn process_new_claim(args: &Args, claim_args: &ClaimArgs) {
     let merkle_tree = AirdropMerkleTree::new_from_file(&claim_args.merkle_tree_path)
         .expect("failed to load merkle tree from file");
 
-    let (distributor, _bump) =
-        get_merkle_distributor_pda(&args.program_id, &args.mint, args.airdrop_version);
-
     // Get user's node in claim
     let node = merkle_tree.get_node(&claimant);
 
-    let (claim_status_pda, _bump) = get_claim_status_pda(&args.program_id, &claimant, &distributor);
-
     let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
 
-    let claimant_ata = get_associated_token_address(&claimant, &args.mint);
+    let distributor_ixs = distributor_instructions(&client, args);
+    let distributor = fetch_distributor(&client, &distributor_ixs.distributor)
+        .expect("Failed fetching distributor account");
+    print_claim_fee(&distributor, Some(node.amount_unlocked()));
+    let distributor_ixs =
+        distributor_ixs.with_claim_fee(distributor.claim_fee, distributor.fee_receiver);
+    let claimant_ata = distributor_ixs.associated_token_account(&claimant);
 
     let mut ixs = vec![];
 
-    match client.get_account(&claimant_ata) {
-        Ok(_) => {}
-        Err(e) => {
-            // TODO: directly pattern match on error kind
-            if e.to_string().contains("AccountNotFound") {
-                println!("PDA does not exist. creating.");
-                let ix =
-                    create_associated_token_account(&claimant, &claimant, &args.mint, &token::ID);
-                ixs.push(ix);
-            } else {
-                panic!("Error fetching PDA: {e}")
+    ixs.extend(attestation_instruction(
+        &client,
+        &distributor_ixs,
+        claim_args,
+        &claimant,
+    ));
+
+    let new_claim_ix = match claim_args.recipient_token_account {
+        Some(to) => distributor_ixs.new_claim_to(&node, &to),
+        None => {
+            match client.get_account_with_commitment(&claimant_ata, client.commitment()) {
+                Ok(response) if response.value.is_some() => {}
+                Ok(_) => {
+                    println!("PDA does not exist. creating.");
+                    let ix = create_associated_token_account(
+                        &claimant,
+                        &claimant,
+                        &args.mint,
+                        &distributor_ixs.token_program,
+                    );
+                    ixs.push(ix);
+                }
+                Err(e) => panic!("Error fetching PDA: {e}"),
             }
-        }
-    }
 
-    let new_claim_ix = Instruction {
-        program_id: args.program_id,
-        accounts: merkle_distributor::accounts::NewClaim {
-            distributor,
-            claim_status: claim_status_pda,
-            from: get_associated_token_address(&distributor, &args.mint),
-            to: claimant_ata,
-            claimant,
-            token_program: token::ID,
-            system_program: solana_program::system_program::ID,
+            distributor_ixs.new_claim(&node, &claimant_ata)
         }
-        .to_account_metas(None),
-        data: merkle_distributor::instruction::NewClaim {
-            amount_unlocked: node.amount_unlocked(),
-            amount_locked: node.amount_locked(),
-            proof: node.proof.expect("proof not found"),
-        }
-        .data(),
-    };
+    }
+    .expect("Failed building claim instruction");
 
     ixs.push(new_claim_ix);
 
@@ -212,50 +531,164 @@ fn process_new_claim(args: &Args, claim_args: &ClaimArgs) {
     println!("successfully created new claim with signature {signature:#?}");
 }
 
-fn process_claim(args: &Args, claim_args: &ClaimArgs) {
+fn process_new_claim_indexed(args: &Args, claim_args: &ClaimArgs, node: TreeNode) {
     let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
     let claimant = keypair.pubkey();
+    let index = node.index.expect("leaf is not indexed");
+    println!("Claiming leaf {index} for user {claimant}...");
 
-    let priority_fee = args.priority.unwrap_or(0);
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
 
-    let (distributor, bump) =
-        get_merkle_distributor_pda(&args.program_id, &args.mint, args.airdrop_version);
-    println!("distributor pubkey {}", distributor);
+    let distributor_ixs = distributor_instructions(&client, args);
+    let claimant_ata = distributor_ixs.associated_token_account(&claimant);
+
+    let mut ixs = vec![create_associated_token_account_idempotent(
+        &claimant,
+        &claimant,
+        &args.mint,
+        &distributor_ixs.token_program,
+    )];
+    if let Some(priority_fee) = args.priority {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+
+    ixs.extend(attestation_instruction(
+        &client,
+        &distributor_ixs,
+        claim_args,
+        &claimant,
+    ));
+    ixs.push(
+        distributor_ixs
+            .new_claim_indexed(&node, &claimant_ata)
+            .expect("Failed building claim instruction"),
+    );
+
+    if claim_args.recipient_token_account.is_some() {
+        println!("Indexed claims always go to the claimant's token account, ignoring recipient");
+    }
 
-    let (claim_status_pda, _bump) = get_claim_status_pda(&args.program_id, &claimant, &distributor);
-    println!("claim pda: {claim_status_pda}, bump: {bump}");
+    let blockhash = client.get_latest_blockhash().unwrap();
+    let tx = Transaction::new_signed_with_payer(&ixs, Some(&claimant), &[&keypair], blockhash);
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!("successfully claimed leaf {index} with signature {signature:#?}");
+}
+
+fn process_cumulative_claim(args: &Args, claim_args: &ClaimArgs, node: TreeNode) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+    let claimant = keypair.pubkey();
+    let cumulative_amount = node.amount_unlocked();
+    println!("Claiming up to cumulative amount {cumulative_amount} for user {claimant}...");
 
     let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
 
-    match client.get_account(&claim_status_pda) {
-        Ok(_) => {}
-        Err(e) => {
-            // TODO: match on the error kind
-            if e.to_string().contains("AccountNotFound") {
-                println!("PDA does not exist. creating.");
-                process_new_claim(args, claim_args);
-            } else {
-                panic!("error getting PDA: {e}")
-            }
+    let distributor_ixs = distributor_instructions(&client, args);
+    let claimant_ata = distributor_ixs.associated_token_account(&claimant);
+
+    let mut ixs = vec![create_associated_token_account_idempotent(
+        &claimant,
+        &claimant,
+        &args.mint,
+        &distributor_ixs.token_program,
+    )];
+    if let Some(priority_fee) = args.priority {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+
+    let claim_status = distributor_ixs.cumulative_claim_status(&claimant);
+    let claim_ix =
+        match fetch_cumulative_claim_status(&client, &claim_status).expect("error getting PDA") {
+            Some(_) => distributor_ixs.claim_cumulative(&node, &claimant_ata),
+            None => distributor_ixs.new_cumulative_claim(&node, &claimant_ata),
         }
+        .expect("Failed building claim instruction");
+    ixs.extend(attestation_instruction(
+        &client,
+        &distributor_ixs,
+        claim_args,
+        &claimant,
+    ));
+    ixs.push(claim_ix);
+
+    if claim_args.recipient_token_account.is_some() {
+        println!("Cumulative claims always go to the claimant's token account, ignoring recipient");
+    }
+
+    let blockhash = client.get_latest_blockhash().unwrap();
+    let tx = Transaction::new_signed_with_payer(&ixs, Some(&claimant), &[&keypair], blockhash);
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!("successfully claimed with signature {signature:#?}");
+}
+
+fn process_claim(args: &Args, claim_args: &ClaimArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+    let claimant = keypair.pubkey();
+
+    // Indexed and cumulative leaves have no locked tokens, so they never claim from a ClaimStatus
+    let merkle_tree = AirdropMerkleTree::new_from_file(&claim_args.merkle_tree_path)
+        .expect("failed to load merkle tree from file");
+    if merkle_tree.is_multi_mint() {
+        process_multi_mint_claim(args, claim_args, merkle_tree.get_nodes(&claimant));
+        return;
+    }
+    let node = merkle_tree.get_node(&claimant);
+    if node.index.is_some() {
+        process_new_claim_indexed(args, claim_args, node);
+        return;
+    }
+    if merkle_tree.cumulative {
+        process_cumulative_claim(args, claim_args, node);
+        return;
     }
+    if args.mint == Pubkey::default() {
+        process_native_claim(args, claim_args, node);
+        return;
+    }
+
+    let priority_fee = args.priority.unwrap_or(0);
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let distributor_ixs = distributor_instructions(&client, args);
+    println!("distributor pubkey {}", distributor_ixs.distributor);
 
-    let claimant_ata = get_associated_token_address(&claimant, &args.mint);
+    let claim_status_pda = distributor_ixs.claim_status(&claimant);
+    println!("claim pda: {claim_status_pda}");
 
     let mut ixs = vec![];
 
-    let claim_ix = Instruction {
-        program_id: args.program_id,
-        accounts: merkle_distributor::accounts::ClaimLocked {
-            distributor,
-            claim_status: claim_status_pda,
-            from: get_associated_token_address(&distributor, &args.mint),
-            to: claimant_ata,
-            claimant,
-            token_program: token::ID,
+    match client
+        .get_account_with_commitment(&claim_status_pda, client.commitment())
+        .expect("error getting PDA")
+        .value
+    {
+        None => {
+            println!("PDA does not exist. creating.");
+            process_new_claim(args, claim_args);
+        }
+        Some(account) if account.data.len() < ClaimStatus::LEN => {
+            println!("PDA created by an earlier program version. migrating.");
+            ixs.push(distributor_ixs.migrate_claim_status(&claim_status_pda, &claimant));
         }
-        .to_account_metas(None),
-        data: merkle_distributor::instruction::ClaimLocked {}.data(),
+        Some(_) => {}
+    }
+
+    let claim_ix = match claim_args.recipient_token_account {
+        Some(to) => distributor_ixs.claim_locked_to(&claimant, &to),
+        None => distributor_ixs.claim_locked(
+            &claimant,
+            &distributor_ixs.associated_token_account(&claimant),
+        ),
     };
     ixs.push(claim_ix);
 
@@ -280,105 +713,565 @@ fn process_claim(args: &Args, claim_args: &ClaimArgs) {
     println!("successfully claimed tokens with signature {signature:#?}",);
 }
 
-fn check_distributor_onchain_matches(
-    account: &Account,
-    merkle_tree: &AirdropMerkleTree,
-    new_distributor_args: &NewDistributorArgs,
-    pubkey: Pubkey,
-) -> Result<(), &'static str> {
-    if let Ok(distributor) = MerkleDistributor::try_deserialize(&mut account.data.as_slice()) {
-        if distributor.root != merkle_tree.merkle_root {
-            return Err("root mismatch");
-        }
-        if distributor.max_total_claim != merkle_tree.max_total_claim {
-            return Err("max_total_claim mismatch");
-        }
-        if distributor.max_num_nodes != merkle_tree.max_num_nodes {
-            return Err("max_num_nodes mismatch");
-        }
+/// Claims every mint of the claimant from a multi-mint distributor in a single transaction: opens
+/// the claims of each mint if needed, otherwise withdraws their unlocked locked tokens
+fn process_multi_mint_claim(args: &Args, claim_args: &ClaimArgs, nodes: Vec<TreeNode>) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+    let claimant = keypair.pubkey();
+    println!("Claiming {} mints for user {claimant}...", nodes.len());
 
-        if distributor.start_ts != new_distributor_args.start_vesting_ts {
-            return Err("start_ts mismatch");
-        }
-        if distributor.end_ts != new_distributor_args.end_vesting_ts {
-            return Err("end_ts mismatch");
-        }
-        if distributor.clawback_start_ts != new_distributor_args.clawback_start_ts {
-            return Err("clawback_start_ts mismatch");
-        }
-        if distributor.clawback_receiver != new_distributor_args.clawback_receiver_token_account {
-            return Err("clawback_receiver mismatch");
-        }
-        if distributor.admin != pubkey {
-            return Err("admin mismatch");
-        }
-    }
-    Ok(())
-}
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
 
-fn process_new_distributor(args: &Args, new_distributor_args: &NewDistributorArgs) {
-    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::finalized());
+    let distributor_ixs = distributor_instructions(&client, args);
+    let distributor = fetch_distributor(&client, &distributor_ixs.distributor)
+        .expect("Failed fetching distributor account");
+    let curr_ts = client
+        .get_block_time(client.get_slot().unwrap())
+        .expect("Failed fetching block time");
 
-    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
-    let merkle_tree = AirdropMerkleTree::new_from_file(&new_distributor_args.merkle_tree_path)
-        .expect("failed to read");
-    let (distributor_pubkey, _bump) =
-        get_merkle_distributor_pda(&args.program_id, &args.mint, args.airdrop_version);
-    let token_vault = get_associated_token_address(&distributor_pubkey, &args.mint);
+    let mut ixs = vec![];
+    if let Some(priority_fee) = args.priority {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    let num_budget_ixs = ixs.len();
+    let attestation_ix = attestation_instruction(&client, &distributor_ixs, claim_args, &claimant);
+
+    for node in nodes {
+        let mint_index = node
+            .mint_index
+            .expect("multi-mint leaf without a mint index");
+        let distributor_mint =
+            fetch_distributor_mint(&client, &distributor_ixs.distributor_mint(mint_index))
+                .expect("Failed fetching distributor mint account")
+                .unwrap_or_else(|| panic!("mint index {mint_index} is not registered yet"));
+        let token_program = fetch_token_program(&client, &distributor_mint.mint)
+            .expect("Failed fetching mint account");
+        let to = get_associated_token_address_with_program_id(
+            &claimant,
+            &distributor_mint.mint,
+            &token_program,
+        );
 
-    if let Some(account) = client
-        .get_account_with_commitment(&distributor_pubkey, CommitmentConfig::confirmed())
-        .unwrap()
-        .value
-    {
-        println!("merkle distributor account exists, checking parameters...");
-        check_distributor_onchain_matches(
-            &account,
-            &merkle_tree,
-            new_distributor_args,
-            keypair.pubkey(),
-        ).expect("merkle root on-chain does not match provided arguments! Confirm admin and clawback parameters to avoid loss of funds!");
+        let claim_status_pda = distributor_ixs.multi_mint_claim_status(&claimant, mint_index);
+        match fetch_claim_status(&client, &claim_status_pda).expect("error getting PDA") {
+            None => {
+                ixs.push(create_associated_token_account_idempotent(
+                    &claimant,
+                    &claimant,
+                    &distributor_mint.mint,
+                    &token_program,
+                ));
+                ixs.push(
+                    distributor_ixs
+                        .new_claim_multi_mint(&node, &distributor_mint, &token_program, &to)
+                        .expect("Failed building claim instruction"),
+                );
+            }
+            Some(claim_status) => {
+                let (start_ts, end_ts) =
+                    claim_status.lockup(distributor.start_ts, distributor.end_ts);
+                let amount_withdrawable = claim_status
+                    .amount_withdrawable(curr_ts, start_ts, end_ts, &distributor.vesting_schedule)
+                    .unwrap();
+                // claim_locked fails when nothing is withdrawable, which would fail every other mint
+                if amount_withdrawable == 0 {
+                    continue;
+                }
+                ixs.push(distributor_ixs.claim_locked_multi_mint(
+                    &claimant,
+                    &distributor_mint,
+                    &token_program,
+                    &to,
+                ));
+            }
+        }
     }
 
-    println!("creating new distributor with args: {new_distributor_args:#?}");
+    if ixs.len() == num_budget_ixs {
+        println!("nothing to claim");
+        return;
+    }
+    // the attestation is only read by new claims, but must come before them
+    if let Some(attestation_ix) = attestation_ix {
+        ixs.insert(num_budget_ixs, attestation_ix);
+    }
 
-    let new_distributor_ix = Instruction {
-        program_id: args.program_id,
-        accounts: merkle_distributor::accounts::NewDistributor {
-            clawback_receiver: new_distributor_args.clawback_receiver_token_account,
-            mint: args.mint,
-            token_vault,
-            distributor: distributor_pubkey,
-            system_program: solana_program::system_program::id(),
-            associated_token_program: spl_associated_token_account::ID,
-            token_program: token::ID,
-            admin: keypair.pubkey(),
-        }
-        .to_account_metas(None),
-        data: merkle_distributor::instruction::NewDistributor {
-            version: args.airdrop_version,
-            root: merkle_tree.merkle_root,
-            max_total_claim: merkle_tree.max_total_claim,
-            max_num_nodes: merkle_tree.max_num_nodes,
-            start_vesting_ts: new_distributor_args.start_vesting_ts,
-            end_vesting_ts: new_distributor_args.end_vesting_ts,
-            clawback_start_ts: new_distributor_args.clawback_start_ts,
-        }
-        .data(),
-    };
+    if claim_args.recipient_token_account.is_some() {
+        println!("Multi-mint claims always go to the claimant's ATAs, ignoring recipient");
+    }
 
     let blockhash = client.get_latest_blockhash().unwrap();
-    let tx = Transaction::new_signed_with_payer(
-        &[new_distributor_ix],
-        Some(&keypair.pubkey()),
-        &[&keypair],
-        blockhash,
-    );
+    let tx = Transaction::new_signed_with_payer(&ixs, Some(&claimant), &[&keypair], blockhash);
 
-    // See comments on new_distributor instruction inside the program to ensure this transaction
-    // didn't get frontrun.
-    // If this fails, make sure to run it again.
-    match client.send_and_confirm_transaction_with_spinner(&tx) {
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!("successfully claimed every mint with signature {signature:#?}");
+}
+
+/// Claims from a native SOL distributor: opens the claim if needed, otherwise withdraws the
+/// unlocked locked lamports
+fn process_native_claim(args: &Args, claim_args: &ClaimArgs, node: TreeNode) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+    let claimant = keypair.pubkey();
+    println!("Claiming SOL for user {claimant}...");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let distributor_ixs = distributor_instructions(&client, args);
+
+    let mut ixs = vec![];
+    if let Some(priority_fee) = args.priority {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+
+    let claim_status = distributor_ixs.claim_status(&claimant);
+    match fetch_claim_status(&client, &claim_status).expect("error getting PDA") {
+        Some(_) => ixs.push(distributor_ixs.claim_locked_native(&claimant)),
+        None => {
+            ixs.extend(attestation_instruction(
+                &client,
+                &distributor_ixs,
+                claim_args,
+                &claimant,
+            ));
+            ixs.push(
+                distributor_ixs
+                    .new_claim_native(&node)
+                    .expect("Failed building claim instruction"),
+            );
+        }
+    }
+
+    if claim_args.recipient_token_account.is_some() {
+        println!("Native SOL claims always go to the claimant, ignoring recipient");
+    }
+
+    let blockhash = client.get_latest_blockhash().unwrap();
+    let tx = Transaction::new_signed_with_payer(&ixs, Some(&claimant), &[&keypair], blockhash);
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!("successfully claimed SOL with signature {signature:#?}");
+}
+
+/// Ed25519 instruction carrying the claimant's attestation, if one was given
+fn attestation_instruction(
+    client: &RpcClient,
+    distributor_ixs: &DistributorInstructions,
+    claim_args: &ClaimArgs,
+    claimant: &Pubkey,
+) -> Option<Instruction> {
+    let (signature, expiry_ts) = claim_args
+        .attestation_signature
+        .zip(claim_args.attestation_expiry_ts)?;
+
+    let distributor = fetch_distributor(client, &distributor_ixs.distributor)
+        .expect("Failed fetching distributor account");
+    let attestation_signer = distributor
+        .attestation_signer
+        .expect("distributor does not have an attestation signer");
+
+    Some(distributor_ixs.attestation(
+        &attestation_signer,
+        signature.as_ref().try_into().unwrap(),
+        claimant,
+        expiry_ts,
+    ))
+}
+
+/// Prints the claim fee of the distributor before claiming, including the tokens withheld from
+/// `amount_unlocked` if known
+fn print_claim_fee(distributor: &MerkleDistributor, amount_unlocked: Option<u64>) {
+    match (distributor.claim_fee, amount_unlocked) {
+        (ClaimFee::None, _) => {}
+        (ClaimFee::Lamports { amount }, _) => {
+            println!(
+                "new claims pay a fee of {amount} lamports to {}",
+                distributor.fee_receiver
+            );
+        }
+        (ClaimFee::Bps { bps }, None) => {
+            println!(
+                "new claims pay a fee of {bps} bps of their unlocked tokens to {}",
+                distributor.fee_receiver
+            );
+        }
+        (claim_fee @ ClaimFee::Bps { bps }, Some(amount_unlocked)) => {
+            println!(
+                "new claims pay a fee of {bps} bps of their unlocked tokens to {}: {} of {amount_unlocked} tokens",
+                distributor.fee_receiver,
+                claim_fee.token_amount(amount_unlocked).unwrap()
+            );
+        }
+    }
+}
+
+fn process_sign_attestation(args: &Args, sign_attestation_args: &SignAttestationArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let (distributor, _bump) =
+        get_merkle_distributor_pda(&args.program_id, &args.mint, args.airdrop_version);
+
+    let signature = keypair.sign_message(&attestation_message(
+        &distributor,
+        &sign_attestation_args.claimant,
+        sign_attestation_args.expiry_ts,
+    ));
+    println!(
+        "attestation for {} by {} until {}: {signature}",
+        sign_attestation_args.claimant,
+        keypair.pubkey(),
+        sign_attestation_args.expiry_ts
+    );
+}
+
+fn process_claim_on_behalf(args: &Args, claim_on_behalf_args: &ClaimOnBehalfArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let merkle_tree = AirdropMerkleTree::new_from_file(&claim_on_behalf_args.merkle_tree_path)
+        .expect("failed to load merkle tree from file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let distributor_ixs = distributor_instructions(&client, args);
+    let distributor_pubkey = distributor_ixs.distributor;
+
+    let distributor = fetch_distributor(&client, &distributor_pubkey)
+        .expect("Failed fetching distributor account");
+    if distributor.cumulative {
+        panic!("distributor {distributor_pubkey} is cumulative, which can't be claimed on behalf of claimants");
+    }
+    if distributor.attestation_signer.is_some() {
+        panic!("distributor {distributor_pubkey} requires attestations, which can't be claimed on behalf of claimants");
+    }
+    if distributor.bitmap_claims {
+        panic!("distributor {distributor_pubkey} uses bitmap claims, which can't be claimed on behalf of claimants");
+    }
+    if distributor.multi_mint {
+        panic!("distributor {distributor_pubkey} is multi-mint, which can't be claimed on behalf of claimants");
+    }
+    if !distributor.allow_claim_on_behalf {
+        panic!("distributor {distributor_pubkey} does not allow claiming on behalf of claimants");
+    }
+    print_claim_fee(&distributor, None);
+    let distributor_ixs =
+        distributor_ixs.with_claim_fee(distributor.claim_fee, distributor.fee_receiver);
+
+    let curr_ts = client
+        .get_block_time(client.get_slot().unwrap())
+        .expect("Failed fetching block time");
+
+    for nodes in merkle_tree
+        .tree_nodes
+        .chunks(claim_on_behalf_args.batch_size)
+    {
+        let claim_status_pdas: Vec<Pubkey> = nodes
+            .iter()
+            .map(|node| distributor_ixs.claim_status(&node.claimant))
+            .collect();
+        let claim_status_accounts = client
+            .get_multiple_accounts(&claim_status_pdas)
+            .expect("Failed fetching claim status accounts");
+        let closed_claims_pdas: Vec<Pubkey> = nodes
+            .iter()
+            .map(|node| distributor_ixs.closed_claims(&node.claimant))
+            .collect();
+        let closed_claims_accounts = client
+            .get_multiple_accounts(&closed_claims_pdas)
+            .expect("Failed fetching closed claims accounts");
+
+        let mut ixs = vec![];
+        if let Some(priority_fee) = args.priority {
+            ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+                priority_fee,
+            ));
+        }
+        let num_budget_ixs = ixs.len();
+
+        for (((node, claim_status_pda), claim_status_account), closed_claims_account) in nodes
+            .iter()
+            .zip(claim_status_pdas)
+            .zip(claim_status_accounts)
+            .zip(closed_claims_accounts)
+        {
+            match claim_status_account {
+                // claimed everything and closed the claim status, the leaf can't be claimed again
+                None if closed_claims_account.is_some_and(|account| {
+                    ClosedClaims::is_claim_closed(&account.data, &node.claimant)
+                }) => {}
+                None => {
+                    ixs.push(create_associated_token_account_idempotent(
+                        &keypair.pubkey(),
+                        &node.claimant,
+                        &args.mint,
+                        &distributor_ixs.token_program,
+                    ));
+                    ixs.push(
+                        distributor_ixs
+                            .new_claim_on_behalf(node, &keypair.pubkey())
+                            .expect("Failed building claim instruction"),
+                    );
+                }
+                Some(claim_status_account) => {
+                    let claim_status: ClaimStatus =
+                        deserialize_account(&claim_status_pda, &claim_status_account.data)
+                            .expect("Failed deserializing claim status account");
+                    let (start_ts, end_ts) =
+                        claim_status.lockup(distributor.start_ts, distributor.end_ts);
+                    let amount_withdrawable = claim_status
+                        .amount_withdrawable(
+                            curr_ts,
+                            start_ts,
+                            end_ts,
+                            &distributor.vesting_schedule,
+                        )
+                        .unwrap();
+                    // claim_locked fails when nothing is withdrawable, which would fail the whole batch
+                    if amount_withdrawable == 0 {
+                        continue;
+                    }
+
+                    ixs.push(distributor_ixs.claim_locked_on_behalf(&node.claimant));
+                }
+            }
+        }
+
+        if ixs.len() == num_budget_ixs {
+            continue;
+        }
+
+        let tx = Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&keypair.pubkey()),
+            &[&keypair],
+            client.get_latest_blockhash().unwrap(),
+        );
+
+        match client.send_and_confirm_transaction_with_spinner(&tx) {
+            Ok(signature) => {
+                println!(
+                    "successfully processed batch of {} claimants with signature {signature:#?}",
+                    nodes.len()
+                );
+            }
+            Err(e) => {
+                println!(
+                    "Failed to claim for batch starting at {}: {:?}",
+                    nodes[0].claimant, e
+                );
+            }
+        }
+    }
+}
+
+/// Instruction builder for the distributor of `args`, using the token program that owns its mint
+fn distributor_instructions(client: &RpcClient, args: &Args) -> DistributorInstructions {
+    if args.mint == Pubkey::default() {
+        return DistributorInstructions::new_native(args.program_id, args.airdrop_version);
+    }
+    let token_program =
+        fetch_token_program(client, &args.mint).expect("Failed fetching mint account");
+    DistributorInstructions::new(
+        args.program_id,
+        args.mint,
+        args.airdrop_version,
+        token_program,
+    )
+}
+
+/// Exits if a lockup committed in the tree would be refused on claim by a distributor with
+/// `clawback_start_ts` and `vesting_schedule`.
+fn validate_tree_lockups(
+    merkle_tree: &AirdropMerkleTree,
+    clawback_start_ts: i64,
+    vesting_schedule: &VestingSchedule,
+) {
+    let (cliff_offset, step_period) = match *vesting_schedule {
+        VestingSchedule::Linear => (None, None),
+        VestingSchedule::Step { period } => (None, Some(period)),
+        VestingSchedule::CliffLinear { cliff_offset, .. } => (Some(cliff_offset), None),
+    };
+    merkle_tree
+        .validate_lockups(clawback_start_ts, cliff_offset, step_period)
+        .expect("merkle tree lockups don't fit the distributor");
+}
+
+fn check_distributor_onchain_matches(
+    account: &Account,
+    merkle_tree: &AirdropMerkleTree,
+    new_distributor_args: &NewDistributorArgs,
+    pubkey: Pubkey,
+) -> Result<(), &'static str> {
+    if let Ok(distributor) = MerkleDistributor::try_deserialize(&mut account.data.as_slice()) {
+        if distributor.root != merkle_tree.merkle_root {
+            return Err("root mismatch");
+        }
+        if distributor.max_total_claim != merkle_tree.max_total_claim {
+            return Err("max_total_claim mismatch");
+        }
+        if distributor.max_num_nodes != merkle_tree.max_num_nodes {
+            return Err("max_num_nodes mismatch");
+        }
+
+        if distributor.start_ts != new_distributor_args.start_vesting_ts {
+            return Err("start_ts mismatch");
+        }
+        if distributor.end_ts != new_distributor_args.end_vesting_ts {
+            return Err("end_ts mismatch");
+        }
+        if distributor.vesting_schedule != new_distributor_args.vesting_schedule() {
+            return Err("vesting_schedule mismatch");
+        }
+        if distributor.allow_claim_on_behalf != new_distributor_args.allow_claim_on_behalf {
+            return Err("allow_claim_on_behalf mismatch");
+        }
+        if distributor.root_lock_ts != new_distributor_args.root_lock_ts {
+            return Err("root_lock_ts mismatch");
+        }
+        if distributor.bitmap_claims != merkle_tree.is_indexed() {
+            return Err("bitmap_claims mismatch");
+        }
+        if distributor.require_full_funding != new_distributor_args.require_full_funding {
+            return Err("require_full_funding mismatch");
+        }
+        if distributor.claim_deadline_ts != new_distributor_args.claim_deadline_ts {
+            return Err("claim_deadline_ts mismatch");
+        }
+        if distributor.attestation_signer != new_distributor_args.attestation_signer {
+            return Err("attestation_signer mismatch");
+        }
+        if distributor.claim_fee != new_distributor_args.claim_fee() {
+            return Err("claim_fee mismatch");
+        }
+        if distributor.fee_receiver != new_distributor_args.fee_receiver.unwrap_or_default() {
+            return Err("fee_receiver mismatch");
+        }
+        if distributor.claim_recovery_delay != new_distributor_args.claim_recovery_delay {
+            return Err("claim_recovery_delay mismatch");
+        }
+        if distributor.revocable != new_distributor_args.revocable {
+            return Err("revocable mismatch");
+        }
+        if distributor.cumulative != merkle_tree.cumulative {
+            return Err("cumulative mismatch");
+        }
+        if distributor.multi_mint != merkle_tree.is_multi_mint() {
+            return Err("multi_mint mismatch");
+        }
+        if distributor.clawback_start_ts != new_distributor_args.clawback_start_ts {
+            return Err("clawback_start_ts mismatch");
+        }
+        if distributor.clawback_receiver != new_distributor_args.clawback_receiver_token_account {
+            return Err("clawback_receiver mismatch");
+        }
+        if distributor.admin != pubkey {
+            return Err("admin mismatch");
+        }
+    }
+    Ok(())
+}
+
+fn process_new_distributor(args: &Args, new_distributor_args: &NewDistributorArgs) {
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::finalized());
+
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+    let merkle_tree = AirdropMerkleTree::new_from_file(&new_distributor_args.merkle_tree_path)
+        .expect("failed to read");
+    let distributor_ixs = distributor_instructions(&client, args);
+    let distributor_pubkey = distributor_ixs.distributor;
+
+    if let Some(account) = client
+        .get_account_with_commitment(&distributor_pubkey, CommitmentConfig::confirmed())
+        .unwrap()
+        .value
+    {
+        println!("merkle distributor account exists, checking parameters...");
+        check_distributor_onchain_matches(
+            &account,
+            &merkle_tree,
+            new_distributor_args,
+            keypair.pubkey(),
+        ).expect("merkle root on-chain does not match provided arguments! Confirm admin and clawback parameters to avoid loss of funds!");
+    }
+
+    validate_tree_lockups(
+        &merkle_tree,
+        new_distributor_args.clawback_start_ts,
+        &new_distributor_args.vesting_schedule(),
+    );
+
+    println!("creating new distributor with args: {new_distributor_args:#?}");
+
+    let distributor_args = NewDistributorParams {
+        root: merkle_tree.merkle_root,
+        max_total_claim: merkle_tree.max_total_claim,
+        max_num_nodes: merkle_tree.max_num_nodes,
+        start_vesting_ts: new_distributor_args.start_vesting_ts,
+        end_vesting_ts: new_distributor_args.end_vesting_ts,
+        clawback_start_ts: new_distributor_args.clawback_start_ts,
+        vesting_schedule: new_distributor_args.vesting_schedule(),
+        allow_claim_on_behalf: new_distributor_args.allow_claim_on_behalf,
+        root_lock_ts: new_distributor_args.root_lock_ts,
+        bitmap_claims: merkle_tree.is_indexed(),
+        require_full_funding: new_distributor_args.require_full_funding,
+        cumulative: merkle_tree.cumulative,
+        claim_deadline_ts: new_distributor_args.claim_deadline_ts,
+        attestation_signer: new_distributor_args.attestation_signer,
+        multi_mint: merkle_tree.is_multi_mint(),
+        claim_fee: new_distributor_args.claim_fee(),
+        fee_receiver: new_distributor_args.fee_receiver.unwrap_or_default(),
+        claim_recovery_delay: new_distributor_args.claim_recovery_delay,
+        revocable: new_distributor_args.revocable,
+    };
+    let mut ixs = vec![];
+    // Token fees are sent to the fee receiver's ATA, which must exist before the first claim
+    if matches!(distributor_args.claim_fee, ClaimFee::Bps { .. }) {
+        ixs.push(create_associated_token_account_idempotent(
+            &keypair.pubkey(),
+            &distributor_args.fee_receiver,
+            &args.mint,
+            &distributor_ixs.token_program,
+        ));
+    }
+    let new_distributor_ix = if distributor_ixs.is_native() {
+        if distributor_args.bitmap_claims
+            || distributor_args.cumulative
+            || distributor_args.allow_claim_on_behalf
+            || distributor_args.multi_mint
+            || distributor_args.claim_fee != ClaimFee::None
+            || distributor_args.claim_recovery_delay.is_some()
+            || distributor_args.revocable
+        {
+            panic!("native SOL distributors only support claims by the claimant, without bitmap, cumulative or multi-mint claims, a claim fee, claim recovery or revocation");
+        }
+        distributor_ixs.new_distributor_native(
+            &keypair.pubkey(),
+            &new_distributor_args.clawback_receiver_token_account,
+            distributor_args,
+        )
+    } else {
+        distributor_ixs.new_distributor_v2(
+            &keypair.pubkey(),
+            &new_distributor_args.clawback_receiver_token_account,
+            distributor_args,
+        )
+    };
+    ixs.push(new_distributor_ix);
+
+    let blockhash = client.get_latest_blockhash().unwrap();
+    let tx =
+        Transaction::new_signed_with_payer(&ixs, Some(&keypair.pubkey()), &[&keypair], blockhash);
+
+    // See comments on new_distributor instruction inside the program to ensure this transaction
+    // didn't get frontrun.
+    // If this fails, make sure to run it again.
+    match client.send_and_confirm_transaction_with_spinner(&tx) {
         Ok(_) => {}
         Err(e) => {
             println!("Failed to create MerkleDistributor: {:?}", e);
@@ -398,6 +1291,163 @@ fn process_new_distributor(args: &Args, new_distributor_args: &NewDistributorArg
             }
         }
     }
+
+    if merkle_tree.is_indexed() {
+        create_claim_bitmaps(&client, &distributor_ixs, &keypair, &merkle_tree);
+    }
+}
+
+/// Creates the missing claim bitmap chunks covering every leaf of an indexed tree
+fn create_claim_bitmaps(
+    client: &RpcClient,
+    distributor_ixs: &DistributorInstructions,
+    keypair: &Keypair,
+    merkle_tree: &AirdropMerkleTree,
+) {
+    for chunk_index in 0..ClaimBitmap::num_chunks(merkle_tree.max_num_nodes) {
+        if client
+            .get_account_with_commitment(
+                &distributor_ixs.claim_bitmap(chunk_index),
+                CommitmentConfig::confirmed(),
+            )
+            .unwrap()
+            .value
+            .is_some()
+        {
+            continue;
+        }
+
+        let tx = Transaction::new_signed_with_payer(
+            &[distributor_ixs.new_claim_bitmap(&keypair.pubkey(), chunk_index)],
+            Some(&keypair.pubkey()),
+            &[keypair],
+            client.get_latest_blockhash().unwrap(),
+        );
+
+        let signature = client
+            .send_and_confirm_transaction_with_spinner(&tx)
+            .unwrap();
+        println!("created claim bitmap {chunk_index} with signature {signature:#?}");
+    }
+}
+
+fn process_add_mint(args: &Args, add_mint_args: &AddMintArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let distributor_ixs = distributor_instructions(&client, args);
+    let distributor = fetch_distributor(&client, &distributor_ixs.distributor)
+        .expect("Failed fetching distributor account");
+    if !distributor.multi_mint {
+        panic!(
+            "distributor {} is not multi-mint",
+            distributor_ixs.distributor
+        );
+    }
+
+    let mint = add_mint_args.distributor_mint;
+    let mint_index = distributor.num_mints;
+    let token_program = fetch_token_program(&client, &mint).expect("Failed fetching mint account");
+
+    let mut ixs = vec![];
+    if let Some(priority_fee) = args.priority {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    ixs.push(create_associated_token_account_idempotent(
+        &keypair.pubkey(),
+        &distributor_ixs.distributor,
+        &mint,
+        &token_program,
+    ));
+    ixs.push(distributor_ixs.add_distributor_mint(
+        &keypair.pubkey(),
+        mint_index,
+        &mint,
+        &token_program,
+        add_mint_args.max_total_claim,
+    ));
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!(
+        "added mint {mint} with index {mint_index} and vault {} with signature {signature:#?}",
+        get_token_vault(&distributor_ixs.distributor, &mint, &token_program)
+    );
+}
+
+fn process_fund_distributor(args: &Args, fund_distributor_args: &FundDistributorArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let distributor_ixs = distributor_instructions(&client, args);
+
+    let amount = match fund_distributor_args.amount {
+        Some(amount) => amount,
+        None => {
+            let distributor = fetch_distributor(&client, &distributor_ixs.distributor)
+                .expect("Failed fetching distributor account");
+            let vault_amount = vault_balance(&client, &distributor_ixs);
+            distributor.remaining_claim().saturating_sub(vault_amount)
+        }
+    };
+    if amount == 0 {
+        println!("Vault already covers every remaining claim, nothing to fund");
+        return;
+    }
+
+    let fund_distributor_ix = if distributor_ixs.is_native() {
+        distributor_ixs.fund_distributor_native(&keypair.pubkey(), amount)
+    } else {
+        distributor_ixs.fund_distributor(
+            &keypair.pubkey(),
+            &distributor_ixs.associated_token_account(&keypair.pubkey()),
+            amount,
+        )
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[fund_distributor_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!("Successfully funded distributor with {amount}! signature: {signature:#?}");
+}
+
+/// Amount in the vault that can be claimed: its token balance, or its lamports above the rent
+/// exempt minimum for native SOL
+fn vault_balance(client: &RpcClient, distributor_ixs: &DistributorInstructions) -> u64 {
+    if distributor_ixs.is_native() {
+        let lamports = client
+            .get_balance(&distributor_ixs.token_vault)
+            .expect("Failed fetching vault balance");
+        let rent_exempt_minimum = client
+            .get_minimum_balance_for_rent_exemption(SolVault::LEN)
+            .expect("Failed fetching rent exempt minimum");
+        return lamports.saturating_sub(rent_exempt_minimum);
+    }
+    client
+        .get_token_account_balance(&distributor_ixs.token_vault)
+        .expect("Failed fetching vault balance")
+        .amount
+        .parse()
+        .unwrap()
 }
 
 fn process_clawback(args: &Args, clawback_args: &ClawbackArgs) {
@@ -405,28 +1455,16 @@ fn process_clawback(args: &Args, clawback_args: &ClawbackArgs) {
     let clawback_keypair = read_keypair_file(&clawback_args.clawback_keypair_path)
         .expect("Failed reading keypair file");
 
-    let clawback_ata = get_associated_token_address(&clawback_keypair.pubkey(), &args.mint);
-
     let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
 
-    let (distributor, _bump) =
-        get_merkle_distributor_pda(&args.program_id, &args.mint, args.airdrop_version);
+    let distributor_ixs = distributor_instructions(&client, args);
+    println!("from: {}", distributor_ixs.token_vault);
 
-    let from = get_associated_token_address(&distributor, &args.mint);
-    println!("from: {from}");
-
-    let clawback_ix = Instruction {
-        program_id: args.program_id,
-        accounts: merkle_distributor::accounts::Clawback {
-            distributor,
-            from,
-            to: clawback_ata,
-            claimant: clawback_keypair.pubkey(),
-            system_program: solana_program::system_program::ID,
-            token_program: token::ID,
-        }
-        .to_account_metas(None),
-        data: merkle_distributor::instruction::Clawback {}.data(),
+    let clawback_ix = if distributor_ixs.is_native() {
+        distributor_ixs.clawback_native(&clawback_keypair.pubkey(), &clawback_keypair.pubkey())
+    } else {
+        let clawback_ata = distributor_ixs.associated_token_account(&clawback_keypair.pubkey());
+        distributor_ixs.clawback(&clawback_keypair.pubkey(), &clawback_ata)
     };
 
     let tx = Transaction::new_signed_with_payer(
@@ -443,8 +1481,285 @@ fn process_clawback(args: &Args, clawback_args: &ClawbackArgs) {
     println!("Successfully clawed back funds! signature: {signature:#?}");
 }
 
+fn process_clawback_surplus(args: &Args) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let distributor_ixs = distributor_instructions(&client, args);
+    let distributor = fetch_distributor(&client, &distributor_ixs.distributor)
+        .expect("Failed fetching distributor account");
+
+    let clawback_surplus_ix =
+        distributor_ixs.clawback_surplus(&keypair.pubkey(), &distributor.clawback_receiver);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[clawback_surplus_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!("Successfully clawed back surplus! signature: {signature:#?}");
+}
+
+/// Revokes the claimant's unvested tokens, opening the claim on their behalf if they have not
+/// claimed yet
+fn process_revoke(args: &Args, revoke_args: &RevokeArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+    let claimant = revoke_args.claimant;
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let distributor_ixs = distributor_instructions(&client, args);
+    let distributor = fetch_distributor(&client, &distributor_ixs.distributor)
+        .expect("Failed fetching distributor account");
+
+    let mut ixs = vec![];
+    match fetch_claim_status(&client, &distributor_ixs.claim_status(&claimant))
+        .expect("Failed fetching claim status account")
+    {
+        Some(claim_status) => {
+            println!(
+                "revoking claim of {claimant} with {} of {} locked tokens withdrawn",
+                claim_status.locked_amount_withdrawn, claim_status.locked_amount
+            );
+            ixs.push(distributor_ixs.revoke(
+                &keypair.pubkey(),
+                &claimant,
+                &distributor.clawback_receiver,
+            ));
+        }
+        None => {
+            let merkle_tree = AirdropMerkleTree::new_from_file(&revoke_args.merkle_tree_path)
+                .expect("failed to load merkle tree from file");
+            let node = merkle_tree.get_node(&claimant);
+            println!(
+                "{claimant} has not claimed yet, sending their {} unlocked tokens to their ATA and revoking their {} locked tokens as of now",
+                node.amount_unlocked(),
+                node.amount_locked()
+            );
+            ixs.push(create_associated_token_account_idempotent(
+                &keypair.pubkey(),
+                &claimant,
+                &args.mint,
+                &distributor_ixs.token_program,
+            ));
+            ixs.push(
+                distributor_ixs
+                    .revoke_unclaimed(&keypair.pubkey(), &node, &distributor.clawback_receiver)
+                    .expect("Failed building revoke instruction"),
+            );
+        }
+    }
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!("Successfully revoked claim of {claimant}! signature: {signature:#?}");
+}
+
+fn process_transfer_claim(args: &Args, transfer_claim_args: &TransferClaimArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+    let new_claimant_keypair = read_keypair_file(&transfer_claim_args.new_claimant_keypair_path)
+        .expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let distributor_ixs = distributor_instructions(&client, args);
+    let transfer_claim_ix =
+        distributor_ixs.transfer_claim(&keypair.pubkey(), &new_claimant_keypair.pubkey());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_claim_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair, &new_claimant_keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!(
+        "Successfully moved claim to {}! signature: {signature:#?}",
+        new_claimant_keypair.pubkey()
+    );
+}
+
+fn process_recover_claim(args: &Args, recover_claim_args: &RecoverClaimArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let distributor_ixs = distributor_instructions(&client, args);
+
+    let mut signers = vec![keypair];
+    let recover_claim_ix = match &recover_claim_args.command {
+        RecoverClaimCommands::Propose {
+            claimant,
+            new_claimant,
+        } => distributor_ixs.propose_claim_recovery(&signers[0].pubkey(), claimant, new_claimant),
+        RecoverClaimCommands::Execute {
+            claimant,
+            new_claimant_keypair_path,
+        } => {
+            let claim_recovery =
+                fetch_claim_recovery(&client, &distributor_ixs.claim_recovery(claimant))
+                    .expect("Failed fetching claim recovery account")
+                    .expect("no claim recovery proposed for the claimant");
+            let new_claimant_keypair =
+                read_keypair_file(new_claimant_keypair_path).expect("Failed reading keypair file");
+            if new_claimant_keypair.pubkey() != claim_recovery.new_claimant {
+                panic!(
+                    "new claimant keypair {} does not match the proposed new claimant {}",
+                    new_claimant_keypair.pubkey(),
+                    claim_recovery.new_claimant
+                );
+            }
+            signers.push(new_claimant_keypair);
+            println!(
+                "recovering claim of {claimant} to {}, recoverable from {}",
+                claim_recovery.new_claimant, claim_recovery.recoverable_ts
+            );
+            distributor_ixs.recover_claim(
+                &signers[0].pubkey(),
+                claimant,
+                &claim_recovery.new_claimant,
+            )
+        }
+        RecoverClaimCommands::Cancel { claimant } => {
+            distributor_ixs.cancel_claim_recovery(&signers[0].pubkey(), claimant)
+        }
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[recover_claim_ix],
+        Some(&signers[0].pubkey()),
+        &signers.iter().collect::<Vec<_>>(),
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!(
+        "Successfully ran recover-claim {:?}! signature: {signature:#?}",
+        recover_claim_args.command
+    );
+}
+
+fn process_close_distributor(args: &Args, close_distributor_args: &CloseDistributorArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let distributor_ixs = distributor_instructions(&client, args);
+    let receiver = close_distributor_args
+        .receiver
+        .unwrap_or_else(|| keypair.pubkey());
+
+    let distributor = fetch_distributor(&client, &distributor_ixs.distributor)
+        .expect("Failed fetching distributor account");
+
+    // Closed claims chunks only exist for the claimants who closed their claim, there are too many
+    // chunks to close them along with the distributor
+    let chunk_indexes: Vec<u8> = (0..=u8::MAX).collect();
+    for chunk_indexes in chunk_indexes.chunks(CLOSE_CLOSED_CLAIMS_BATCH_SIZE) {
+        let closed_claims_pdas: Vec<Pubkey> = chunk_indexes
+            .iter()
+            .map(|chunk_index| distributor_ixs.closed_claims_chunk(*chunk_index))
+            .collect();
+        let accounts = client
+            .get_multiple_accounts(&closed_claims_pdas)
+            .expect("Failed fetching closed claims accounts");
+        let ixs: Vec<Instruction> = chunk_indexes
+            .iter()
+            .zip(accounts)
+            .filter(|(_, account)| account.is_some())
+            .map(|(chunk_index, _)| {
+                distributor_ixs.close_closed_claims(&keypair.pubkey(), *chunk_index, &receiver)
+            })
+            .collect();
+        if ixs.is_empty() {
+            continue;
+        }
+
+        let tx = Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&keypair.pubkey()),
+            &[&keypair],
+            client.get_latest_blockhash().unwrap(),
+        );
+        let signature = client
+            .send_and_confirm_transaction_with_spinner(&tx)
+            .unwrap();
+        println!(
+            "Closed {} closed claims chunks! signature: {signature:#?}",
+            ixs.len()
+        );
+    }
+
+    // Claim bitmaps are closed along with the distributor
+    let mut ixs = vec![];
+    if distributor.bitmap_claims {
+        for chunk_index in 0..ClaimBitmap::num_chunks(distributor.max_num_nodes) {
+            let claim_bitmap = distributor_ixs.claim_bitmap(chunk_index);
+            if fetch_claim_bitmap(&client, &claim_bitmap)
+                .expect("Failed fetching claim bitmap")
+                .is_none()
+            {
+                continue;
+            }
+            ixs.push(distributor_ixs.close_claim_bitmap(&keypair.pubkey(), chunk_index, &receiver));
+        }
+    }
+
+    ixs.push(distributor_ixs.close_distributor(&keypair.pubkey(), &receiver));
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!("Successfully closed distributor! signature: {signature:#?}");
+}
+
 fn process_create_merkle_tree(merkle_tree_args: &CreateMerkleTreeArgs) {
-    let merkle_tree = AirdropMerkleTree::new_from_csv(&merkle_tree_args.csv_path).unwrap();
+    let merkle_tree = if merkle_tree_args.cumulative {
+        let previous = merkle_tree_args
+            .previous_merkle_tree_path
+            .as_ref()
+            .map(|path| {
+                AirdropMerkleTree::new_from_file(path)
+                    .expect("failed to load previous merkle tree from file")
+            });
+        AirdropMerkleTree::new_cumulative_from_csv(previous.as_ref(), &merkle_tree_args.csv_path)
+            .unwrap()
+    } else if merkle_tree_args.bitmap_claims {
+        AirdropMerkleTree::new_from_csv_indexed(&merkle_tree_args.csv_path).unwrap()
+    } else {
+        AirdropMerkleTree::new_from_csv(&merkle_tree_args.csv_path).unwrap()
+    };
     merkle_tree.write_to_file(&merkle_tree_args.merkle_tree_path);
 }
 
@@ -453,18 +1768,14 @@ fn process_set_admin(args: &Args, set_admin_args: &SetAdminArgs) {
 
     let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
 
-    let (distributor, _bump) =
-        get_merkle_distributor_pda(&args.program_id, &args.mint, args.airdrop_version);
+    let distributor_ixs = distributor_instructions(&client, args);
 
-    let set_admin_ix = Instruction {
-        program_id: args.program_id,
-        accounts: merkle_distributor::accounts::SetAdmin {
-            distributor,
-            admin: keypair.pubkey(),
-            new_admin: set_admin_args.new_admin,
+    let set_admin_ix = match set_admin_args.command {
+        SetAdminCommands::Propose { new_admin } => {
+            distributor_ixs.set_admin(&keypair.pubkey(), &new_admin)
         }
-        .to_account_metas(None),
-        data: merkle_distributor::instruction::SetAdmin {}.data(),
+        SetAdminCommands::Accept => distributor_ixs.accept_admin(&keypair.pubkey()),
+        SetAdminCommands::Cancel => distributor_ixs.cancel_pending_admin(&keypair.pubkey()),
     };
 
     let tx = Transaction::new_signed_with_payer(
@@ -478,5 +1789,129 @@ fn process_set_admin(args: &Args, set_admin_args: &SetAdminArgs) {
         .send_and_confirm_transaction_with_spinner(&tx)
         .unwrap();
 
-    println!("Successfully set admin! signature: {signature:#?}");
+    println!(
+        "Successfully ran set-admin {:?}! signature: {signature:#?}",
+        set_admin_args.command
+    );
+}
+
+fn process_update_root(args: &Args, update_root_args: &UpdateRootArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let merkle_tree = AirdropMerkleTree::new_from_file(&update_root_args.merkle_tree_path)
+        .expect("failed to load merkle tree from file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let distributor_ixs = distributor_instructions(&client, args);
+
+    let distributor = fetch_distributor(&client, &distributor_ixs.distributor)
+        .expect("Failed fetching distributor account");
+    validate_tree_lockups(
+        &merkle_tree,
+        distributor.clawback_start_ts,
+        &distributor.vesting_schedule,
+    );
+
+    let update_root_ix = distributor_ixs.update_root(
+        &keypair.pubkey(),
+        merkle_tree.merkle_root,
+        merkle_tree.max_total_claim,
+        merkle_tree.max_num_nodes,
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_root_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!("Successfully updated root! signature: {signature:#?}");
+}
+
+fn process_update_timestamps(args: &Args, update_timestamps_args: &UpdateTimestampsArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let distributor_ixs = distributor_instructions(&client, args);
+    let distributor = fetch_distributor(&client, &distributor_ixs.distributor)
+        .expect("Failed fetching distributor account");
+
+    let update_timestamps_ix = distributor_ixs.update_timestamps(
+        &keypair.pubkey(),
+        update_timestamps_args
+            .start_vesting_ts
+            .unwrap_or(distributor.start_ts),
+        update_timestamps_args
+            .end_vesting_ts
+            .unwrap_or(distributor.end_ts),
+        update_timestamps_args
+            .clawback_start_ts
+            .unwrap_or(distributor.clawback_start_ts),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_timestamps_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!("Successfully updated timestamps! signature: {signature:#?}");
+}
+
+fn process_set_paused(args: &Args, paused: bool) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let distributor_ixs = distributor_instructions(&client, args);
+    let ix = if paused {
+        distributor_ixs.pause(&keypair.pubkey())
+    } else {
+        distributor_ixs.unpause(&keypair.pubkey())
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!("Successfully set paused to {paused}! signature: {signature:#?}");
+}
+
+fn process_migrate_distributor(args: &Args) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let ix = distributor_instructions(&client, args).migrate_distributor(&keypair.pubkey());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!("Successfully migrated distributor! signature: {signature:#?}");
 }
@@ -24,6 +24,9 @@ use crate::{
 // https://flawed.net.nz/2018/02/21/attacking-merkle-trees-with-a-second-preimage-attack
 const LEAF_PREFIX: &[u8] = &[0];
 
+/// Minimum delay between the end of a lockup and the clawback start, enforced by the program.
+const SECONDS_PER_DAY: i64 = 24 * 3600;
+
 /// Merkle Tree which will be used to distribute tokens to claimants.
 /// Contains all the information necessary to verify claims against the Merkle Tree.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,51 +36,98 @@ pub struct AirdropMerkleTree {
     pub max_num_nodes: u64,
     pub max_total_claim: u64,
     pub tree_nodes: Vec<TreeNode>,
+    /// Whether the leaves hold each claimant's cumulative entitlement, for cumulative distributors
+    #[serde(default)]
+    pub cumulative: bool,
 }
 
 pub type Result<T> = result::Result<T, MerkleTreeError>;
 
 impl AirdropMerkleTree {
     pub fn new(tree_nodes: Vec<TreeNode>) -> Result<Self> {
-        // Combine tree nodes with the same claimant, while retaining original order
-        let mut tree_nodes_map: IndexMap<Pubkey, TreeNode> = IndexMap::new();
+        Self::build(tree_nodes, false, false)
+    }
+
+    /// Builds a tree for a distributor with bitmap claims, every leaf commits to its position in the tree.
+    /// Indexed leaves can't have locked tokens or their own lockup.
+    pub fn new_indexed(tree_nodes: Vec<TreeNode>) -> Result<Self> {
+        Self::build(tree_nodes, true, false)
+    }
+
+    /// Builds a tree for a cumulative distributor. Each claimant's amounts are added to their amounts
+    /// in the previous tree, claimants keep their position and new claimants are appended.
+    /// Cumulative leaves can't have locked tokens or their own lockup.
+    pub fn new_cumulative(previous: Option<&Self>, tree_nodes: Vec<TreeNode>) -> Result<Self> {
+        let mut all_nodes = vec![];
+        if let Some(previous) = previous {
+            if !previous.cumulative {
+                return Err(MerkleValidationError(
+                    "Previous tree is not cumulative".to_string(),
+                ));
+            }
+            all_nodes.extend(previous.tree_nodes.iter().cloned().map(|mut n| {
+                n.proof = None;
+                n
+            }));
+        }
+        all_nodes.extend(tree_nodes);
+
+        Self::build(all_nodes, false, true)
+    }
+
+    fn build(tree_nodes: Vec<TreeNode>, indexed: bool, cumulative: bool) -> Result<Self> {
+        // Combine tree nodes with the same claimant and mint, while retaining original order
+        let mut tree_nodes_map: IndexMap<(Pubkey, Option<u8>), TreeNode> = IndexMap::new();
         for tree_node in tree_nodes {
-            let claimant = tree_node.claimant;
-            tree_nodes_map
-                .entry(claimant)
-                .and_modify(|n| {
-                    println!("duplicate claimant {} found, combining", n.claimant);
-                    n.total_unlocked_staker = n
-                        .total_unlocked_staker
-                        .checked_add(tree_node.total_unlocked_staker)
-                        .unwrap();
-                    n.total_locked_staker = n
-                        .total_locked_staker
-                        .checked_add(tree_node.total_locked_staker)
-                        .unwrap();
-                    n.total_unlocked_searcher = n
-                        .total_unlocked_searcher
-                        .checked_add(tree_node.total_unlocked_searcher)
-                        .unwrap();
-                    n.total_locked_searcher = n
-                        .total_locked_searcher
-                        .checked_add(tree_node.total_locked_searcher)
-                        .unwrap();
-                    n.total_unlocked_validator = n
-                        .total_unlocked_validator
-                        .checked_add(tree_node.total_unlocked_validator)
-                        .unwrap();
-                    n.total_locked_validator = n
-                        .total_locked_validator
-                        .checked_add(tree_node.total_locked_validator)
-                        .unwrap();
-                })
-                .or_insert_with(|| tree_node); // If not exists, insert a new entry
+            let key = (tree_node.claimant, tree_node.mint_index);
+            let Some(n) = tree_nodes_map.get_mut(&key) else {
+                // If not exists, insert a new entry
+                tree_nodes_map.insert(key, tree_node);
+                continue;
+            };
+
+            println!("duplicate claimant {} found, combining", n.claimant);
+            if (n.start_ts, n.end_ts) != (tree_node.start_ts, tree_node.end_ts) {
+                return Err(MerkleValidationError(format!(
+                    "Duplicate claimant {} has different lockups",
+                    n.claimant
+                )));
+            }
+            n.total_unlocked_staker = n
+                .total_unlocked_staker
+                .checked_add(tree_node.total_unlocked_staker)
+                .unwrap();
+            n.total_locked_staker = n
+                .total_locked_staker
+                .checked_add(tree_node.total_locked_staker)
+                .unwrap();
+            n.total_unlocked_searcher = n
+                .total_unlocked_searcher
+                .checked_add(tree_node.total_unlocked_searcher)
+                .unwrap();
+            n.total_locked_searcher = n
+                .total_locked_searcher
+                .checked_add(tree_node.total_locked_searcher)
+                .unwrap();
+            n.total_unlocked_validator = n
+                .total_unlocked_validator
+                .checked_add(tree_node.total_unlocked_validator)
+                .unwrap();
+            n.total_locked_validator = n
+                .total_locked_validator
+                .checked_add(tree_node.total_locked_validator)
+                .unwrap();
         }
 
         // Convert IndexMap back to Vec while preserving the order
         let mut tree_nodes: Vec<TreeNode> = tree_nodes_map.values().cloned().collect();
 
+        if indexed {
+            for (i, tree_node) in tree_nodes.iter_mut().enumerate() {
+                tree_node.index = Some(i as u64);
+            }
+        }
+
         let hashed_nodes = tree_nodes
             .iter()
             .map(|claim_info| claim_info.hash().to_bytes())
@@ -98,6 +148,7 @@ impl AirdropMerkleTree {
             max_num_nodes: tree_nodes.len() as u64,
             max_total_claim,
             tree_nodes,
+            cumulative,
         };
 
         println!(
@@ -116,6 +167,22 @@ impl AirdropMerkleTree {
         Ok(tree)
     }
 
+    /// Load a merkle tree for a distributor with bitmap claims from a csv path
+    pub fn new_from_csv_indexed(path: &PathBuf) -> Result<Self> {
+        let csv_entries = CsvEntry::new_from_file(path)?;
+        let tree_nodes: Vec<TreeNode> = csv_entries.into_iter().map(TreeNode::from).collect();
+        let tree = Self::new_indexed(tree_nodes)?;
+        Ok(tree)
+    }
+
+    /// Load a cumulative merkle tree from a csv path, adding to the amounts of the previous tree
+    pub fn new_cumulative_from_csv(previous: Option<&Self>, path: &PathBuf) -> Result<Self> {
+        let csv_entries = CsvEntry::new_from_file(path)?;
+        let tree_nodes: Vec<TreeNode> = csv_entries.into_iter().map(TreeNode::from).collect();
+        let tree = Self::new_cumulative(previous, tree_nodes)?;
+        Ok(tree)
+    }
+
     /// Load a serialized merkle tree from file path
     pub fn new_from_file(path: &PathBuf) -> Result<Self> {
         let file = File::open(path)?;
@@ -142,6 +209,67 @@ impl AirdropMerkleTree {
         panic!("Claimant not found in tree");
     }
 
+    /// Whether the tree was built for a distributor with bitmap claims
+    pub fn is_indexed(&self) -> bool {
+        self.tree_nodes.first().is_some_and(|n| n.index.is_some())
+    }
+
+    /// Whether the leaves commit to the mint of a multi-mint distributor
+    pub fn is_multi_mint(&self) -> bool {
+        self.tree_nodes
+            .first()
+            .is_some_and(|n| n.mint_index.is_some())
+    }
+
+    /// Returns every leaf of `claimant`, one per mint for multi-mint trees
+    pub fn get_nodes(&self, claimant: &Pubkey) -> Vec<TreeNode> {
+        self.tree_nodes
+            .iter()
+            .filter(|n| n.claimant == *claimant)
+            .cloned()
+            .collect()
+    }
+
+    /// Validates the lockups committed in the leaves against the distributor they are claimed from,
+    /// like the program does on claim: each lockup must end at least a day before
+    /// `clawback_start_ts`, last longer than the cliff offset of a cliff schedule and at least one
+    /// step period of a step schedule.
+    pub fn validate_lockups(
+        &self,
+        clawback_start_ts: i64,
+        cliff_offset: Option<i64>,
+        step_period: Option<i64>,
+    ) -> Result<()> {
+        for node in self.tree_nodes.iter() {
+            let (Some(start_ts), Some(end_ts)) = (node.start_ts, node.end_ts) else {
+                continue;
+            };
+
+            let valid_clawback = end_ts
+                .checked_add(SECONDS_PER_DAY)
+                .is_some_and(|min_clawback_start_ts| clawback_start_ts >= min_clawback_start_ts);
+            let total_unlock_time = end_ts.checked_sub(start_ts);
+            let valid_cliff = cliff_offset.map_or(true, |cliff_offset| {
+                total_unlock_time.is_some_and(|total_unlock_time| {
+                    cliff_offset >= 0 && cliff_offset < total_unlock_time
+                })
+            });
+            let valid_step = step_period.map_or(true, |period| {
+                total_unlock_time
+                    .is_some_and(|total_unlock_time| period > 0 && period <= total_unlock_time)
+            });
+
+            if !(valid_clawback && valid_cliff && valid_step) {
+                return Err(MerkleValidationError(format!(
+                    "Lockup start {} end {} for claimant {} does not fit the distributor's clawback start {} or vesting schedule",
+                    start_ts, end_ts, node.claimant, clawback_start_ts
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
     fn validate(&self) -> Result<()> {
         // The Merkle tree can be at most height 32, implying a max node count of 2^32 - 1
         if self.max_num_nodes > 2u64.pow(32) - 1 {
@@ -160,8 +288,12 @@ impl AirdropMerkleTree {
             )));
         }
 
-        // validate that there are no duplicate claimants
-        let unique_nodes: HashSet<_> = self.tree_nodes.iter().map(|n| n.claimant).collect();
+        // validate that there are no duplicate claimants, per mint for multi-mint trees
+        let unique_nodes: HashSet<_> = self
+            .tree_nodes
+            .iter()
+            .map(|n| (n.claimant, n.mint_index))
+            .collect();
 
         if unique_nodes.len() != self.tree_nodes.len() {
             return Err(MerkleValidationError(
@@ -169,6 +301,85 @@ impl AirdropMerkleTree {
             ));
         }
 
+        // validate that per-claimant lockups set both timestamps and start before they end
+        for node in self.tree_nodes.iter() {
+            match (node.start_ts, node.end_ts) {
+                (None, None) => {}
+                (Some(start_ts), Some(end_ts)) if start_ts < end_ts => {}
+                _ => {
+                    return Err(MerkleValidationError(format!(
+                        "Invalid lockup start {:?} end {:?} for claimant {}",
+                        node.start_ts, node.end_ts, node.claimant
+                    )));
+                }
+            }
+        }
+
+        // validate that either no leaf or every leaf is indexed by its position, without locked tokens
+        let indexed = self.is_indexed();
+        for (i, node) in self.tree_nodes.iter().enumerate() {
+            if !indexed {
+                if node.index.is_some() {
+                    return Err(MerkleValidationError(format!(
+                        "Indexed and non-indexed leaves mixed at claimant {}",
+                        node.claimant
+                    )));
+                }
+                continue;
+            }
+
+            if node.index != Some(i as u64) {
+                return Err(MerkleValidationError(format!(
+                    "Leaf index {:?} does not match position {} for claimant {}",
+                    node.index, i, node.claimant
+                )));
+            }
+            if node.amount_locked() != 0 || node.start_ts.is_some() || node.end_ts.is_some() {
+                return Err(MerkleValidationError(format!(
+                    "Indexed leaf for claimant {} has locked tokens or a lockup",
+                    node.claimant
+                )));
+            }
+        }
+
+        // validate that cumulative leaves only hold unlocked tokens
+        if self.cumulative {
+            for node in self.tree_nodes.iter() {
+                if node.amount_locked() != 0
+                    || node.start_ts.is_some()
+                    || node.end_ts.is_some()
+                    || node.index.is_some()
+                {
+                    return Err(MerkleValidationError(format!(
+                        "Cumulative leaf for claimant {} has locked tokens, a lockup or an index",
+                        node.claimant
+                    )));
+                }
+            }
+        }
+
+        // validate that either no leaf or every leaf commits to a mint, without a lockup or an index
+        let multi_mint = self.is_multi_mint();
+        for node in self.tree_nodes.iter() {
+            if node.mint_index.is_some() != multi_mint {
+                return Err(MerkleValidationError(format!(
+                    "Multi-mint and single mint leaves mixed at claimant {}",
+                    node.claimant
+                )));
+            }
+            if multi_mint
+                && (node.start_ts.is_some()
+                    || node.end_ts.is_some()
+                    || node.index.is_some()
+                    || self.cumulative)
+            {
+                return Err(MerkleValidationError(format!(
+                    "Multi-mint leaf for claimant {} has a lockup, an index or is cumulative",
+                    node.claimant
+                )));
+            }
+        }
+
         // validate that sum is equal to max_total_claim
         let sum = get_max_total_claim(&self.tree_nodes);
 
@@ -200,12 +411,15 @@ impl AirdropMerkleTree {
             .collect();
         let mk = MerkleTree::new(&hashed_nodes[..], true);
 
-        assert_eq!(
-            mk.get_root()
-                .ok_or(MerkleValidationError("invalid merkle proof".to_string()))?
-                .to_bytes(),
-            root
-        );
+        let mk_root = mk
+            .get_root()
+            .ok_or(MerkleValidationError("invalid merkle proof".to_string()))?
+            .to_bytes();
+        if mk_root != root {
+            return Err(MerkleValidationError(
+                "Merkle root does not match nodes".to_string(),
+            ));
+        }
 
         // Verify each node against the root
         for (i, _node) in hashed_nodes.iter().enumerate() {
@@ -262,13 +476,13 @@ mod tests {
             // choose amount unlocked and amount locked as a random u64 between 0 and 100
             tree_nodes.push(TreeNode {
                 claimant: new_test_key(),
-                proof: None,
                 total_unlocked_staker: rand_balance(),
                 total_locked_staker: rand_balance(),
                 total_unlocked_searcher: rand_balance(),
                 total_locked_searcher: rand_balance(),
                 total_unlocked_validator: rand_balance(),
                 total_locked_validator: rand_balance(),
+                ..TreeNode::default()
             });
         }
 
@@ -280,14 +494,13 @@ mod tests {
     #[test]
     fn test_verify_new_merkle_tree() {
         let tree_nodes = vec![TreeNode {
-            claimant: Pubkey::default(),
-            proof: None,
             total_unlocked_staker: 2,
             total_locked_staker: 3,
             total_unlocked_searcher: 4,
             total_locked_searcher: 5,
             total_unlocked_validator: 6,
             total_locked_validator: 7,
+            ..TreeNode::default()
         }];
         let merkle_tree = AirdropMerkleTree::new(tree_nodes).unwrap();
         assert!(merkle_tree.verify_proof().is_ok(), "verify failed");
@@ -299,33 +512,21 @@ mod tests {
         let tree_nodes = vec![
             TreeNode {
                 claimant: pubkey!("FLYqJsmJ5AGMxMxK3Qy1rSen4ES2dqqo6h51W3C1tYS"),
-                proof: None,
                 total_unlocked_staker: (100 * u64::pow(10, 9)),
                 total_locked_staker: (100 * u64::pow(10, 9)),
-                total_unlocked_searcher: 0,
-                total_locked_searcher: 0,
-                total_unlocked_validator: 0,
-                total_locked_validator: 0,
+                ..TreeNode::default()
             },
             TreeNode {
                 claimant: pubkey!("EDGARWktv3nDxRYjufjdbZmryqGXceaFPoPpbUzdpqED"),
-                proof: None,
                 total_unlocked_staker: 100 * u64::pow(10, 9),
                 total_locked_staker: (100 * u64::pow(10, 9)),
-                total_unlocked_searcher: 0,
-                total_locked_searcher: 0,
-                total_unlocked_validator: 0,
-                total_locked_validator: 0,
+                ..TreeNode::default()
             },
             TreeNode {
                 claimant: pubkey!("EDGARWktv3nDxRYjufjdbZmryqGXceaFPoPpbUzdpqEH"),
-                proof: None,
                 total_locked_staker: (100 * u64::pow(10, 9)),
                 total_unlocked_staker: (100 * u64::pow(10, 9)),
-                total_unlocked_searcher: 0,
-                total_locked_searcher: 0,
-                total_unlocked_validator: 0,
-                total_locked_validator: 0,
+                ..TreeNode::default()
             },
         ];
 
@@ -353,33 +554,27 @@ mod tests {
         let tree_nodes = vec![
             TreeNode {
                 claimant: duplicate_pubkey,
-                proof: None,
                 total_unlocked_staker: 10,
                 total_locked_staker: 20,
                 total_unlocked_searcher: 30,
                 total_locked_searcher: 40,
                 total_unlocked_validator: 50,
                 total_locked_validator: 60,
+                ..TreeNode::default()
             },
             TreeNode {
                 claimant: duplicate_pubkey,
-                proof: None,
                 total_unlocked_staker: 1,
                 total_locked_staker: 2,
                 total_unlocked_searcher: 3,
                 total_locked_searcher: 4,
                 total_unlocked_validator: 5,
                 total_locked_validator: 6,
+                ..TreeNode::default()
             },
             TreeNode {
                 claimant: Pubkey::new_unique(),
-                proof: None,
-                total_unlocked_staker: 0,
-                total_locked_staker: 0,
-                total_unlocked_searcher: 0,
-                total_locked_searcher: 0,
-                total_unlocked_validator: 0,
-                total_locked_validator: 0,
+                ..TreeNode::default()
             },
         ];
 
@@ -393,4 +588,171 @@ mod tests {
         assert_eq!(tree.tree_nodes[0].total_unlocked_validator, 55);
         assert_eq!(tree.tree_nodes[0].total_locked_validator, 66);
     }
+
+    #[test]
+    fn test_new_merkle_tree_with_lockups() {
+        let path = PathBuf::from("./test_fixtures/test_csv_lockup.csv");
+        let tree = AirdropMerkleTree::new_from_csv(&path).unwrap();
+
+        assert_eq!(tree.tree_nodes.len(), 3);
+        assert_eq!(tree.tree_nodes[0].start_ts, Some(1_700_000_000));
+        assert_eq!(tree.tree_nodes[0].end_ts, Some(1_731_536_000));
+        assert_eq!(tree.tree_nodes[2].start_ts, None);
+        assert!(tree.verify_proof().is_ok(), "verify failed");
+    }
+
+    #[test]
+    fn test_new_merkle_tree_invalid_lockup() {
+        let tree_node = TreeNode {
+            claimant: Pubkey::new_unique(),
+            total_unlocked_staker: 1,
+            total_locked_staker: 2,
+            start_ts: Some(200),
+            ..TreeNode::default()
+        };
+        assert!(AirdropMerkleTree::new(vec![tree_node.clone()]).is_err());
+
+        let tree_node = TreeNode {
+            end_ts: Some(100),
+            ..tree_node
+        };
+        assert!(AirdropMerkleTree::new(vec![tree_node]).is_err());
+    }
+
+    #[test]
+    fn test_new_merkle_tree_duplicate_claimant_different_lockups() {
+        let tree_node = TreeNode {
+            claimant: Pubkey::new_unique(),
+            total_unlocked_staker: 1,
+            total_locked_staker: 2,
+            start_ts: Some(100),
+            end_ts: Some(200),
+            ..TreeNode::default()
+        };
+        let tree_nodes = vec![
+            tree_node.clone(),
+            TreeNode {
+                end_ts: Some(300),
+                ..tree_node
+            },
+        ];
+        assert!(AirdropMerkleTree::new(tree_nodes).is_err());
+    }
+
+    #[test]
+    fn test_validate_lockups() {
+        let tree_node = TreeNode {
+            claimant: Pubkey::new_unique(),
+            total_unlocked_staker: 1,
+            total_locked_staker: 2,
+            start_ts: Some(100),
+            end_ts: Some(200),
+            ..TreeNode::default()
+        };
+        let tree = AirdropMerkleTree::new(vec![tree_node]).unwrap();
+        let clawback_start_ts = 200 + SECONDS_PER_DAY;
+
+        assert!(tree.validate_lockups(clawback_start_ts, None, None).is_ok());
+        assert!(tree
+            .validate_lockups(clawback_start_ts, Some(50), None)
+            .is_ok());
+        assert!(tree
+            .validate_lockups(clawback_start_ts, None, Some(100))
+            .is_ok());
+
+        // the lockup must end a day before the clawback
+        assert!(tree
+            .validate_lockups(clawback_start_ts - 1, None, None)
+            .is_err());
+        // the cliff must lie within the lockup
+        assert!(tree
+            .validate_lockups(clawback_start_ts, Some(100), None)
+            .is_err());
+        assert!(tree
+            .validate_lockups(clawback_start_ts, Some(-1), None)
+            .is_err());
+        // the lockup must last at least one step period
+        assert!(tree
+            .validate_lockups(clawback_start_ts, None, Some(101))
+            .is_err());
+    }
+
+    #[test]
+    fn test_new_indexed_merkle_tree() {
+        let tree_node = TreeNode {
+            claimant: Pubkey::new_unique(),
+            total_unlocked_staker: 1,
+            ..TreeNode::default()
+        };
+        let tree_nodes = vec![
+            tree_node.clone(),
+            TreeNode {
+                claimant: Pubkey::new_unique(),
+                ..tree_node.clone()
+            },
+        ];
+
+        let tree = AirdropMerkleTree::new_indexed(tree_nodes).unwrap();
+        assert_eq!(tree.tree_nodes[0].index, Some(0));
+        assert_eq!(tree.tree_nodes[1].index, Some(1));
+        assert!(tree.verify_proof().is_ok(), "verify failed");
+
+        // indexed leaves can't carry locked tokens
+        let tree_node = TreeNode {
+            total_locked_staker: 1,
+            ..tree_node
+        };
+        assert!(AirdropMerkleTree::new_indexed(vec![tree_node]).is_err());
+    }
+
+    #[test]
+    fn test_new_cumulative_merkle_tree() {
+        let path = PathBuf::from("./test_fixtures/test_csv_cumulative.csv");
+        let first = AirdropMerkleTree::new_cumulative_from_csv(None, &path).unwrap();
+        assert!(first.cumulative);
+
+        let second = AirdropMerkleTree::new_cumulative_from_csv(Some(&first), &path).unwrap();
+        assert_eq!(second.tree_nodes.len(), first.tree_nodes.len());
+        assert_eq!(second.max_total_claim, 2 * first.max_total_claim);
+        for (previous, node) in first.tree_nodes.iter().zip(second.tree_nodes.iter()) {
+            assert_eq!(previous.claimant, node.claimant);
+            assert_eq!(node.amount_unlocked(), 2 * previous.amount_unlocked());
+        }
+        assert!(second.verify_proof().is_ok(), "verify failed");
+
+        // a cumulative tree can only build on another cumulative tree
+        let tree = AirdropMerkleTree::new_from_csv(&path).unwrap();
+        assert!(AirdropMerkleTree::new_cumulative_from_csv(Some(&tree), &path).is_err());
+
+        // cumulative leaves can't carry locked tokens
+        let path = PathBuf::from("./test_fixtures/test_csv.csv");
+        assert!(AirdropMerkleTree::new_cumulative_from_csv(None, &path).is_err());
+    }
+
+    #[test]
+    fn test_new_multi_mint_merkle_tree() {
+        let path = PathBuf::from("./test_fixtures/test_csv_multi_mint.csv");
+        let tree = AirdropMerkleTree::new_from_csv(&path).unwrap();
+        assert!(tree.is_multi_mint());
+
+        // one leaf per claimant and mint, the two entries of the second claimant's mint 1 combined
+        assert_eq!(tree.tree_nodes.len(), 4);
+        let claimant = pubkey!("8G9xE8awr9vA2PZWFTJSHNhS16KLnXYdV6XEaJP1a2Yx");
+        let nodes = tree.get_nodes(&claimant);
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[0].mint_index, Some(0));
+        assert_eq!(nodes[1].mint_index, Some(1));
+        assert_eq!(nodes[1].amount_unlocked(), 100 * u64::pow(10, 9));
+        assert!(tree.verify_proof().is_ok(), "verify failed");
+
+        // every leaf commits to a mint, without a lockup
+        let mut tree_nodes = tree.tree_nodes.clone();
+        tree_nodes[0].mint_index = None;
+        assert!(AirdropMerkleTree::new(tree_nodes).is_err());
+
+        let mut tree_nodes = tree.tree_nodes.clone();
+        tree_nodes[0].start_ts = Some(100);
+        tree_nodes[0].end_ts = Some(200);
+        assert!(AirdropMerkleTree::new(tree_nodes).is_err());
+    }
 }
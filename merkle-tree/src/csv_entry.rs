@@ -25,6 +25,15 @@ pub struct CsvEntry {
     pub amount_locked: u64,
     /// Category
     pub category: AirdropCategory,
+    /// Optional lockup start (unix timestamp), overrides the distributor's lockup
+    #[serde(default)]
+    pub start_ts: Option<i64>,
+    /// Optional lockup end (unix timestamp), overrides the distributor's lockup
+    #[serde(default)]
+    pub end_ts: Option<i64>,
+    /// Optional index of the mint of a multi-mint distributor the amounts are paid in
+    #[serde(default)]
+    pub mint_index: Option<u8>,
 }
 
 impl CsvEntry {
@@ -60,5 +69,39 @@ mod tests {
         assert_eq!(entries[0].amount_unlocked, 1000);
         assert_eq!(entries[0].amount_locked, 500);
         assert_eq!(entries[0].category, AirdropCategory::Staker);
+        assert_eq!(entries[0].start_ts, None);
+        assert_eq!(entries[0].end_ts, None);
+    }
+
+    #[test]
+    fn test_csv_parsing_with_lockup() {
+        let path = PathBuf::from("./test_fixtures/test_csv_lockup.csv");
+        let entries = CsvEntry::new_from_file(&path).expect("Failed to parse CSV");
+
+        assert_eq!(entries.len(), 3);
+
+        assert_eq!(entries[0].start_ts, Some(1_700_000_000));
+        assert_eq!(entries[0].end_ts, Some(1_731_536_000));
+        assert_eq!(entries[1].start_ts, Some(1_700_000_000));
+        assert_eq!(entries[1].end_ts, Some(1_763_072_000));
+        // empty columns fall back to the distributor's lockup
+        assert_eq!(entries[2].start_ts, None);
+        assert_eq!(entries[2].end_ts, None);
+    }
+
+    #[test]
+    fn test_csv_parsing_with_mint_index() {
+        let path = PathBuf::from("./test_fixtures/test_csv_multi_mint.csv");
+        let entries = CsvEntry::new_from_file(&path).expect("Failed to parse CSV");
+
+        assert_eq!(entries.len(), 5);
+        assert_eq!(entries[0].mint_index, Some(0));
+        assert_eq!(entries[1].mint_index, Some(1));
+        assert_eq!(entries[1].start_ts, None);
+
+        // files without the column are single mint
+        let path = PathBuf::from("./test_fixtures/test_csv.csv");
+        let entries = CsvEntry::new_from_file(&path).expect("Failed to parse CSV");
+        assert_eq!(entries[0].mint_index, None);
     }
 }
@@ -8,7 +8,7 @@ use crate::csv_entry::{AirdropCategory, CsvEntry};
 pub const MINT_DECIMALS: u32 = 9;
 
 /// Represents the claim information for an account.
-#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct TreeNode {
     /// Pubkey of the claimant; will be responsible for signing the claim
     pub claimant: Pubkey,
@@ -26,15 +26,53 @@ pub struct TreeNode {
     pub total_unlocked_validator: u64,
     /// Total amount locked under validator allocation
     pub total_locked_validator: u64,
+    /// Lockup start (Unix Timestamp) for this claimant. Uses the distributor's lockup if None
+    #[serde(default)]
+    pub start_ts: Option<i64>,
+    /// Lockup end (Unix Timestamp) for this claimant. Uses the distributor's lockup if None
+    #[serde(default)]
+    pub end_ts: Option<i64>,
+    /// Position of the leaf in trees built for bitmap claims, committed in the leaf. None otherwise
+    #[serde(default)]
+    pub index: Option<u64>,
+    /// Index of the mint of multi-mint distributors the amounts are paid in, committed in the leaf.
+    /// None otherwise
+    #[serde(default)]
+    pub mint_index: Option<u8>,
 }
 
 impl TreeNode {
+    /// Leaves with their own lockup also commit to start_ts and end_ts,
+    /// indexed leaves commit to their index and multi-mint leaves to their mint index
     pub fn hash(&self) -> Hash {
-        hashv(&[
-            &self.claimant.to_bytes(),
-            &self.amount_unlocked().to_le_bytes(),
-            &self.amount_locked().to_le_bytes(),
-        ])
+        if let Some(mint_index) = self.mint_index {
+            return hashv(&[
+                &self.claimant.to_bytes(),
+                &[mint_index],
+                &self.amount_unlocked().to_le_bytes(),
+                &self.amount_locked().to_le_bytes(),
+            ]);
+        }
+        match (self.start_ts, self.end_ts, self.index) {
+            (_, _, Some(index)) => hashv(&[
+                &self.claimant.to_bytes(),
+                &self.amount_unlocked().to_le_bytes(),
+                &self.amount_locked().to_le_bytes(),
+                &index.to_le_bytes(),
+            ]),
+            (Some(start_ts), Some(end_ts), None) => hashv(&[
+                &self.claimant.to_bytes(),
+                &self.amount_unlocked().to_le_bytes(),
+                &self.amount_locked().to_le_bytes(),
+                &start_ts.to_le_bytes(),
+                &end_ts.to_le_bytes(),
+            ]),
+            _ => hashv(&[
+                &self.claimant.to_bytes(),
+                &self.amount_unlocked().to_le_bytes(),
+                &self.amount_locked().to_le_bytes(),
+            ]),
+        }
     }
 
     /// Return total amount of locked and unlocked amount for this claimant
@@ -79,6 +117,10 @@ impl From<CsvEntry> for TreeNode {
             total_locked_searcher: 0,
             total_unlocked_validator: 0,
             total_locked_validator: 0,
+            start_ts: entry.start_ts,
+            end_ts: entry.end_ts,
+            index: None,
+            mint_index: entry.mint_index,
         };
 
         // CSV entry uses UI amounts; we convert to native amounts here
@@ -109,20 +151,85 @@ mod tests {
     #[test]
     fn test_serialize_tree_node() {
         let tree_node = TreeNode {
-            claimant: Pubkey::default(),
-            proof: None,
-            total_unlocked_staker: 0,
-            total_locked_staker: 0,
-            total_unlocked_searcher: 0,
-            total_locked_searcher: 0,
-            total_unlocked_validator: 0,
-            total_locked_validator: 0,
+            start_ts: Some(1),
+            end_ts: Some(2),
+            ..TreeNode::default()
         };
         let serialized = serde_json::to_string(&tree_node).unwrap();
         let deserialized: TreeNode = serde_json::from_str(&serialized).unwrap();
         assert_eq!(tree_node, deserialized);
     }
 
+    #[test]
+    fn test_deserialize_tree_node_without_lockup() {
+        let tree_node = TreeNode {
+            total_unlocked_staker: 1,
+            total_locked_staker: 2,
+            ..TreeNode::default()
+        };
+        // trees written before per-claimant lockups don't have the fields at all
+        let mut serialized = serde_json::to_value(&tree_node).unwrap();
+        let fields = serialized.as_object_mut().unwrap();
+        fields.remove("start_ts");
+        fields.remove("end_ts");
+        fields.remove("index");
+        fields.remove("mint_index");
+
+        let deserialized: TreeNode = serde_json::from_value(serialized).unwrap();
+        assert_eq!(tree_node, deserialized);
+    }
+
+    #[test]
+    fn test_hash_commits_to_lockup() {
+        let mut tree_node = TreeNode {
+            total_unlocked_staker: 1,
+            total_locked_staker: 2,
+            ..TreeNode::default()
+        };
+        let hash = tree_node.hash();
+
+        tree_node.start_ts = Some(100);
+        tree_node.end_ts = Some(200);
+        let hash_with_lockup = tree_node.hash();
+        assert_ne!(hash, hash_with_lockup);
+
+        tree_node.end_ts = Some(300);
+        assert_ne!(hash_with_lockup, tree_node.hash());
+    }
+
+    #[test]
+    fn test_hash_commits_to_index() {
+        let mut tree_node = TreeNode {
+            total_unlocked_staker: 1,
+            ..TreeNode::default()
+        };
+        let hash = tree_node.hash();
+
+        tree_node.index = Some(0);
+        let hash_with_index = tree_node.hash();
+        assert_ne!(hash, hash_with_index);
+
+        tree_node.index = Some(1);
+        assert_ne!(hash_with_index, tree_node.hash());
+    }
+
+    #[test]
+    fn test_hash_commits_to_mint_index() {
+        let mut tree_node = TreeNode {
+            total_unlocked_staker: 1,
+            total_locked_staker: 2,
+            ..TreeNode::default()
+        };
+        let hash = tree_node.hash();
+
+        tree_node.mint_index = Some(0);
+        let hash_with_mint_index = tree_node.hash();
+        assert_ne!(hash, hash_with_mint_index);
+
+        tree_node.mint_index = Some(1);
+        assert_ne!(hash_with_mint_index, tree_node.hash());
+    }
+
     #[test]
     fn test_ui_amount_to_token_amount() {
         let ui_amount = 5;
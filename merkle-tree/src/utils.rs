@@ -55,6 +55,109 @@ pub fn get_claim_status_pda(
     )
 }
 
+pub fn get_cumulative_claim_status_pda(
+    program_id: &Pubkey,
+    claimant: &Pubkey,
+    distributor: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"CumulativeClaimStatus".as_ref(),
+            claimant.to_bytes().as_ref(),
+            distributor.to_bytes().as_ref(),
+        ],
+        program_id,
+    )
+}
+
+pub fn get_claim_bitmap_pda(
+    program_id: &Pubkey,
+    distributor: &Pubkey,
+    chunk_index: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"ClaimBitmap".as_ref(),
+            distributor.to_bytes().as_ref(),
+            chunk_index.to_le_bytes().as_ref(),
+        ],
+        program_id,
+    )
+}
+
+pub fn get_distributor_mint_pda(
+    program_id: &Pubkey,
+    distributor: &Pubkey,
+    mint_index: u8,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"DistributorMint".as_ref(),
+            distributor.to_bytes().as_ref(),
+            [mint_index].as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Claim status of the leaf of `claimant` for `mint_index` in a multi-mint distributor
+pub fn get_multi_mint_claim_status_pda(
+    program_id: &Pubkey,
+    claimant: &Pubkey,
+    distributor: &Pubkey,
+    mint_index: u8,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"ClaimStatus".as_ref(),
+            claimant.to_bytes().as_ref(),
+            distributor.to_bytes().as_ref(),
+            [mint_index].as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Claim recovery of `claimant` proposed by the admin of `distributor`
+pub fn get_claim_recovery_pda(
+    program_id: &Pubkey,
+    claimant: &Pubkey,
+    distributor: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"ClaimRecovery".as_ref(),
+            claimant.to_bytes().as_ref(),
+            distributor.to_bytes().as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Chunk `chunk_index` of the closed claims of `distributor`, recording the claimants whose pubkey
+/// starts with that byte
+pub fn get_closed_claims_pda(
+    program_id: &Pubkey,
+    distributor: &Pubkey,
+    chunk_index: u8,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"ClosedClaims".as_ref(),
+            distributor.to_bytes().as_ref(),
+            &[chunk_index],
+        ],
+        program_id,
+    )
+}
+
+pub fn get_sol_vault_pda(program_id: &Pubkey, distributor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"SolVault".as_ref(), distributor.to_bytes().as_ref()],
+        program_id,
+    )
+}
+
 #[derive(Debug)]
 pub struct MerkleValidationError {
     pub msg: String,
@@ -75,13 +178,13 @@ mod tests {
     ) -> TreeNode {
         TreeNode {
             claimant,
-            proof: None,
             total_unlocked_staker,
             total_locked_staker,
             total_unlocked_searcher,
             total_locked_searcher,
             total_unlocked_validator,
             total_locked_validator,
+            ..TreeNode::default()
         }
     }
 
@@ -39,4 +39,66 @@ pub enum ErrorCode {
     TimestampsNotInFuture,
     #[msg("Airdrop Version Mismatch")]
     InvalidVersion,
+    #[msg("Claim still has locked tokens to withdraw")]
+    LockedTokensRemaining,
+    #[msg("Claim has already been closed")]
+    ClaimAlreadyClosed,
+    #[msg("Vesting schedule does not fit between start and end timestamps")]
+    InvalidVestingSchedule,
+    #[msg("Distributor does not allow claiming on behalf of claimants")]
+    ClaimOnBehalfDisabled,
+    #[msg("Root can no longer be updated")]
+    RootLocked,
+    #[msg("Distributor is paused")]
+    DistributorPaused,
+    #[msg("Distributor has not been clawed back")]
+    DistributorNotClawedBack,
+    #[msg("Instruction does not match how the distributor tracks claims")]
+    ClaimModeMismatch,
+    #[msg("Leaf has already been claimed")]
+    LeafAlreadyClaimed,
+    #[msg("Claim bitmap does not track the leaf index")]
+    ClaimBitmapMismatch,
+    #[msg("Vault holds fewer tokens than can still be claimed")]
+    VaultUnderfunded,
+    #[msg("Vault holds no tokens beyond what can still be claimed")]
+    NoSurplus,
+    #[msg("Clawback start can only be moved later")]
+    ClawbackStartNotExtended,
+    #[msg("Vesting has already started")]
+    VestingAlreadyStarted,
+    #[msg("Claim deadline has passed")]
+    ClaimDeadlinePassed,
+    #[msg("Claim deadline cannot be after clawback start")]
+    ClaimDeadlineAfterClawback,
+    #[msg("Missing attestation of the claimant by the attestation signer")]
+    MissingAttestation,
+    #[msg("Attestation has expired")]
+    AttestationExpired,
+    #[msg("Instruction does not match the asset the distributor distributes")]
+    AssetKindMismatch,
+    #[msg("Vault holds fewer lamports than the transfer")]
+    InsufficientVaultBalance,
+    #[msg("Mint index does not match the mints registered with the distributor")]
+    MintIndexMismatch,
+    #[msg("Claim fee exceeds the claim or has no fee receiver")]
+    InvalidClaimFee,
+    #[msg("Account does not belong to the fee receiver")]
+    FeeReceiverMismatch,
+    #[msg("Missing the fee receiver account the claim fee is paid to")]
+    MissingFeeReceiver,
+    #[msg("Claim has no unvested tokens to revoke")]
+    NothingToRevoke,
+    #[msg("Claim recovery delay must be positive")]
+    InvalidClaimRecoveryDelay,
+    #[msg("Distributor does not let the admin recover claims")]
+    ClaimRecoveryDisabled,
+    #[msg("Claim recovery delay has not passed")]
+    ClaimRecoveryTimelocked,
+    #[msg("New claimant does not match the proposed claim recovery")]
+    NewClaimantMismatch,
+    #[msg("Account is already migrated")]
+    AlreadyMigrated,
+    #[msg("Distributor does not let the admin revoke claims")]
+    RevokeDisabled,
 }
@@ -0,0 +1,43 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{admin_event::AdminChangedEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::accept_admin] accounts.
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Pending admin signer
+    #[account(address = distributor.pending_admin @ ErrorCode::Unauthorized)]
+    pub pending_admin: Signer<'info>,
+}
+
+/// Makes the pending admin proposed with [set_admin](crate::instructions::handle_set_admin) the admin.
+#[allow(clippy::result_large_err)]
+pub fn handle_accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    let old_admin = distributor.admin;
+    distributor.admin = distributor.pending_admin;
+    distributor.pending_admin = Pubkey::default();
+
+    // Note: might get truncated, do not rely on
+    msg!("set new admin to {}", distributor.admin);
+    emit!(AdminChangedEvent {
+        distributor: distributor.key(),
+        old_admin,
+        new_admin: distributor.admin,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        admin_event::ClawbackReceiverChangedEvent, asset_kind::AssetKind,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::accept_clawback_receiver] accounts.
+#[derive(Accounts)]
+pub struct AcceptClawbackReceiver<'info> {
+    /// The token [MerkleDistributor].
+    #[account(
+        mut,
+        constraint = distributor.asset_kind == AssetKind::Token @ ErrorCode::AssetKindMismatch
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Pending clawback receiver token account
+    #[account(
+        token::mint = distributor.mint,
+        address = distributor.pending_clawback_receiver @ ErrorCode::Unauthorized
+    )]
+    pub new_clawback_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Owner of the pending clawback receiver token account
+    #[account(address = new_clawback_account.owner @ ErrorCode::OwnerMismatch)]
+    pub owner: Signer<'info>,
+}
+
+/// Makes the pending clawback receiver proposed with
+/// [set_clawback_receiver](crate::instructions::handle_set_clawback_receiver) the clawback receiver.
+#[allow(clippy::result_large_err)]
+pub fn handle_accept_clawback_receiver(ctx: Context<AcceptClawbackReceiver>) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    let old_clawback_receiver = distributor.clawback_receiver;
+    distributor.clawback_receiver = distributor.pending_clawback_receiver;
+    distributor.pending_clawback_receiver = Pubkey::default();
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "set new clawback receiver ATA to {}, owned by {}",
+        distributor.clawback_receiver,
+        ctx.accounts.owner.key()
+    );
+    emit!(ClawbackReceiverChangedEvent {
+        distributor: distributor.key(),
+        old_clawback_receiver,
+        new_clawback_receiver: distributor.clawback_receiver,
+    });
+
+    Ok(())
+}
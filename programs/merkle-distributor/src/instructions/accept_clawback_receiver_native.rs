@@ -0,0 +1,56 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        admin_event::ClawbackReceiverChangedEvent, asset_kind::AssetKind,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::accept_clawback_receiver_native] accounts.
+#[derive(Accounts)]
+pub struct AcceptClawbackReceiverNative<'info> {
+    /// The native SOL [MerkleDistributor].
+    #[account(
+        mut,
+        constraint = distributor.asset_kind == AssetKind::NativeSol @ ErrorCode::AssetKindMismatch
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Pending clawback receiver wallet
+    #[account(address = distributor.pending_clawback_receiver @ ErrorCode::Unauthorized)]
+    pub new_clawback_receiver: Signer<'info>,
+}
+
+/// Makes the pending clawback receiver proposed with
+/// [set_clawback_receiver_native](crate::instructions::handle_set_clawback_receiver_native) the
+/// clawback receiver of a native SOL distributor.
+#[allow(clippy::result_large_err)]
+pub fn handle_accept_clawback_receiver_native(
+    ctx: Context<AcceptClawbackReceiverNative>,
+) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    let old_clawback_receiver = distributor.clawback_receiver;
+    distributor.clawback_receiver = distributor.pending_clawback_receiver;
+    distributor.pending_clawback_receiver = Pubkey::default();
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "set new clawback receiver to {}",
+        distributor.clawback_receiver
+    );
+    emit!(ClawbackReceiverChangedEvent {
+        distributor: distributor.key(),
+        old_clawback_receiver,
+        new_clawback_receiver: distributor.clawback_receiver,
+    });
+
+    Ok(())
+}
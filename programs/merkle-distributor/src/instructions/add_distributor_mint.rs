@@ -0,0 +1,114 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        distributor_event::DistributorMintAddedEvent, distributor_mint::DistributorMint,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::add_distributor_mint] accounts.
+#[derive(Accounts)]
+#[instruction(mint_index: u8)]
+pub struct AddDistributorMint<'info> {
+    /// The [MerkleDistributor].
+    #[account(
+        mut,
+        constraint = distributor.multi_mint @ ErrorCode::ClaimModeMismatch,
+        constraint = mint_index == distributor.num_mints @ ErrorCode::MintIndexMismatch,
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// [DistributorMint] PDA of the mint index.
+    #[account(
+        init,
+        seeds = [
+            b"DistributorMint".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            [mint_index].as_ref()
+        ],
+        bump,
+        space = DistributorMint::LEN,
+        payer = admin
+    )]
+    pub distributor_mint: Account<'info, DistributorMint>,
+
+    /// The mint to distribute.
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Distributor ATA of the mint, created beforehand. For the distributor's own mint this is
+    /// its token vault.
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = distributor,
+        associated_token::token_program = token_program,
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Admin signer, pays for the [DistributorMint].
+    #[account(mut, address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+
+    /// The [Associated Token] program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// The [Token] or [Token-2022] program that owns the mint.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Registers the next mint of a multi-mint distributor and its vault, the leaves committing to
+/// mint_index can be claimed from then on, up to max_total_claim tokens of the mint.
+/// CHECK:
+///     1. The distributor is multi-mint
+///     2. Mints are registered in order of their index
+///     3. The vault is the distributor's associated token account of the mint
+#[allow(clippy::result_large_err)]
+pub fn handle_add_distributor_mint(
+    ctx: Context<AddDistributorMint>,
+    mint_index: u8,
+    max_total_claim: u64,
+) -> Result<()> {
+    let distributor_mint = &mut ctx.accounts.distributor_mint;
+
+    distributor_mint.distributor = ctx.accounts.distributor.key();
+    distributor_mint.mint_index = mint_index;
+    distributor_mint.bump = *ctx.bumps.get("distributor_mint").unwrap();
+    distributor_mint.mint = ctx.accounts.mint.key();
+    distributor_mint.token_vault = ctx.accounts.token_vault.key();
+    distributor_mint.max_total_claim = max_total_claim;
+    distributor_mint.total_amount_claimed = 0;
+    distributor_mint.clawed_back = false;
+
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.num_mints = distributor
+        .num_mints
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Added mint {} with index {}, vault {} and max_total_claim {}",
+        distributor_mint.mint,
+        mint_index,
+        distributor_mint.token_vault,
+        max_total_claim
+    );
+    emit!(DistributorMintAddedEvent {
+        distributor: distributor.key(),
+        mint_index,
+        mint: distributor_mint.mint,
+        token_vault: distributor_mint.token_vault,
+        max_total_claim,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        admin_event::ClaimRecoveryCancelledEvent, claim_recovery::ClaimRecovery,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::cancel_claim_recovery] accounts.
+#[derive(Accounts)]
+pub struct CancelClaimRecovery<'info> {
+    /// The [MerkleDistributor].
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim Recovery PDA, closed to the admin.
+    #[account(
+        mut,
+        close = admin,
+        has_one = distributor,
+    )]
+    pub claim_recovery: Account<'info, ClaimRecovery>,
+
+    /// Admin signer
+    #[account(mut, address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+}
+
+/// Cancels a proposed claim recovery, returning its rent to the admin. Also cleans up recoveries
+/// that can no longer be executed because the claimant moved or closed the claim.
+/// CHECK:
+///     1. The admin signed the transaction
+#[allow(clippy::result_large_err)]
+pub fn handle_cancel_claim_recovery(ctx: Context<CancelClaimRecovery>) -> Result<()> {
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Cancelled recovery of the claim of {}",
+        ctx.accounts.claim_recovery.claimant
+    );
+    emit!(ClaimRecoveryCancelledEvent {
+        distributor: ctx.accounts.distributor.key(),
+        claimant: ctx.accounts.claim_recovery.claimant,
+    });
+
+    Ok(())
+}
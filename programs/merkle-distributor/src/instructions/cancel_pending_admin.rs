@@ -0,0 +1,41 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{admin_event::PendingAdminSetEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::cancel_pending_admin] accounts.
+#[derive(Accounts)]
+pub struct CancelPendingAdmin<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+}
+
+/// Cancels the pending admin proposed with [set_admin](crate::instructions::handle_set_admin).
+#[allow(clippy::result_large_err)]
+pub fn handle_cancel_pending_admin(ctx: Context<CancelPendingAdmin>) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    // Note: might get truncated, do not rely on
+    msg!("cancelled pending admin {}", distributor.pending_admin);
+
+    distributor.pending_admin = Pubkey::default();
+
+    emit!(PendingAdminSetEvent {
+        distributor: distributor.key(),
+        pending_admin: distributor.pending_admin,
+    });
+
+    Ok(())
+}
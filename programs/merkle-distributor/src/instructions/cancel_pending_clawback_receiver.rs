@@ -0,0 +1,47 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{admin_event::PendingClawbackReceiverSetEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::cancel_pending_clawback_receiver] accounts.
+#[derive(Accounts)]
+pub struct CancelPendingClawbackReceiver<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+}
+
+/// Cancels the pending clawback receiver proposed with
+/// [set_clawback_receiver](crate::instructions::handle_set_clawback_receiver).
+#[allow(clippy::result_large_err)]
+pub fn handle_cancel_pending_clawback_receiver(
+    ctx: Context<CancelPendingClawbackReceiver>,
+) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "cancelled pending clawback receiver {}",
+        distributor.pending_clawback_receiver
+    );
+
+    distributor.pending_clawback_receiver = Pubkey::default();
+
+    emit!(PendingClawbackReceiverSetEvent {
+        distributor: distributor.key(),
+        pending_clawback_receiver: distributor.pending_clawback_receiver,
+    });
+
+    Ok(())
+}
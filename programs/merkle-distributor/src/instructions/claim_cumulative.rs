@@ -0,0 +1,87 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    instructions::new_cumulative_claim::process_cumulative_claim,
+    state::{
+        cumulative_claim_status::CumulativeClaimStatus, merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::claim_cumulative] accounts.
+#[derive(Accounts)]
+pub struct ClaimCumulative<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Cumulative claim status PDA
+    #[account(
+        mut,
+        seeds = [
+            b"CumulativeClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, CumulativeClaimStatus>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = claimant.key(),
+        token::token_program = token_program,
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Who is claiming the tokens.
+    #[account(address = to.owner @ ErrorCode::OwnerMismatch)]
+    pub claimant: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or [Token-2022] program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: The instructions sysvar, holds the attestation.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+/// Claims what a claimant has earned since their last claim from a cumulative [MerkleDistributor],
+/// once the admin posted a root with a higher cumulative amount for them.
+/// CHECK:
+///     1. All checks of [process_cumulative_claim](crate::instructions::new_cumulative_claim::process_cumulative_claim)
+#[allow(clippy::result_large_err)]
+pub fn handle_claim_cumulative(
+    ctx: Context<ClaimCumulative>,
+    cumulative_amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    process_cumulative_claim(
+        &mut ctx.accounts.distributor,
+        &mut ctx.accounts.claim_status,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.instructions_sysvar,
+        cumulative_amount,
+        proof,
+    )
+}
@@ -1,11 +1,11 @@
 use anchor_lang::{
-    accounts::{account::Account, program::Program, signer::Signer},
+    accounts::{account::Account, signer::Signer},
     context::{Context, CpiContext},
     emit,
     prelude::*,
     Accounts, Result, ToAccountInfo,
 };
-use anchor_spl::token::{self, Token, TokenAccount};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
 
 use crate::{
     error::ErrorCode,
@@ -13,6 +13,7 @@ use crate::{
         claim_status::ClaimStatus, claimed_event::ClaimedEvent,
         merkle_distributor::MerkleDistributor,
     },
+    utils::get_transfer_fee,
 };
 
 /// [merkle_distributor::claim_locked] accounts.
@@ -37,23 +38,33 @@ pub struct ClaimLocked<'info> {
     /// Distributor ATA containing the tokens to distribute.
     #[account(
         mut,
-        associated_token::mint = distributor.mint,
-        associated_token::authority = distributor.key(),
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
         address = distributor.token_vault,
     )]
-    pub from: Account<'info, TokenAccount>,
+    pub from: InterfaceAccount<'info, TokenAccount>,
 
     /// Account to send the claimed tokens to.
     /// Claimant must sign the transaction and can only claim on behalf of themself
-    #[account(mut, token::authority = claimant.key())]
-    pub to: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = claimant.key(),
+        token::token_program = token_program,
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
 
     /// Who is claiming the tokens.
     #[account(mut, address = to.owner @ ErrorCode::OwnerMismatch)]
     pub claimant: Signer<'info>,
 
-    /// SPL [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or [Token-2022] program.
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 /// Claim locked tokens as they become unlocked.
@@ -62,40 +73,113 @@ pub struct ClaimLocked<'info> {
 ///     2. The withdraw-able amount is greater than 0
 ///     3. The locked amount withdrawn is ≤ than the locked amount
 ///     4. The distributor amount claimed is ≤ than the max total claim
+///     5. The distributor is not paused
 #[allow(clippy::result_large_err)]
 pub fn handle_claim_locked(ctx: Context<ClaimLocked>) -> Result<()> {
-    let distributor = &ctx.accounts.distributor;
+    process_claim_locked(
+        &mut ctx.accounts.distributor,
+        &mut ctx.accounts.claim_status,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+    )
+}
 
-    let claim_status = &mut ctx.accounts.claim_status;
+/// Transfers the currently withdrawable locked tokens of claim_status.
+/// Shared by [handle_claim_locked], [handle_claim_locked_on_behalf](crate::instructions::handle_claim_locked_on_behalf)
+/// and [handle_claim_locked_to](crate::instructions::handle_claim_locked_to), callers are responsible for
+/// checking the claimant authorized sending tokens to the `to` account.
+#[allow(clippy::result_large_err)]
+pub(crate) fn process_claim_locked<'info>(
+    distributor: &mut Account<'info, MerkleDistributor>,
+    claim_status: &mut Account<'info, ClaimStatus>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+) -> Result<()> {
     let curr_ts = Clock::get()?.unix_timestamp;
-
-    require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
-
-    let amount =
-        claim_status.amount_withdrawable(curr_ts, distributor.start_ts, distributor.end_ts)?;
-
-    require!(amount > 0, ErrorCode::InsufficientUnlockedTokens);
+    let amount = withdraw_locked(distributor, claim_status, curr_ts)?;
+    let (_, end_ts) = claim_status.lockup(distributor.start_ts, distributor.end_ts);
 
     let seeds = [
         b"MerkleDistributor".as_ref(),
         &distributor.mint.to_bytes(),
         &distributor.version.to_le_bytes(),
-        &[ctx.accounts.distributor.bump],
+        &[distributor.bump],
     ];
 
-    token::transfer(
+    token_interface::transfer_checked(
         CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
-                from: ctx.accounts.from.to_account_info(),
-                to: ctx.accounts.to.to_account_info(),
-                authority: ctx.accounts.distributor.to_account_info(),
+            token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: from.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: distributor.to_account_info(),
             },
         )
         .with_signer(&[&seeds[..]]),
         amount,
+        mint.decimals,
+    )?;
+
+    let transfer_fee = get_transfer_fee(&mint.to_account_info(), amount)?;
+
+    let remaining_seconds = match curr_ts < end_ts {
+        true => end_ts - curr_ts,
+        false => 0,
+    };
+
+    let days = remaining_seconds / (24 * 60 * 60); // number of days
+    let seconds_after_days = remaining_seconds % (24 * 60 * 60); // Remaining seconds after subtracting full days
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Withdrew amount {} ({} withheld as transfer fee) with {} days and {} seconds left in lockup",
+        amount,
+        transfer_fee,
+        days,
+        seconds_after_days,
+    );
+    emit!(ClaimedEvent {
+        distributor: distributor.key(),
+        claimant: claim_status.claimant,
+        amount,
+        to: to.key(),
+        locked_amount_remaining: claim_status
+            .locked_amount
+            .checked_sub(claim_status.locked_amount_withdrawn)
+            .ok_or(ErrorCode::ArithmeticError)?,
+        mint_index: None,
+    });
+    Ok(())
+}
+
+/// Computes the currently withdrawable locked amount of claim_status and adds it to the
+/// withdrawn and claimed totals, leaving the transfer to the caller. Shared by
+/// [process_claim_locked] and
+/// [handle_claim_locked_native](crate::instructions::handle_claim_locked_native).
+#[allow(clippy::result_large_err)]
+pub(crate) fn withdraw_locked(
+    distributor: &mut MerkleDistributor,
+    claim_status: &mut ClaimStatus,
+    curr_ts: i64,
+) -> Result<u64> {
+    require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+    require!(!distributor.paused, ErrorCode::DistributorPaused);
+
+    let (start_ts, end_ts) = claim_status.lockup(distributor.start_ts, distributor.end_ts);
+    let amount = claim_status.amount_withdrawable(
+        curr_ts,
+        start_ts,
+        end_ts,
+        &distributor.vesting_schedule,
     )?;
 
+    require!(amount > 0, ErrorCode::InsufficientUnlockedTokens);
+
     claim_status.locked_amount_withdrawn = claim_status
         .locked_amount_withdrawn
         .checked_add(amount)
@@ -106,35 +190,17 @@ pub fn handle_claim_locked(ctx: Context<ClaimLocked>) -> Result<()> {
         ErrorCode::ExceededMaxClaim
     );
 
-    let distributor = &mut ctx.accounts.distributor;
     distributor.total_amount_claimed = distributor
         .total_amount_claimed
         .checked_add(amount)
         .ok_or(ErrorCode::ArithmeticError)?;
 
+    // Amounts of different mints don't add up, multi-mint distributors cap each mint in its
+    // DistributorMint instead
     require!(
-        distributor.total_amount_claimed <= distributor.max_total_claim,
+        distributor.multi_mint || distributor.total_amount_claimed <= distributor.max_total_claim,
         ErrorCode::ExceededMaxClaim
     );
 
-    let remaining_seconds = match curr_ts < distributor.end_ts {
-        true => distributor.end_ts - curr_ts,
-        false => 0,
-    };
-
-    let days = remaining_seconds / (24 * 60 * 60); // number of days
-    let seconds_after_days = remaining_seconds % (24 * 60 * 60); // Remaining seconds after subtracting full days
-
-    // Note: might get truncated, do not rely on
-    msg!(
-        "Withdrew amount {} with {} days and {} seconds left in lockup",
-        amount,
-        days,
-        seconds_after_days,
-    );
-    emit!(ClaimedEvent {
-        claimant: ctx.accounts.claimant.key(),
-        amount,
-    });
-    Ok(())
+    Ok(amount)
 }
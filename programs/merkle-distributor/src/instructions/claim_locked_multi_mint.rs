@@ -0,0 +1,156 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+use anchor_spl::{
+    token_interface,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    error::ErrorCode,
+    instructions::claim_locked::withdraw_locked,
+    state::{
+        claim_status::ClaimStatus, claimed_event::ClaimedEvent, distributor_mint::DistributorMint,
+        merkle_distributor::MerkleDistributor,
+    },
+    utils::get_transfer_fee,
+};
+
+/// [merkle_distributor::claim_locked_multi_mint] accounts.
+#[derive(Accounts)]
+#[instruction(mint_index: u8)]
+pub struct ClaimLockedMultiMint<'info> {
+    /// The multi-mint [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// [DistributorMint] of the claimed mint index.
+    #[account(
+        mut,
+        seeds = [
+            b"DistributorMint".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            [mint_index].as_ref()
+        ],
+        bump = distributor_mint.bump,
+        has_one = distributor,
+    )]
+    pub distributor_mint: Account<'info, DistributorMint>,
+
+    /// Claim Status PDA of the claimant's leaf for the mint index
+    #[account(
+        mut,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            [mint_index].as_ref()
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// Distributor ATA containing the tokens of the mint.
+    #[account(
+        mut,
+        token::mint = distributor_mint.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor_mint.token_vault,
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to.
+    #[account(
+        mut,
+        token::mint = distributor_mint.mint,
+        token::authority = claimant.key(),
+        token::token_program = token_program,
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Who is claiming the tokens.
+    #[account(mut, address = to.owner @ ErrorCode::OwnerMismatch)]
+    pub claimant: Signer<'info>,
+
+    /// The claimed mint.
+    #[account(address = distributor_mint.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or [Token-2022] program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Claim locked tokens of one mint of a multi-mint [MerkleDistributor] as they become unlocked.
+/// CHECK:
+///     1. The same checks as [handle_claim_locked](crate::instructions::handle_claim_locked)
+///     2. The vault of the mint has not been clawed back
+///     3. The claimed tokens of the mint stay within its max_total_claim
+#[allow(clippy::result_large_err)]
+pub fn handle_claim_locked_multi_mint(
+    ctx: Context<ClaimLockedMultiMint>,
+    mint_index: u8,
+) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+    let distributor_mint = &mut ctx.accounts.distributor_mint;
+    let claim_status = &mut ctx.accounts.claim_status;
+
+    require!(!distributor_mint.clawed_back, ErrorCode::ClaimExpired);
+
+    let amount = withdraw_locked(distributor, claim_status, Clock::get()?.unix_timestamp)?;
+
+    distributor_mint.total_amount_claimed = distributor_mint
+        .total_amount_claimed
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        distributor_mint.total_amount_claimed <= distributor_mint.max_total_claim,
+        ErrorCode::ExceededMaxClaim
+    );
+
+    let seeds = [
+        b"MerkleDistributor".as_ref(),
+        &distributor.mint.to_bytes(),
+        &distributor.version.to_le_bytes(),
+        &[distributor.bump],
+    ];
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.to.to_account_info(),
+                authority: distributor.to_account_info(),
+            },
+        )
+        .with_signer(&[&seeds[..]]),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    let transfer_fee = get_transfer_fee(&ctx.accounts.mint.to_account_info(), amount)?;
+
+    let locked_amount_remaining = claim_status
+        .locked_amount
+        .checked_sub(claim_status.locked_amount_withdrawn)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Withdrew amount {} of mint {} ({} withheld as transfer fee) with {} locked remaining",
+        amount,
+        mint_index,
+        transfer_fee,
+        locked_amount_remaining,
+    );
+    emit!(ClaimedEvent {
+        distributor: distributor.key(),
+        claimant: claim_status.claimant,
+        amount,
+        to: ctx.accounts.to.key(),
+        locked_amount_remaining,
+        mint_index: Some(mint_index),
+    });
+    Ok(())
+}
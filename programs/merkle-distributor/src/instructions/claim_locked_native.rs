@@ -0,0 +1,80 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Result};
+
+use crate::{
+    error::ErrorCode,
+    instructions::claim_locked::withdraw_locked,
+    state::{
+        asset_kind::AssetKind, claim_status::ClaimStatus, claimed_event::ClaimedEvent,
+        merkle_distributor::MerkleDistributor, sol_vault::SolVault,
+    },
+};
+
+/// [merkle_distributor::claim_locked_native] accounts.
+#[derive(Accounts)]
+pub struct ClaimLockedNative<'info> {
+    /// The native SOL [MerkleDistributor].
+    #[account(
+        mut,
+        constraint = distributor.asset_kind == AssetKind::NativeSol @ ErrorCode::AssetKindMismatch
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim Status PDA
+    #[account(
+        mut,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// [SolVault] holding the lamports to distribute.
+    #[account(mut, address = distributor.token_vault)]
+    pub sol_vault: Account<'info, SolVault>,
+
+    /// Who is claiming, receives the claimed lamports.
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+}
+
+/// Claim locked lamports of a native SOL [MerkleDistributor] as they become unlocked.
+/// CHECK:
+///     1. The same checks as [handle_claim_locked](crate::instructions::handle_claim_locked)
+///     2. The distributor distributes native SOL
+#[allow(clippy::result_large_err)]
+pub fn handle_claim_locked_native(ctx: Context<ClaimLockedNative>) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+    let claim_status = &mut ctx.accounts.claim_status;
+
+    let amount = withdraw_locked(distributor, claim_status, Clock::get()?.unix_timestamp)?;
+
+    SolVault::transfer(
+        &ctx.accounts.sol_vault.to_account_info(),
+        &ctx.accounts.claimant.to_account_info(),
+        amount,
+    )?;
+
+    let locked_amount_remaining = claim_status
+        .locked_amount
+        .checked_sub(claim_status.locked_amount_withdrawn)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Withdrew {} lamports with {} locked remaining",
+        amount,
+        locked_amount_remaining,
+    );
+    emit!(ClaimedEvent {
+        distributor: distributor.key(),
+        claimant: claim_status.claimant,
+        amount,
+        to: ctx.accounts.claimant.key(),
+        locked_amount_remaining,
+        mint_index: None,
+    });
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Result};
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    error::ErrorCode,
+    instructions::claim_locked::process_claim_locked,
+    state::{claim_status::ClaimStatus, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::claim_locked_on_behalf] accounts.
+#[derive(Accounts)]
+pub struct ClaimLockedOnBehalf<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim Status PDA
+    #[account(
+        mut,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault,
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// The claimant's ATA, the only account the claimed tokens can be sent to.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = claimant.key(),
+        token::token_program = token_program,
+        address = get_associated_token_address_with_program_id(
+            &claimant.key(),
+            &distributor.mint,
+            &token_program.key(),
+        ) @ ErrorCode::OwnerMismatch
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Who the tokens are claimed for, only used to derive the claim status PDA and ATA
+    pub claimant: UncheckedAccount<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or [Token-2022] program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Claim locked tokens as they become unlocked, on behalf of the claimant.
+/// Same as [claim_locked](crate::instructions::handle_claim_locked), except anyone can send the transaction.
+/// Check:
+///     1. The distributor allows claiming on behalf of claimants
+///     2. The to account is the claimant's ATA
+///     3. All checks of [claim_locked](crate::instructions::handle_claim_locked)
+#[allow(clippy::result_large_err)]
+pub fn handle_claim_locked_on_behalf(ctx: Context<ClaimLockedOnBehalf>) -> Result<()> {
+    require!(
+        ctx.accounts.distributor.allow_claim_on_behalf,
+        ErrorCode::ClaimOnBehalfDisabled
+    );
+
+    process_claim_locked(
+        &mut ctx.accounts.distributor,
+        &mut ctx.accounts.claim_status,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+    )
+}
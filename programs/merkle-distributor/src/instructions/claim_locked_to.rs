@@ -0,0 +1,73 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Result};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    instructions::claim_locked::process_claim_locked,
+    state::{claim_status::ClaimStatus, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::claim_locked_to] accounts.
+#[derive(Accounts)]
+pub struct ClaimLockedTo<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim Status PDA
+    #[account(
+        mut,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault,
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to, chosen by the claimant. Can have any owner.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::token_program = token_program,
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Who is claiming the tokens.
+    pub claimant: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or [Token-2022] program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Claim locked tokens as they become unlocked, sending them to a token account named by the claimant.
+/// Same as [claim_locked](crate::instructions::handle_claim_locked), except the to account does not
+/// have to be owned by the claimant. The claimant's signature authorizes the destination.
+/// Check:
+///     1. The claimant signed the transaction
+///     2. All checks of [claim_locked](crate::instructions::handle_claim_locked)
+#[allow(clippy::result_large_err)]
+pub fn handle_claim_locked_to(ctx: Context<ClaimLockedTo>) -> Result<()> {
+    process_claim_locked(
+        &mut ctx.accounts.distributor,
+        &mut ctx.accounts.claim_status,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+    )
+}
@@ -2,31 +2,38 @@
 
 use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
 use anchor_spl::{
-    token,
-    token::{Token, TokenAccount},
+    token_interface,
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-use crate::{error::ErrorCode, state::merkle_distributor::MerkleDistributor};
+use crate::{
+    error::ErrorCode,
+    state::{distributor_event::ClawbackEvent, merkle_distributor::MerkleDistributor},
+};
 
 /// [merkle_distributor::clawback] accounts.
 #[derive(Accounts)]
 pub struct Clawback<'info> {
     /// The [MerkleDistributor].
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = !distributor.multi_mint @ ErrorCode::ClaimModeMismatch,
+    )]
     pub distributor: Account<'info, MerkleDistributor>,
 
     /// Distributor ATA containing the tokens to distribute.
     #[account(
         mut,
-        associated_token::mint = distributor.mint,
-        associated_token::authority = distributor.key(),
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
         address = distributor.token_vault
     )]
-    pub from: Account<'info, TokenAccount>,
+    pub from: InterfaceAccount<'info, TokenAccount>,
 
     /// The Clawback token account.
     #[account(mut, address = distributor.clawback_receiver)]
-    pub to: Account<'info, TokenAccount>,
+    pub to: InterfaceAccount<'info, TokenAccount>,
 
     /// Claimant account
     /// Anyone can claw back the funds
@@ -35,14 +42,21 @@ pub struct Clawback<'info> {
     /// The [System] program.
     pub system_program: Program<'info, System>,
 
-    /// SPL [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or [Token-2022] program.
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 /// Claws back unclaimed tokens by:
 /// 1. Checking that the lockup has expired
 /// 2. Transferring remaining funds from the vault to the clawback receiver
 /// 3. Marking the distributor as clawed back
+///
+/// Multi-mint distributors are clawed back with
+/// [clawback_multi_mint](crate::instructions::handle_clawback_multi_mint).
 /// CHECK:
 ///     1. The distributor has not already been clawed back
 #[allow(clippy::result_large_err)]
@@ -57,6 +71,8 @@ pub fn handle_clawback(ctx: Context<Clawback>) -> Result<()> {
         return Err(ErrorCode::ClawbackBeforeStart.into());
     }
 
+    let amount = ctx.accounts.from.amount;
+
     let seeds = [
         b"MerkleDistributor".as_ref(),
         &distributor.mint.to_bytes(),
@@ -64,22 +80,84 @@ pub fn handle_clawback(ctx: Context<Clawback>) -> Result<()> {
         &[ctx.accounts.distributor.bump],
     ];
 
-    token::transfer(
+    token_interface::transfer_checked(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
+            token_interface::TransferChecked {
                 from: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.to.to_account_info(),
                 authority: ctx.accounts.distributor.to_account_info(),
             },
         )
         .with_signer(&[&seeds[..]]),
-        ctx.accounts.from.amount,
+        amount,
+        ctx.accounts.mint.decimals,
     )?;
 
     let distributor = &mut ctx.accounts.distributor;
 
     distributor.clawed_back = true;
 
+    // Note: might get truncated, do not rely on
+    msg!("Clawed back {} to {}", amount, ctx.accounts.to.key());
+    emit!(ClawbackEvent {
+        distributor: distributor.key(),
+        to: ctx.accounts.to.key(),
+        amount,
+    });
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::system_program;
+    use anchor_spl::token;
+
+    use super::*;
+    use crate::test_utils::{process_instruction, TestAccount};
+
+    #[test]
+    fn test_clawback_rejects_multi_mint_distributor() {
+        let (distributor, admin, from, to) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let multi_mint_distributor = MerkleDistributor {
+            admin,
+            clawback_receiver: to,
+            token_vault: from,
+            multi_mint: true,
+            ..MerkleDistributor::default()
+        };
+
+        let mut accounts = [
+            TestAccount::program(distributor, &multi_mint_distributor, MerkleDistributor::LEN),
+            TestAccount::token_account(from, Pubkey::default(), distributor, 100),
+            TestAccount::token_account(to, Pubkey::default(), admin, 0),
+            TestAccount::wallet(admin),
+            TestAccount::executable(system_program::ID),
+            TestAccount::mint(Pubkey::default()),
+            TestAccount::executable(token::ID),
+        ];
+        assert_eq!(
+            process_instruction(
+                crate::accounts::Clawback {
+                    distributor,
+                    from,
+                    to,
+                    claimant: admin,
+                    system_program: system_program::ID,
+                    mint: Pubkey::default(),
+                    token_program: token::ID,
+                },
+                crate::instruction::Clawback {},
+                &mut accounts
+            ),
+            Err(Error::from(ErrorCode::ClaimModeMismatch).into())
+        );
+    }
+}
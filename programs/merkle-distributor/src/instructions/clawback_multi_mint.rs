@@ -0,0 +1,134 @@
+// Instruction to clawback the tokens of one mint of a multi-mint distributor once they have expired
+
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+use anchor_spl::{
+    token_interface,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        distributor_event::ClawbackEvent, distributor_mint::DistributorMint,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::clawback_multi_mint] accounts.
+#[derive(Accounts)]
+#[instruction(mint_index: u8)]
+pub struct ClawbackMultiMint<'info> {
+    /// The multi-mint [MerkleDistributor].
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// [DistributorMint] of the clawed back mint index.
+    #[account(
+        mut,
+        seeds = [
+            b"DistributorMint".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            [mint_index].as_ref()
+        ],
+        bump = distributor_mint.bump,
+        has_one = distributor,
+    )]
+    pub distributor_mint: Account<'info, DistributorMint>,
+
+    /// Distributor ATA containing the tokens of the mint.
+    #[account(
+        mut,
+        token::mint = distributor_mint.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor_mint.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// The clawback receiver token account of the distributor's own mint, its owner receives the
+    /// tokens of every mint.
+    #[account(address = distributor.clawback_receiver)]
+    pub clawback_receiver: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token account of the clawback receiver's owner for the mint.
+    #[account(
+        mut,
+        token::mint = distributor_mint.mint,
+        token::authority = clawback_receiver.owner,
+        token::token_program = token_program,
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Claimant account
+    /// Anyone can claw back the funds
+    pub claimant: Signer<'info>,
+
+    /// The clawed back mint.
+    #[account(address = distributor_mint.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or [Token-2022] program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Claws back the unclaimed tokens of one mint of a multi-mint distributor, marking its
+/// [DistributorMint] as clawed back. Same as [handle_clawback](crate::instructions::handle_clawback)
+/// for the other vaults.
+/// CHECK:
+///     1. The vault of the mint has not already been clawed back
+///     2. The clawback period has started
+#[allow(clippy::result_large_err)]
+pub fn handle_clawback_multi_mint(ctx: Context<ClawbackMultiMint>, mint_index: u8) -> Result<()> {
+    let distributor = &ctx.accounts.distributor;
+
+    require!(
+        !ctx.accounts.distributor_mint.clawed_back,
+        ErrorCode::ClawbackAlreadyClaimed
+    );
+
+    let curr_ts = Clock::get()?.unix_timestamp;
+
+    if curr_ts < distributor.clawback_start_ts {
+        return Err(ErrorCode::ClawbackBeforeStart.into());
+    }
+
+    let amount = ctx.accounts.from.amount;
+
+    let seeds = [
+        b"MerkleDistributor".as_ref(),
+        &distributor.mint.to_bytes(),
+        &distributor.version.to_le_bytes(),
+        &[distributor.bump],
+    ];
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.to.to_account_info(),
+                authority: distributor.to_account_info(),
+            },
+        )
+        .with_signer(&[&seeds[..]]),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    ctx.accounts.distributor_mint.clawed_back = true;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Clawed back {} of mint {} to {}",
+        amount,
+        mint_index,
+        ctx.accounts.to.key()
+    );
+    emit!(ClawbackEvent {
+        distributor: distributor.key(),
+        to: ctx.accounts.to.key(),
+        amount,
+    });
+
+    Ok(())
+}
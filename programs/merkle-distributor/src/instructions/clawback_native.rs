@@ -0,0 +1,72 @@
+// Instruction to clawback lamports of a native SOL distributor once they have expired
+
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        asset_kind::AssetKind, distributor_event::ClawbackEvent,
+        merkle_distributor::MerkleDistributor, sol_vault::SolVault,
+    },
+};
+
+/// [merkle_distributor::clawback_native] accounts.
+#[derive(Accounts)]
+pub struct ClawbackNative<'info> {
+    /// The native SOL [MerkleDistributor].
+    #[account(
+        mut,
+        constraint = distributor.asset_kind == AssetKind::NativeSol @ ErrorCode::AssetKindMismatch
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// [SolVault] holding the lamports to distribute.
+    #[account(mut, address = distributor.token_vault)]
+    pub sol_vault: Account<'info, SolVault>,
+
+    /// The clawback receiver wallet.
+    #[account(mut, address = distributor.clawback_receiver)]
+    pub to: SystemAccount<'info>,
+
+    /// Claimant account
+    /// Anyone can claw back the funds
+    pub claimant: Signer<'info>,
+}
+
+/// Claws back unclaimed lamports, leaving the rent exempt minimum in the [SolVault].
+/// Same as [handle_clawback](crate::instructions::handle_clawback).
+/// CHECK:
+///     1. The distributor has not already been clawed back
+///     2. The clawback period has started
+#[allow(clippy::result_large_err)]
+pub fn handle_clawback_native(ctx: Context<ClawbackNative>) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    require!(!distributor.clawed_back, ErrorCode::ClawbackAlreadyClaimed);
+
+    let curr_ts = Clock::get()?.unix_timestamp;
+
+    if curr_ts < distributor.clawback_start_ts {
+        return Err(ErrorCode::ClawbackBeforeStart.into());
+    }
+
+    let sol_vault = ctx.accounts.sol_vault.to_account_info();
+    let amount = SolVault::balance(&sol_vault)?;
+    SolVault::transfer(&sol_vault, &ctx.accounts.to.to_account_info(), amount)?;
+
+    distributor.clawed_back = true;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Clawed back {} lamports to {}",
+        amount,
+        ctx.accounts.to.key()
+    );
+    emit!(ClawbackEvent {
+        distributor: distributor.key(),
+        to: ctx.accounts.to.key(),
+        amount,
+    });
+
+    Ok(())
+}
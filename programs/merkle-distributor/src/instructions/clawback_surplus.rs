@@ -0,0 +1,145 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+use anchor_spl::{
+    token_interface,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{distributor_event::SurplusClawbackEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::clawback_surplus] accounts.
+#[derive(Accounts)]
+pub struct ClawbackSurplus<'info> {
+    /// The [MerkleDistributor].
+    #[account(constraint = !distributor.multi_mint @ ErrorCode::ClaimModeMismatch)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// The Clawback token account.
+    #[account(mut, address = distributor.clawback_receiver)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or [Token-2022] program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Transfers the tokens in the vault beyond max_total_claim - total_amount_claimed to the
+/// clawback receiver. Can run at any time before clawback, claimants are always left with
+/// enough tokens in the vault to claim everything they are still owed.
+/// Not available to multi-mint distributors, whose max_total_claim adds up every mint.
+/// CHECK:
+///     1. The distributor has not been clawed back
+///     2. The vault holds more tokens than can still be claimed
+#[allow(clippy::result_large_err)]
+pub fn handle_clawback_surplus(ctx: Context<ClawbackSurplus>) -> Result<()> {
+    let distributor = &ctx.accounts.distributor;
+
+    require!(!distributor.clawed_back, ErrorCode::ClawbackAlreadyClaimed);
+
+    let amount = distributor.surplus(ctx.accounts.from.amount);
+    require!(amount > 0, ErrorCode::NoSurplus);
+
+    let seeds = [
+        b"MerkleDistributor".as_ref(),
+        &distributor.mint.to_bytes(),
+        &distributor.version.to_le_bytes(),
+        &[distributor.bump],
+    ];
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.to.to_account_info(),
+                authority: distributor.to_account_info(),
+            },
+        )
+        .with_signer(&[&seeds[..]]),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Clawed back surplus of {}, {} left for remaining claims",
+        amount,
+        distributor.remaining_claim()
+    );
+    emit!(SurplusClawbackEvent {
+        distributor: distributor.key(),
+        to: ctx.accounts.to.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_spl::token;
+
+    use super::*;
+    use crate::test_utils::{process_instruction, TestAccount};
+
+    #[test]
+    fn test_clawback_surplus_rejects_multi_mint_distributor() {
+        let (distributor, admin, from, to) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let multi_mint_distributor = MerkleDistributor {
+            admin,
+            clawback_receiver: to,
+            token_vault: from,
+            multi_mint: true,
+            ..MerkleDistributor::default()
+        };
+
+        let mut accounts = [
+            TestAccount::program(distributor, &multi_mint_distributor, MerkleDistributor::LEN),
+            TestAccount::token_account(from, Pubkey::default(), distributor, 100),
+            TestAccount::token_account(to, Pubkey::default(), admin, 0),
+            TestAccount::wallet(admin),
+            TestAccount::mint(Pubkey::default()),
+            TestAccount::executable(token::ID),
+        ];
+        assert_eq!(
+            process_instruction(
+                crate::accounts::ClawbackSurplus {
+                    distributor,
+                    from,
+                    to,
+                    admin,
+                    mint: Pubkey::default(),
+                    token_program: token::ID,
+                },
+                crate::instruction::ClawbackSurplus {},
+                &mut accounts
+            ),
+            Err(Error::from(ErrorCode::ClaimModeMismatch).into())
+        );
+    }
+}
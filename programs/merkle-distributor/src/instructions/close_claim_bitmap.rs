@@ -0,0 +1,162 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    instructions::close_distributor::clawed_back_admin,
+    state::{claim_bitmap::ClaimBitmap, claim_bitmap_event::ClaimBitmapClosedEvent},
+};
+
+/// [merkle_distributor::close_claim_bitmap] accounts.
+#[derive(Accounts)]
+pub struct CloseClaimBitmap<'info> {
+    /// CHECK: The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor), or its
+    /// [DistributorTombstone](crate::state::distributor_tombstone::DistributorTombstone) once closed.
+    /// Deserialized in the handler.
+    pub distributor: UncheckedAccount<'info>,
+
+    /// Claim bitmap PDA
+    #[account(mut, close = receiver, has_one = distributor)]
+    pub claim_bitmap: AccountLoader<'info, ClaimBitmap>,
+
+    /// Admin signer, checked against the distributor in the handler
+    pub admin: Signer<'info>,
+
+    /// CHECK: Any account chosen by the admin, receives the rent of the claim bitmap
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+}
+
+/// Closes a [ClaimBitmap] after clawback, sending its rent to the receiver.
+/// Still possible after [close_distributor](crate::instructions::handle_close_distributor), against
+/// the distributor's tombstone.
+/// CHECK:
+///     1. The admin signed the transaction
+///     2. The distributor has been clawed back or closed
+#[allow(clippy::result_large_err)]
+pub fn handle_close_claim_bitmap(ctx: Context<CloseClaimBitmap>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        clawed_back_admin(&ctx.accounts.distributor)?,
+        ErrorCode::Unauthorized
+    );
+
+    let chunk_index = ctx.accounts.claim_bitmap.load()?.chunk_index;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Closed claim bitmap {}, rent sent to {}",
+        chunk_index,
+        ctx.accounts.receiver.key()
+    );
+    emit!(ClaimBitmapClosedEvent {
+        distributor: ctx.accounts.distributor.key(),
+        chunk_index,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::Discriminator;
+
+    use super::*;
+    use crate::{
+        state::{
+            distributor_tombstone::DistributorTombstone, merkle_distributor::MerkleDistributor,
+        },
+        test_utils::{process_instruction, TestAccount},
+    };
+
+    fn close_claim_bitmap(
+        distributor: TestAccount,
+        admin: Pubkey,
+    ) -> std::result::Result<[TestAccount; 4], ProgramError> {
+        let (claim_bitmap, receiver) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut data = ClaimBitmap::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(distributor.key.as_ref());
+        data.resize(ClaimBitmap::LEN, 0);
+        let distributor_key = distributor.key;
+        let mut accounts = [
+            distributor,
+            TestAccount {
+                key: claim_bitmap,
+                lamports: Rent::default().minimum_balance(ClaimBitmap::LEN),
+                data,
+                owner: crate::ID,
+                executable: false,
+            },
+            TestAccount::wallet(admin),
+            TestAccount::wallet(receiver),
+        ];
+        process_instruction(
+            crate::accounts::CloseClaimBitmap {
+                distributor: distributor_key,
+                claim_bitmap,
+                admin,
+                receiver,
+            },
+            crate::instruction::CloseClaimBitmap {},
+            &mut accounts,
+        )?;
+        Ok(accounts)
+    }
+
+    #[test]
+    fn test_close_claim_bitmap_after_close_distributor() {
+        let (distributor, admin) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let tombstone = DistributorTombstone { admin };
+
+        let [_, claim_bitmap, _, receiver] = close_claim_bitmap(
+            TestAccount::program(distributor, &tombstone, DistributorTombstone::LEN),
+            admin,
+        )
+        .unwrap();
+        assert_eq!(claim_bitmap.lamports, 0);
+        assert_eq!(
+            receiver.lamports,
+            TestAccount::wallet(admin).lamports + Rent::default().minimum_balance(ClaimBitmap::LEN)
+        );
+
+        assert_eq!(
+            close_claim_bitmap(
+                TestAccount::program(distributor, &tombstone, DistributorTombstone::LEN),
+                Pubkey::new_unique(),
+            )
+            .err(),
+            Some(Error::from(ErrorCode::Unauthorized).into())
+        );
+    }
+
+    #[test]
+    fn test_close_claim_bitmap_before_clawback() {
+        let (distributor, admin) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let live_distributor = MerkleDistributor {
+            admin,
+            ..MerkleDistributor::default()
+        };
+
+        assert_eq!(
+            close_claim_bitmap(
+                TestAccount::program(distributor, &live_distributor, MerkleDistributor::LEN),
+                admin,
+            )
+            .err(),
+            Some(Error::from(ErrorCode::DistributorNotClawedBack).into())
+        );
+
+        let clawed_back_distributor = MerkleDistributor {
+            clawed_back: true,
+            ..live_distributor
+        };
+        assert!(close_claim_bitmap(
+            TestAccount::program(
+                distributor,
+                &clawed_back_distributor,
+                MerkleDistributor::LEN
+            ),
+            admin,
+        )
+        .is_ok());
+    }
+}
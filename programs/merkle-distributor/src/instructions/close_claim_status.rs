@@ -0,0 +1,223 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    system_program, Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claim_status::ClaimStatus, claimed_event::ClaimStatusClosedEvent,
+        closed_claims::ClosedClaims, distributor_tombstone::DistributorTombstone,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::close_claim_status] accounts.
+#[derive(Accounts)]
+pub struct CloseClaimStatus<'info> {
+    /// CHECK: The [MerkleDistributor], or its [DistributorTombstone] once closed with
+    /// [close_distributor](crate::instructions::handle_close_distributor). Deserialized in the handler.
+    pub distributor: UncheckedAccount<'info>,
+
+    /// Claim Status PDA
+    #[account(
+        mut,
+        close = claimant,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// CHECK: [ClosedClaims] chunk recording the claimant, created by the first claim it records.
+    #[account(
+        mut,
+        seeds = [
+            b"ClosedClaims".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            &[ClosedClaims::chunk_index_of(&claimant.key())]
+        ],
+        bump,
+    )]
+    pub closed_claims: UncheckedAccount<'info>,
+
+    /// Who claimed the tokens, receives the rent from the closed [ClaimStatus].
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Closes a [ClaimStatus] and returns its rent to the claimant.
+/// If the distributor has been closed, there is nothing left to claim and the [ClaimStatus] is closed as is.
+/// If the distributor has not been clawed back yet, the claimant is recorded in its [ClosedClaims]
+/// chunk so the same leaf can never be claimed again. The rent for the record is paid out of the
+/// [ClaimStatus] lamports.
+/// CHECK:
+///     1. All locked tokens have been withdrawn or the distributor has been clawed back
+#[allow(clippy::result_large_err)]
+pub fn handle_close_claim_status(ctx: Context<CloseClaimStatus>) -> Result<()> {
+    let distributor_info = ctx.accounts.distributor.to_account_info();
+    let claim_status = &ctx.accounts.claim_status;
+
+    if Account::<DistributorTombstone>::try_from(&distributor_info).is_ok() {
+        // Note: might get truncated, do not rely on
+        msg!(
+            "Closed claim status for {} after the distributor was closed",
+            claim_status.claimant
+        );
+        emit!(ClaimStatusClosedEvent {
+            distributor: distributor_info.key(),
+            claimant: claim_status.claimant,
+            recorded: false,
+        });
+        return Ok(());
+    }
+
+    let distributor = Account::<MerkleDistributor>::try_from(&distributor_info)?;
+
+    // Once clawed back no new claims can be made, so there is no need to remember the claimant
+    if distributor.clawed_back {
+        // Note: might get truncated, do not rely on
+        msg!(
+            "Closed claim status for {} after clawback",
+            claim_status.claimant
+        );
+        emit!(ClaimStatusClosedEvent {
+            distributor: distributor_info.key(),
+            claimant: claim_status.claimant,
+            recorded: false,
+        });
+        return Ok(());
+    }
+
+    require!(
+        claim_status.locked_amount_withdrawn == claim_status.locked_amount,
+        ErrorCode::LockedTokensRemaining
+    );
+
+    record_closed_claim(
+        &distributor_info.key(),
+        &ctx.accounts.closed_claims,
+        *ctx.bumps.get("closed_claims").unwrap(),
+        &ctx.accounts.claim_status.to_account_info(),
+        &claim_status.claimant,
+        &ctx.accounts.system_program,
+    )?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Closed claim status for {}, {} claims closed in chunk {}",
+        claim_status.claimant,
+        ClosedClaims::num_closed_claims(&ctx.accounts.closed_claims.try_borrow_data()?),
+        ClosedClaims::chunk_index_of(&claim_status.claimant),
+    );
+    emit!(ClaimStatusClosedEvent {
+        distributor: distributor_info.key(),
+        claimant: claim_status.claimant,
+        recorded: true,
+    });
+
+    Ok(())
+}
+
+/// Records `claimant` in its [ClosedClaims] chunk so its leaf can never be claimed again,
+/// creating the chunk or growing it by one record. The rent for the record is paid out of the
+/// lamports of the closing `claim_status_info`.
+/// Shared by [handle_close_claim_status] and [move_claim](crate::instructions::transfer_claim::move_claim).
+#[allow(clippy::result_large_err)]
+pub(crate) fn record_closed_claim<'info>(
+    distributor: &Pubkey,
+    closed_claims: &UncheckedAccount<'info>,
+    closed_claims_bump: u8,
+    claim_status_info: &AccountInfo<'info>,
+    claimant: &Pubkey,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let chunk_index = ClosedClaims::chunk_index_of(claimant);
+    let is_new = closed_claims.data_is_empty();
+    if !is_new {
+        // Checks the owner and discriminator
+        Account::<ClosedClaims>::try_from(closed_claims)?;
+    }
+
+    let new_len = match is_new {
+        true => ClosedClaims::LEN,
+        false => closed_claims.data_len(),
+    }
+    .checked_add(ClosedClaims::CLOSED_CLAIM_LEN)
+    .ok_or(ErrorCode::ArithmeticError)?;
+    let rent_top_up = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(closed_claims.lamports());
+
+    **claim_status_info.try_borrow_mut_lamports()? = claim_status_info
+        .lamports()
+        .checked_sub(rent_top_up)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    **closed_claims.try_borrow_mut_lamports()? = closed_claims
+        .lamports()
+        .checked_add(rent_top_up)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    if is_new {
+        let seeds = [
+            b"ClosedClaims".as_ref(),
+            distributor.as_ref(),
+            &[chunk_index],
+            &[closed_claims_bump],
+        ];
+        system_program::allocate(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Allocate {
+                    account_to_allocate: closed_claims.to_account_info(),
+                },
+            )
+            .with_signer(&[&seeds[..]]),
+            new_len as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Assign {
+                    account_to_assign: closed_claims.to_account_info(),
+                },
+            )
+            .with_signer(&[&seeds[..]]),
+            &crate::ID,
+        )?;
+        ClosedClaims {
+            distributor: *distributor,
+            chunk_index,
+        }
+        .try_serialize(&mut &mut closed_claims.try_borrow_mut_data()?[..])?;
+    } else {
+        closed_claims.realloc(new_len, false)?;
+    }
+
+    ClosedClaims::insert_closed_claim(&mut closed_claims.try_borrow_mut_data()?, claimant);
+
+    Ok(())
+}
+
+/// Returns true if `claimant` is recorded in the `closed_claims` chunk, which may not exist yet.
+#[allow(clippy::result_large_err)]
+pub(crate) fn is_claim_closed(closed_claims: &AccountInfo, claimant: &Pubkey) -> Result<bool> {
+    if closed_claims.data_is_empty() {
+        return Ok(false);
+    }
+    // Checks the owner and discriminator
+    Account::<ClosedClaims>::try_from(closed_claims)?;
+
+    Ok(ClosedClaims::is_claim_closed(
+        &closed_claims.try_borrow_data()?,
+        claimant,
+    ))
+}
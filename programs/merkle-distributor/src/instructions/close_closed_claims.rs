@@ -0,0 +1,143 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    instructions::close_distributor::clawed_back_admin,
+    state::{claimed_event::ClosedClaimsClosedEvent, closed_claims::ClosedClaims},
+};
+
+/// [merkle_distributor::close_closed_claims] accounts.
+#[derive(Accounts)]
+pub struct CloseClosedClaims<'info> {
+    /// CHECK: The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor), or its
+    /// [DistributorTombstone](crate::state::distributor_tombstone::DistributorTombstone) once closed.
+    /// Deserialized in the handler.
+    pub distributor: UncheckedAccount<'info>,
+
+    /// Closed claims chunk PDA
+    #[account(mut, close = receiver, has_one = distributor)]
+    pub closed_claims: Account<'info, ClosedClaims>,
+
+    /// Admin signer, checked against the distributor in the handler
+    pub admin: Signer<'info>,
+
+    /// CHECK: Any account chosen by the admin, receives the rent of the closed claims chunk
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+}
+
+/// Closes a [ClosedClaims] chunk after clawback, sending its rent to the receiver.
+/// No new claims can be made once clawed back, so the records are no longer needed.
+/// Still possible after [close_distributor](crate::instructions::handle_close_distributor), against
+/// the distributor's tombstone.
+/// CHECK:
+///     1. The admin signed the transaction
+///     2. The distributor has been clawed back or closed
+#[allow(clippy::result_large_err)]
+pub fn handle_close_closed_claims(ctx: Context<CloseClosedClaims>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        clawed_back_admin(&ctx.accounts.distributor)?,
+        ErrorCode::Unauthorized
+    );
+
+    let chunk_index = ctx.accounts.closed_claims.chunk_index;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Closed closed claims chunk {}, rent sent to {}",
+        chunk_index,
+        ctx.accounts.receiver.key()
+    );
+    emit!(ClosedClaimsClosedEvent {
+        distributor: ctx.accounts.distributor.key(),
+        chunk_index,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        state::{
+            distributor_tombstone::DistributorTombstone, merkle_distributor::MerkleDistributor,
+        },
+        test_utils::{process_instruction, TestAccount},
+    };
+
+    fn close_closed_claims(
+        distributor: TestAccount,
+        admin: Pubkey,
+    ) -> std::result::Result<[TestAccount; 4], ProgramError> {
+        let (closed_claims, receiver) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let chunk = ClosedClaims {
+            distributor: distributor.key,
+            chunk_index: 7,
+        };
+        let distributor_key = distributor.key;
+        let mut accounts = [
+            distributor,
+            TestAccount::program(closed_claims, &chunk, ClosedClaims::LEN),
+            TestAccount::wallet(admin),
+            TestAccount::wallet(receiver),
+        ];
+        process_instruction(
+            crate::accounts::CloseClosedClaims {
+                distributor: distributor_key,
+                closed_claims,
+                admin,
+                receiver,
+            },
+            crate::instruction::CloseClosedClaims {},
+            &mut accounts,
+        )?;
+        Ok(accounts)
+    }
+
+    #[test]
+    fn test_close_closed_claims_after_close_distributor() {
+        let (distributor, admin) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let tombstone = DistributorTombstone { admin };
+
+        let [_, closed_claims, _, receiver] = close_closed_claims(
+            TestAccount::program(distributor, &tombstone, DistributorTombstone::LEN),
+            admin,
+        )
+        .unwrap();
+        assert_eq!(closed_claims.lamports, 0);
+        assert_eq!(
+            receiver.lamports,
+            TestAccount::wallet(admin).lamports
+                + Rent::default().minimum_balance(ClosedClaims::LEN)
+        );
+
+        assert_eq!(
+            close_closed_claims(
+                TestAccount::program(distributor, &tombstone, DistributorTombstone::LEN),
+                Pubkey::new_unique(),
+            )
+            .err(),
+            Some(Error::from(ErrorCode::Unauthorized).into())
+        );
+    }
+
+    #[test]
+    fn test_close_closed_claims_before_clawback() {
+        let (distributor, admin) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let live_distributor = MerkleDistributor {
+            admin,
+            ..MerkleDistributor::default()
+        };
+
+        assert_eq!(
+            close_closed_claims(
+                TestAccount::program(distributor, &live_distributor, MerkleDistributor::LEN),
+                admin,
+            )
+            .err(),
+            Some(Error::from(ErrorCode::DistributorNotClawedBack).into())
+        );
+    }
+}
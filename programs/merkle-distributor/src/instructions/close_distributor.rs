@@ -0,0 +1,214 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+use anchor_spl::{
+    token_interface,
+    token_interface::{TokenAccount, TokenInterface},
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        asset_kind::AssetKind, distributor_event::DistributorClosedEvent,
+        distributor_tombstone::DistributorTombstone, merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::close_distributor] accounts.
+#[derive(Accounts)]
+pub struct CloseDistributor<'info> {
+    /// CHECK: The [MerkleDistributor], replaced by its [DistributorTombstone]. The owner is checked
+    /// here, the rest in the handler.
+    #[account(mut, owner = crate::ID)]
+    pub distributor: UncheckedAccount<'info>,
+
+    /// Distributor ATA containing the tokens to distribute, checked against the distributor in
+    /// the handler.
+    #[account(mut, token::token_program = token_program)]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Admin signer
+    pub admin: Signer<'info>,
+
+    /// CHECK: Any account chosen by the admin, receives the rent of the distributor and vault
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+
+    /// SPL [Token] or [Token-2022] program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Closes the token vault and the [MerkleDistributor] after clawback, sending their rent to the receiver.
+/// The distributor is shrunk into a [DistributorTombstone] rather than closed, so a distributor with
+/// the same mint and version can never be created again and the old ClaimStatus accounts can't
+/// claim from it. Only the rent of the tombstone stays behind.
+/// Claimants can still close their [ClaimStatus](crate::state::claim_status::ClaimStatus), and the
+/// admin the claim bitmaps and closed claims chunks, afterwards.
+/// Native SOL and multi-mint distributors can't be closed: their vaults are not token accounts of
+/// the distributor mint.
+/// CHECK:
+///     1. The admin signed the transaction
+///     2. The distributor has been clawed back
+///     3. The distributor is a single mint token distributor
+///     4. The token vault is the distributor's and empty, enforced by the token program
+#[allow(clippy::result_large_err)]
+pub fn handle_close_distributor(ctx: Context<CloseDistributor>) -> Result<()> {
+    let distributor_info = ctx.accounts.distributor.to_account_info();
+    let distributor = Account::<MerkleDistributor>::try_from(&distributor_info)?;
+
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        distributor.admin,
+        ErrorCode::Unauthorized
+    );
+    require!(
+        distributor.asset_kind == AssetKind::Token,
+        ErrorCode::AssetKindMismatch
+    );
+    require!(!distributor.multi_mint, ErrorCode::ClaimModeMismatch);
+    require_keys_eq!(
+        ctx.accounts.token_vault.key(),
+        distributor.token_vault,
+        anchor_lang::error::ErrorCode::ConstraintAddress
+    );
+    require!(distributor.clawed_back, ErrorCode::DistributorNotClawedBack);
+
+    let seeds = [
+        b"MerkleDistributor".as_ref(),
+        &distributor.mint.to_bytes(),
+        &distributor.version.to_le_bytes(),
+        &[distributor.bump],
+    ];
+
+    token_interface::close_account(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::CloseAccount {
+                account: ctx.accounts.token_vault.to_account_info(),
+                destination: ctx.accounts.receiver.to_account_info(),
+                authority: distributor_info.clone(),
+            },
+        )
+        .with_signer(&[&seeds[..]]),
+    )?;
+
+    distributor_info.realloc(DistributorTombstone::LEN, false)?;
+    DistributorTombstone {
+        admin: distributor.admin,
+    }
+    .try_serialize(&mut &mut distributor_info.try_borrow_mut_data()?[..])?;
+
+    let receiver = ctx.accounts.receiver.to_account_info();
+    let rent_returned = distributor_info
+        .lamports()
+        .checked_sub(Rent::get()?.minimum_balance(DistributorTombstone::LEN))
+        .ok_or(ErrorCode::ArithmeticError)?;
+    **distributor_info.try_borrow_mut_lamports()? = distributor_info
+        .lamports()
+        .checked_sub(rent_returned)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    **receiver.try_borrow_mut_lamports()? = receiver
+        .lamports()
+        .checked_add(rent_returned)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Closed distributor and vault {}, rent sent to {}",
+        ctx.accounts.token_vault.key(),
+        receiver.key()
+    );
+    emit!(DistributorClosedEvent {
+        distributor: distributor_info.key(),
+        receiver: receiver.key(),
+    });
+
+    Ok(())
+}
+
+/// Returns the admin allowed to close the leftover accounts of `distributor` once nothing can be
+/// claimed from it anymore: the admin of a clawed back [MerkleDistributor], or the admin kept in
+/// the [DistributorTombstone] of a closed one.
+/// Shared by [close_claim_bitmap](crate::instructions::handle_close_claim_bitmap) and
+/// [close_closed_claims](crate::instructions::handle_close_closed_claims).
+#[allow(clippy::result_large_err)]
+pub(crate) fn clawed_back_admin(distributor: &AccountInfo) -> Result<Pubkey> {
+    if let Ok(tombstone) = Account::<DistributorTombstone>::try_from(distributor) {
+        return Ok(tombstone.admin);
+    }
+
+    let distributor = Account::<MerkleDistributor>::try_from(distributor)?;
+    require!(distributor.clawed_back, ErrorCode::DistributorNotClawedBack);
+
+    Ok(distributor.admin)
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_spl::token;
+
+    use super::*;
+    use crate::test_utils::{process_instruction, TestAccount};
+
+    #[test]
+    fn test_close_distributor_leaves_tombstone() {
+        let (distributor, admin, token_vault, receiver) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let clawed_back_distributor = MerkleDistributor {
+            admin,
+            token_vault,
+            clawed_back: true,
+            ..MerkleDistributor::default()
+        };
+
+        let close = |clawed_back_distributor: &MerkleDistributor| {
+            let mut accounts = [
+                TestAccount::program(distributor, clawed_back_distributor, MerkleDistributor::LEN),
+                TestAccount::token_account(token_vault, Pubkey::default(), distributor, 0),
+                TestAccount::wallet(admin),
+                TestAccount::wallet(receiver),
+                TestAccount::executable(token::ID),
+            ];
+            let result = process_instruction(
+                crate::accounts::CloseDistributor {
+                    distributor,
+                    token_vault,
+                    admin,
+                    receiver,
+                    token_program: token::ID,
+                },
+                crate::instruction::CloseDistributor {},
+                &mut accounts,
+            );
+            result.map(|()| accounts)
+        };
+
+        let [distributor, _, _, receiver, _] = close(&clawed_back_distributor).unwrap();
+        assert_eq!(
+            DistributorTombstone::try_deserialize(&mut distributor.data.as_slice())
+                .unwrap()
+                .admin,
+            admin
+        );
+        assert_eq!(distributor.data.len(), DistributorTombstone::LEN);
+        let tombstone_rent = Rent::default().minimum_balance(DistributorTombstone::LEN);
+        assert_eq!(distributor.lamports, tombstone_rent);
+        assert_eq!(
+            receiver.lamports,
+            TestAccount::wallet(Pubkey::default()).lamports
+                + Rent::default().minimum_balance(MerkleDistributor::LEN)
+                - tombstone_rent
+        );
+
+        assert_eq!(
+            close(&MerkleDistributor {
+                clawed_back: false,
+                ..clawed_back_distributor
+            })
+            .err(),
+            Some(Error::from(ErrorCode::DistributorNotClawedBack).into())
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+use anchor_spl::{
+    token_interface,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{distributor_event::FundedEvent, merkle_distributor::MerkleDistributor},
+    utils::get_transfer_fee,
+};
+
+/// [merkle_distributor::fund_distributor] accounts.
+#[derive(Accounts)]
+pub struct FundDistributor<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Admin token account the tokens are transferred from.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = admin.key(),
+        token::token_program = token_program,
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or [Token-2022] program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Transfers `amount` tokens from the admin into the token vault and adds what the vault
+/// received (net of any Token-2022 transfer fee) to total_amount_funded.
+/// CHECK:
+///     1. The distributor has not been clawed back
+#[allow(clippy::result_large_err)]
+pub fn handle_fund_distributor(ctx: Context<FundDistributor>, amount: u64) -> Result<()> {
+    require!(
+        !ctx.accounts.distributor.clawed_back,
+        ErrorCode::ClaimExpired
+    );
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.token_vault.to_account_info(),
+                authority: ctx.accounts.admin.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    let transfer_fee = get_transfer_fee(&ctx.accounts.mint.to_account_info(), amount)?;
+    let amount_received = amount
+        .checked_sub(transfer_fee)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.total_amount_funded = distributor
+        .total_amount_funded
+        .checked_add(amount_received)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    ctx.accounts.token_vault.reload()?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Funded distributor with {} ({} withheld as transfer fee), vault holds {} of {} remaining claims",
+        amount_received,
+        transfer_fee,
+        ctx.accounts.token_vault.amount,
+        distributor.remaining_claim(),
+    );
+    emit!(FundedEvent {
+        distributor: distributor.key(),
+        amount: amount_received,
+        total_amount_funded: distributor.total_amount_funded,
+    });
+
+    Ok(())
+}
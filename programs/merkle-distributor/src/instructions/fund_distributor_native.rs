@@ -0,0 +1,78 @@
+use anchor_lang::{context::Context, prelude::*, system_program, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        asset_kind::AssetKind, distributor_event::FundedEvent,
+        merkle_distributor::MerkleDistributor, sol_vault::SolVault,
+    },
+};
+
+/// [merkle_distributor::fund_distributor_native] accounts.
+#[derive(Accounts)]
+pub struct FundDistributorNative<'info> {
+    /// The native SOL [MerkleDistributor].
+    #[account(
+        mut,
+        constraint = distributor.asset_kind == AssetKind::NativeSol @ ErrorCode::AssetKindMismatch
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// [SolVault] holding the lamports to distribute.
+    #[account(mut, address = distributor.token_vault)]
+    pub sol_vault: Account<'info, SolVault>,
+
+    /// Admin signer, the lamports are transferred from.
+    #[account(mut, address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Transfers `amount` lamports from the admin into the [SolVault] and adds them to
+/// total_amount_funded.
+/// CHECK:
+///     1. The distributor has not been clawed back
+#[allow(clippy::result_large_err)]
+pub fn handle_fund_distributor_native(
+    ctx: Context<FundDistributorNative>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.distributor.clawed_back,
+        ErrorCode::ClaimExpired
+    );
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.admin.to_account_info(),
+                to: ctx.accounts.sol_vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.total_amount_funded = distributor
+        .total_amount_funded
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Funded distributor with {} lamports, vault holds {} of {} remaining claims",
+        amount,
+        SolVault::balance(&ctx.accounts.sol_vault.to_account_info())?,
+        distributor.remaining_claim(),
+    );
+    emit!(FundedEvent {
+        distributor: distributor.key(),
+        amount,
+        total_amount_funded: distributor.total_amount_funded,
+    });
+
+    Ok(())
+}
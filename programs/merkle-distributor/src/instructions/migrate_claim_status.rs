@@ -0,0 +1,125 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Discriminator, Key, Result};
+
+use crate::{
+    state::{claim_status::ClaimStatus, claimed_event::ClaimStatusMigratedEvent},
+    utils::grow_account,
+};
+
+/// [merkle_distributor::migrate_claim_status] accounts.
+#[derive(Accounts)]
+pub struct MigrateClaimStatus<'info> {
+    /// CHECK: The [ClaimStatus], too small to deserialize until migrated. The owner is checked here
+    /// and the discriminator in the handler.
+    #[account(mut, owner = crate::ID)]
+    pub claim_status: UncheckedAccount<'info>,
+
+    /// Anyone, pays the rent for the grown claim status.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Grows a [ClaimStatus] created by an earlier version of the program to [ClaimStatus::LEN]. The
+/// lockup fields appended since start zeroed, so the claim keeps vesting against the distributor's
+/// lockup. Locked tokens can't be withdrawn until it is migrated.
+/// CHECK:
+///     1. The account is a [ClaimStatus]
+///     2. The account is smaller than [ClaimStatus::LEN]
+#[allow(clippy::result_large_err)]
+pub fn handle_migrate_claim_status(ctx: Context<MigrateClaimStatus>) -> Result<()> {
+    let claim_status = ctx.accounts.claim_status.to_account_info();
+
+    require!(
+        claim_status.try_borrow_data()?.get(..8) == Some(&ClaimStatus::DISCRIMINATOR[..]),
+        anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    grow_account(
+        &claim_status,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ClaimStatus::LEN,
+    )?;
+
+    // Checks the migrated claim status deserializes
+    Account::<ClaimStatus>::try_from(&claim_status)?;
+
+    // Note: might get truncated, do not rely on
+    msg!("Migrated claim status {}", claim_status.key());
+    emit!(ClaimStatusMigratedEvent {
+        claim_status: claim_status.key(),
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::{solana_program::entrypoint::ProgramResult, system_program};
+
+    use super::*;
+    use crate::{
+        error::ErrorCode,
+        test_utils::{process_instruction, TestAccount},
+    };
+
+    /// [ClaimStatus::LEN] before the lockup fields were appended.
+    const ORIGINAL_LEN: usize = 64;
+
+    fn migrate(claim_status: TestAccount) -> (ProgramResult, TestAccount) {
+        let (key, payer) = (claim_status.key, Pubkey::new_unique());
+        let mut accounts = [
+            claim_status,
+            TestAccount::wallet(payer),
+            TestAccount::executable(system_program::ID),
+        ];
+        let result = process_instruction(
+            crate::accounts::MigrateClaimStatus {
+                claim_status: key,
+                payer,
+                system_program: system_program::ID,
+            },
+            crate::instruction::MigrateClaimStatus {},
+            &mut accounts,
+        );
+        let [claim_status, ..] = accounts;
+        (result, claim_status)
+    }
+
+    #[test]
+    fn test_migrate_claim_status() {
+        let original = ClaimStatus {
+            claimant: Pubkey::new_unique(),
+            locked_amount: 100,
+            locked_amount_withdrawn: 40,
+            unlocked_amount: 10,
+            ..ClaimStatus::default()
+        };
+        let mut data = vec![];
+        original.try_serialize(&mut data).unwrap();
+        data.truncate(ORIGINAL_LEN);
+        let claim_status = TestAccount {
+            data,
+            ..TestAccount::program(Pubkey::new_unique(), &original, ORIGINAL_LEN)
+        };
+        assert!(ClaimStatus::try_deserialize(&mut claim_status.data.as_slice()).is_err());
+
+        let (result, claim_status) = migrate(claim_status);
+        result.unwrap();
+        assert_eq!(claim_status.data.len(), ClaimStatus::LEN);
+        let migrated = ClaimStatus::try_deserialize(&mut claim_status.data.as_slice()).unwrap();
+        assert_eq!(migrated.claimant, original.claimant);
+        assert_eq!(migrated.locked_amount, 100);
+        assert_eq!(migrated.locked_amount_withdrawn, 40);
+        assert_eq!(migrated.unlocked_amount, 10);
+        // vests against the distributor's lockup
+        assert_eq!(migrated.lockup(1_000, 2_000), (1_000, 2_000));
+
+        assert_eq!(
+            migrate(claim_status).0,
+            Err(Error::from(ErrorCode::AlreadyMigrated).into())
+        );
+    }
+}
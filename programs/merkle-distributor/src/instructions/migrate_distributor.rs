@@ -0,0 +1,122 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Discriminator, Key, Result};
+
+use crate::{
+    state::{distributor_event::DistributorMigratedEvent, merkle_distributor::MerkleDistributor},
+    utils::grow_account,
+};
+
+/// [merkle_distributor::migrate_distributor] accounts.
+#[derive(Accounts)]
+pub struct MigrateDistributor<'info> {
+    /// CHECK: The [MerkleDistributor], too small to deserialize until migrated. The owner is
+    /// checked here and the discriminator in the handler.
+    #[account(mut, owner = crate::ID)]
+    pub distributor: UncheckedAccount<'info>,
+
+    /// Anyone, pays the rent for the grown distributor.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Grows a [MerkleDistributor] created by an earlier version of the program to
+/// [MerkleDistributor::LEN]. The fields appended since start zeroed, which is their default, so the
+/// distributor keeps behaving as it did. Instructions on the distributor fail until it is migrated.
+/// CHECK:
+///     1. The account is a [MerkleDistributor]
+///     2. The account is smaller than [MerkleDistributor::LEN]
+#[allow(clippy::result_large_err)]
+pub fn handle_migrate_distributor(ctx: Context<MigrateDistributor>) -> Result<()> {
+    let distributor = ctx.accounts.distributor.to_account_info();
+
+    require!(
+        distributor.try_borrow_data()?.get(..8) == Some(&MerkleDistributor::DISCRIMINATOR[..]),
+        anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    grow_account(
+        &distributor,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        MerkleDistributor::LEN,
+    )?;
+
+    // Checks the migrated distributor deserializes
+    Account::<MerkleDistributor>::try_from(&distributor)?;
+
+    // Note: might get truncated, do not rely on
+    msg!("Migrated distributor {}", distributor.key());
+    emit!(DistributorMigratedEvent {
+        distributor: distributor.key(),
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::{solana_program::entrypoint::ProgramResult, system_program};
+
+    use super::*;
+    use crate::{
+        error::ErrorCode,
+        test_utils::{process_instruction, TestAccount},
+    };
+
+    /// Serialized length of the fields of a [MerkleDistributor] created before any field was appended.
+    const ORIGINAL_FIELDS_LEN: usize = 8 + 226;
+    /// [MerkleDistributor::LEN] before any field was appended.
+    const ORIGINAL_LEN: usize = 240;
+
+    fn migrate(distributor: TestAccount) -> (ProgramResult, TestAccount) {
+        let (key, payer) = (distributor.key, Pubkey::new_unique());
+        let mut accounts = [
+            distributor,
+            TestAccount::wallet(payer),
+            TestAccount::executable(system_program::ID),
+        ];
+        let result = process_instruction(
+            crate::accounts::MigrateDistributor {
+                distributor: key,
+                payer,
+                system_program: system_program::ID,
+            },
+            crate::instruction::MigrateDistributor {},
+            &mut accounts,
+        );
+        let [distributor, ..] = accounts;
+        (result, distributor)
+    }
+
+    #[test]
+    fn test_migrate_distributor() {
+        let original = MerkleDistributor {
+            admin: Pubkey::new_unique(),
+            max_total_claim: 1_000,
+            start_ts: 1_000,
+            end_ts: 2_000,
+            clawed_back: true,
+            ..MerkleDistributor::default()
+        };
+        let mut distributor = TestAccount::program(Pubkey::new_unique(), &original, ORIGINAL_LEN);
+        distributor.data[ORIGINAL_FIELDS_LEN..].fill(0);
+        assert!(MerkleDistributor::try_deserialize(&mut distributor.data.as_slice()).is_err());
+
+        let (result, distributor) = migrate(distributor);
+        result.unwrap();
+        assert_eq!(distributor.data.len(), MerkleDistributor::LEN);
+        let migrated =
+            MerkleDistributor::try_deserialize(&mut distributor.data.as_slice()).unwrap();
+        assert_eq!(migrated.admin, original.admin);
+        assert_eq!(migrated.max_total_claim, 1_000);
+        assert_eq!((migrated.start_ts, migrated.end_ts), (1_000, 2_000));
+        assert!(migrated.clawed_back);
+
+        assert_eq!(
+            migrate(distributor).0,
+            Err(Error::from(ErrorCode::AlreadyMigrated).into())
+        );
+    }
+}
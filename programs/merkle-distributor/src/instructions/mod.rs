@@ -1,13 +1,95 @@
+pub use accept_admin::*;
+pub use accept_clawback_receiver::*;
+pub use accept_clawback_receiver_native::*;
+pub use add_distributor_mint::*;
+pub use cancel_claim_recovery::*;
+pub use cancel_pending_admin::*;
+pub use cancel_pending_clawback_receiver::*;
+pub use claim_cumulative::*;
 pub use claim_locked::*;
+pub use claim_locked_multi_mint::*;
+pub use claim_locked_native::*;
+pub use claim_locked_on_behalf::*;
+pub use claim_locked_to::*;
 pub use clawback::*;
+pub use clawback_multi_mint::*;
+pub use clawback_native::*;
+pub use clawback_surplus::*;
+pub use close_claim_bitmap::*;
+pub use close_claim_status::*;
+pub use close_closed_claims::*;
+pub use close_distributor::*;
+pub use fund_distributor::*;
+pub use fund_distributor_native::*;
+pub use migrate_claim_status::*;
+pub use migrate_distributor::*;
 pub use new_claim::*;
+pub use new_claim_bitmap::*;
+pub use new_claim_indexed::*;
+pub use new_claim_multi_mint::*;
+pub use new_claim_native::*;
+pub use new_claim_on_behalf::*;
+pub use new_claim_to::*;
+pub use new_cumulative_claim::*;
 pub use new_distributor::*;
+pub use new_distributor_native::*;
+pub use pause::*;
+pub use propose_claim_recovery::*;
+pub use recover_claim::*;
+pub use revoke::*;
+pub use revoke_unclaimed::*;
 pub use set_admin::*;
 pub use set_clawback_receiver::*;
+pub use set_clawback_receiver_native::*;
+pub use transfer_claim::*;
+pub use unpause::*;
+pub use update_root::*;
+pub use update_timestamps::*;
+pub mod accept_admin;
+pub mod accept_clawback_receiver;
+pub mod accept_clawback_receiver_native;
+pub mod add_distributor_mint;
+pub mod cancel_claim_recovery;
+pub mod cancel_pending_admin;
+pub mod cancel_pending_clawback_receiver;
+pub mod claim_cumulative;
 pub mod claim_locked;
+pub mod claim_locked_multi_mint;
+pub mod claim_locked_native;
+pub mod claim_locked_on_behalf;
+pub mod claim_locked_to;
 pub mod clawback;
+pub mod clawback_multi_mint;
+pub mod clawback_native;
+pub mod clawback_surplus;
+pub mod close_claim_bitmap;
+pub mod close_claim_status;
+pub mod close_closed_claims;
+pub mod close_distributor;
+pub mod fund_distributor;
+pub mod fund_distributor_native;
+pub mod migrate_claim_status;
+pub mod migrate_distributor;
 pub mod new_claim;
+pub mod new_claim_bitmap;
+pub mod new_claim_indexed;
+pub mod new_claim_multi_mint;
+pub mod new_claim_native;
+pub mod new_claim_on_behalf;
+pub mod new_claim_to;
+pub mod new_cumulative_claim;
 pub mod new_distributor;
+pub mod new_distributor_native;
+pub mod pause;
 
+pub mod propose_claim_recovery;
+pub mod recover_claim;
+pub mod revoke;
+pub mod revoke_unclaimed;
 pub mod set_admin;
 pub mod set_clawback_receiver;
+pub mod set_clawback_receiver_native;
+pub mod transfer_claim;
+pub mod unpause;
+pub mod update_root;
+pub mod update_timestamps;
@@ -1,25 +1,29 @@
 use anchor_lang::{
-    context::Context, prelude::*, solana_program::hash::hashv, system_program::System, Accounts,
-    Key, Result,
+    context::Context, prelude::*, solana_program::hash::hashv, system_program,
+    system_program::System, Accounts, Key, Result,
 };
 use anchor_spl::{
-    token,
-    token::{Token, TokenAccount},
+    token_interface,
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 use jito_merkle_verify::verify;
 
 use crate::{
     error::ErrorCode,
+    instructions::close_claim_status::is_claim_closed,
     state::{
-        claim_status::ClaimStatus, claimed_event::NewClaimEvent,
+        claim_status::ClaimStatus,
+        claimed_event::{ClaimFeePaidEvent, NewClaimEvent},
+        closed_claims::ClosedClaims,
         merkle_distributor::MerkleDistributor,
     },
+    utils::{get_transfer_fee, verify_attestation},
 };
 
 // We need to discern between leaf and intermediate nodes to prevent trivial second
 // pre-image attacks.
 // https://flawed.net.nz/2018/02/21/attacking-merkle-trees-with-a-second-preimage-attack
-const LEAF_PREFIX: &[u8] = &[0];
+pub(crate) const LEAF_PREFIX: &[u8] = &[0];
 
 /// [merkle_distributor::new_claim] accounts.
 #[derive(Accounts)]
@@ -42,55 +46,339 @@ pub struct NewClaim<'info> {
     )]
     pub claim_status: Account<'info, ClaimStatus>,
 
+    /// CHECK: [ClosedClaims] chunk that would record the claimant, which may not exist yet.
+    #[account(
+        seeds = [
+            b"ClosedClaims".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            &[ClosedClaims::chunk_index_of(&claimant.key())]
+        ],
+        bump,
+    )]
+    pub closed_claims: UncheckedAccount<'info>,
+
     /// Distributor ATA containing the tokens to distribute.
     #[account(
         mut,
-        associated_token::mint = distributor.mint,
-        associated_token::authority = distributor.key(),
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
         address = distributor.token_vault
     )]
-    pub from: Account<'info, TokenAccount>,
+    pub from: InterfaceAccount<'info, TokenAccount>,
 
     /// Account to send the claimed tokens to.
     #[account(
         mut,
         token::mint=distributor.mint,
-        token::authority = claimant.key()
+        token::authority = claimant.key(),
+        token::token_program = token_program,
     )]
-    pub to: Account<'info, TokenAccount>,
+    pub to: InterfaceAccount<'info, TokenAccount>,
 
     /// Who is claiming the tokens.
     #[account(mut, address = to.owner @ ErrorCode::OwnerMismatch)]
     pub claimant: Signer<'info>,
 
-    /// SPL [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or [Token-2022] program.
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// The [System] program.
     pub system_program: Program<'info, System>,
+
+    /// CHECK: The instructions sysvar, holds the attestation.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Claim fee receiver, for lamport fees.
+    #[account(mut, address = distributor.fee_receiver @ ErrorCode::FeeReceiverMismatch)]
+    pub fee_receiver: Option<SystemAccount<'info>>,
+
+    /// Claim fee receiver's token account, for token fees.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.fee_receiver,
+        token::token_program = token_program,
+    )]
+    pub fee_receiver_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 }
 
 /// Initializes a new claim from the [MerkleDistributor].
 /// 1. Increments num_nodes_claimed by 1
 /// 2. Initializes claim_status
-/// 3. Transfers claim_status.unlocked_amount to the claimant
-/// 4. Increments total_amount_claimed by claim_status.unlocked_amount
+/// 3. Transfers claim_status.unlocked_amount to the claimant, minus the claim fee if the
+///    distributor charges one in tokens, which is sent to the fee receiver's token account
+/// 4. Transfers the claim fee from the claimant to the fee receiver, if the distributor charges
+///    one in lamports
+/// 5. Increments total_amount_claimed by claim_status.unlocked_amount
+///    (the amount debited from the vault, including any Token-2022 transfer fee and claim fee)
+///
 /// CHECK:
 ///     1. The claim window has not expired and the distributor has not been clawed back
 ///     2. The claimant is the owner of the to account
 ///     3. Num nodes claimed is less than max_num_nodes
 ///     4. The merkle proof is valid
+///     5. The claimant has not closed a previous claim
+///     6. A lockup committed in the leaf sets both start_ts and end_ts and holds the invariants of
+///        the distributor's own lockup: start_ts before end_ts, a valid vesting schedule and a
+///        clawback start at least a day after end_ts
+///     7. The distributor is not paused
+///     8. The distributor tracks claims with [ClaimStatus] accounts, is not cumulative and is not
+///        multi-mint
+///     9. The vault holds every remaining claim, if the distributor requires full funding
+///     10. The transaction holds an unexpired attestation of the claimant, if the distributor has an
+///         attestation signer
+///     11. The fee receiver accounts the claim fee is paid to are present and owned by the
+///         distributor's fee receiver
 #[allow(clippy::result_large_err)]
 pub fn handle_new_claim(
     ctx: Context<NewClaim>,
     amount_unlocked: u64,
     amount_locked: u64,
     proof: Vec<[u8; 32]>,
+    start_ts: Option<i64>,
+    end_ts: Option<i64>,
 ) -> Result<()> {
-    let distributor = &mut ctx.accounts.distributor;
+    process_new_claim(
+        &mut ctx.accounts.distributor,
+        &mut ctx.accounts.claim_status,
+        &ctx.accounts.closed_claims,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.claimant,
+        &ctx.accounts.system_program,
+        ctx.accounts.fee_receiver.as_ref(),
+        ctx.accounts.fee_receiver_token_account.as_ref(),
+        ctx.accounts.claimant.key(),
+        amount_unlocked,
+        amount_locked,
+        proof,
+        start_ts,
+        end_ts,
+    )
+}
 
+/// Verifies the leaf for `claimant`, seeds the claim_status, transfers the unlocked tokens and
+/// collects the claim fee, lamports being paid by `fee_payer`.
+/// Shared by [handle_new_claim], [handle_new_claim_on_behalf](crate::instructions::handle_new_claim_on_behalf)
+/// and [handle_new_claim_to](crate::instructions::handle_new_claim_to), callers are responsible for
+/// checking the claimant authorized sending tokens to the `to` account.
+#[allow(clippy::result_large_err)]
+pub(crate) fn process_new_claim<'info>(
+    distributor: &mut Account<'info, MerkleDistributor>,
+    claim_status: &mut Account<'info, ClaimStatus>,
+    closed_claims: &AccountInfo<'info>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    instructions_sysvar: &AccountInfo<'info>,
+    fee_payer: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    fee_receiver: Option<&SystemAccount<'info>>,
+    fee_receiver_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    claimant: Pubkey,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    proof: Vec<[u8; 32]>,
+    start_ts: Option<i64>,
+    end_ts: Option<i64>,
+) -> Result<()> {
     let curr_ts = Clock::get()?.unix_timestamp;
+    open_claim(
+        distributor,
+        claim_status,
+        Some(closed_claims),
+        instructions_sysvar,
+        claimant,
+        None,
+        from.amount,
+        curr_ts,
+        amount_unlocked,
+        amount_locked,
+        proof,
+        start_ts,
+        end_ts,
+    )?;
+
+    let seeds = [
+        b"MerkleDistributor".as_ref(),
+        &distributor.mint.to_bytes(),
+        &distributor.version.to_le_bytes(),
+        &[distributor.bump],
+    ];
+
+    let fee_amount = distributor
+        .claim_fee
+        .token_amount(claim_status.unlocked_amount)?;
+    let fee_lamports = distributor.claim_fee.lamports();
+    let amount = claim_status
+        .unlocked_amount
+        .checked_sub(fee_amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: from.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: distributor.to_account_info(),
+            },
+        )
+        .with_signer(&[&seeds[..]]),
+        amount,
+        mint.decimals,
+    )?;
+
+    let transfer_fee = get_transfer_fee(&mint.to_account_info(), amount)?;
+
+    if fee_amount > 0 {
+        let fee_receiver_token_account =
+            fee_receiver_token_account.ok_or(ErrorCode::MissingFeeReceiver)?;
+        token_interface::transfer_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: from.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: fee_receiver_token_account.to_account_info(),
+                    authority: distributor.to_account_info(),
+                },
+            )
+            .with_signer(&[&seeds[..]]),
+            fee_amount,
+            mint.decimals,
+        )?;
+    }
+
+    if fee_lamports > 0 {
+        let fee_receiver = fee_receiver.ok_or(ErrorCode::MissingFeeReceiver)?;
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: fee_payer.to_account_info(),
+                    to: fee_receiver.to_account_info(),
+                },
+            ),
+            fee_lamports,
+        )?;
+    }
+
+    let (lockup_start_ts, lockup_end_ts) =
+        claim_status.lockup(distributor.start_ts, distributor.end_ts);
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Created new claim with locked {} and {} unlocked ({} withheld as transfer fee) with lockup start:{} end:{}",
+        claim_status.locked_amount,
+        claim_status.unlocked_amount,
+        transfer_fee,
+        lockup_start_ts,
+        lockup_end_ts,
+    );
+    emit!(NewClaimEvent {
+        distributor: distributor.key(),
+        claimant,
+        timestamp: curr_ts,
+        to: to.key(),
+        amount_unlocked,
+        amount_locked,
+        start_ts,
+        end_ts,
+        index: None,
+        mint_index: None,
+    });
+
+    if fee_amount > 0 || fee_lamports > 0 {
+        // Note: might get truncated, do not rely on
+        msg!(
+            "Paid claim fee of {} lamports and {} tokens to {}",
+            fee_lamports,
+            fee_amount,
+            distributor.fee_receiver,
+        );
+        emit!(ClaimFeePaidEvent {
+            distributor: distributor.key(),
+            claimant,
+            fee_receiver: distributor.fee_receiver,
+            lamports: fee_lamports,
+            amount: fee_amount,
+        });
+    }
+
+    Ok(())
+}
+
+/// Verifies the leaf for `claimant`, seeds the claim_status and adds its unlocked amount to
+/// total_amount_claimed, leaving the transfer out of a vault holding `vault_amount` to the caller.
+/// Leaves of multi-mint distributors commit to the `mint_index` of the claimed mint instead of a
+/// lockup. Their claims are never closed, so they have no `closed_claims` chunk to check.
+/// Shared by [process_new_claim],
+/// [handle_new_claim_native](crate::instructions::handle_new_claim_native) and
+/// [handle_new_claim_multi_mint](crate::instructions::handle_new_claim_multi_mint).
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::result_large_err)]
+pub(crate) fn open_claim(
+    distributor: &mut Account<MerkleDistributor>,
+    claim_status: &mut ClaimStatus,
+    closed_claims: Option<&AccountInfo>,
+    instructions_sysvar: &AccountInfo,
+    claimant: Pubkey,
+    mint_index: Option<u8>,
+    vault_amount: u64,
+    curr_ts: i64,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    proof: Vec<[u8; 32]>,
+    start_ts: Option<i64>,
+    end_ts: Option<i64>,
+) -> Result<()> {
     require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+    require!(
+        !distributor.claim_deadline_passed(curr_ts),
+        ErrorCode::ClaimDeadlinePassed
+    );
+    if let Some(attestation_signer) = distributor.attestation_signer {
+        verify_attestation(
+            instructions_sysvar,
+            &attestation_signer,
+            &distributor.key(),
+            &claimant,
+            curr_ts,
+        )?;
+    }
+    require!(!distributor.paused, ErrorCode::DistributorPaused);
+    require!(
+        !distributor.require_full_funding || distributor.is_fully_funded(vault_amount),
+        ErrorCode::VaultUnderfunded
+    );
+    require!(
+        !distributor.bitmap_claims && !distributor.cumulative,
+        ErrorCode::ClaimModeMismatch
+    );
+    require!(
+        distributor.multi_mint == mint_index.is_some(),
+        ErrorCode::ClaimModeMismatch
+    );
+
+    // The ClaimStatus PDA can be re-created after it is closed, so check the closed claims as well
+    if let Some(closed_claims) = closed_claims {
+        require!(
+            !is_claim_closed(closed_claims, &claimant)?,
+            ErrorCode::ClaimAlreadyClosed
+        );
+    }
 
     distributor.num_nodes_claimed = distributor
         .num_nodes_claimed
@@ -102,74 +390,275 @@ pub fn handle_new_claim(
         ErrorCode::MaxNodesExceeded
     );
 
-    let claimant_account = &ctx.accounts.claimant;
-
-    // Verify the merkle proof.
-    let node = hashv(&[
-        &claimant_account.key().to_bytes(),
-        &amount_unlocked.to_le_bytes(),
-        &amount_locked.to_le_bytes(),
-    ]);
-
-    let distributor = &ctx.accounts.distributor;
-    let node = hashv(&[LEAF_PREFIX, &node.to_bytes()]);
-
-    require!(
-        verify(proof, distributor.root, node.to_bytes()),
-        ErrorCode::InvalidProof
-    );
-
-    let claim_status = &mut ctx.accounts.claim_status;
+    let lockup = verify_leaf(
+        distributor,
+        claimant,
+        mint_index,
+        amount_unlocked,
+        amount_locked,
+        proof,
+        start_ts,
+        end_ts,
+    )?;
 
     // Seed initial values
-    claim_status.claimant = claimant_account.key();
+    claim_status.claimant = claimant;
     claim_status.locked_amount = amount_locked;
     claim_status.unlocked_amount = amount_unlocked;
     claim_status.locked_amount_withdrawn = 0;
+    (claim_status.start_ts, claim_status.end_ts) = lockup;
 
-    let seeds = [
-        b"MerkleDistributor".as_ref(),
-        &distributor.mint.to_bytes(),
-        &distributor.version.to_le_bytes(),
-        &[ctx.accounts.distributor.bump],
-    ];
-
-    token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
-                from: ctx.accounts.from.to_account_info(),
-                to: ctx.accounts.to.to_account_info(),
-                authority: ctx.accounts.distributor.to_account_info(),
-            },
-        )
-        .with_signer(&[&seeds[..]]),
-        claim_status.unlocked_amount,
-    )?;
-
-    let distributor = &mut ctx.accounts.distributor;
     distributor.total_amount_claimed = distributor
         .total_amount_claimed
         .checked_add(claim_status.unlocked_amount)
         .ok_or(ErrorCode::ArithmeticError)?;
 
+    // Amounts of different mints don't add up, multi-mint distributors cap each mint in its
+    // DistributorMint instead
     require!(
-        distributor.total_amount_claimed <= distributor.max_total_claim,
+        distributor.multi_mint || distributor.total_amount_claimed <= distributor.max_total_claim,
         ErrorCode::ExceededMaxClaim
     );
 
-    // Note: might get truncated, do not rely on
-    msg!(
-        "Created new claim with locked {} and {} unlocked with lockup start:{} end:{}",
-        claim_status.locked_amount,
-        claim_status.unlocked_amount,
-        distributor.start_ts,
-        distributor.end_ts,
+    Ok(())
+}
+
+/// Verifies `proof` of the leaf of `claimant` against the distributor's root, returning the lockup
+/// committed in the leaf, (0, 0) if it vests against the distributor's lockup.
+/// A lockup in the leaf is validated like the distributor's, see
+/// [MerkleDistributor::validate_timestamps].
+/// Shared by [open_claim] and [handle_revoke_unclaimed](crate::instructions::handle_revoke_unclaimed).
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::result_large_err)]
+pub(crate) fn verify_leaf(
+    distributor: &MerkleDistributor,
+    claimant: Pubkey,
+    mint_index: Option<u8>,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    proof: Vec<[u8; 32]>,
+    start_ts: Option<i64>,
+    end_ts: Option<i64>,
+) -> Result<(i64, i64)> {
+    // Verify the merkle proof. Leaves with their own lockup also commit to it,
+    // multi-mint leaves commit to their mint index.
+    let (node, lockup) = match (mint_index, start_ts, end_ts) {
+        (Some(mint_index), None, None) => {
+            let node = hashv(&[
+                &claimant.to_bytes(),
+                &[mint_index],
+                &amount_unlocked.to_le_bytes(),
+                &amount_locked.to_le_bytes(),
+            ]);
+            (node, (0, 0))
+        }
+        (None, Some(start_ts), Some(end_ts)) => {
+            MerkleDistributor::validate_timestamps(
+                start_ts,
+                end_ts,
+                distributor.clawback_start_ts,
+                &distributor.vesting_schedule,
+            )?;
+            let node = hashv(&[
+                &claimant.to_bytes(),
+                &amount_unlocked.to_le_bytes(),
+                &amount_locked.to_le_bytes(),
+                &start_ts.to_le_bytes(),
+                &end_ts.to_le_bytes(),
+            ]);
+            (node, (start_ts, end_ts))
+        }
+        (None, None, None) => {
+            let node = hashv(&[
+                &claimant.to_bytes(),
+                &amount_unlocked.to_le_bytes(),
+                &amount_locked.to_le_bytes(),
+            ]);
+            // 0 means the claim vests against the distributor's lockup
+            (node, (0, 0))
+        }
+        _ => return err!(ErrorCode::InvalidProof),
+    };
+
+    let node = hashv(&[LEAF_PREFIX, &node.to_bytes()]);
+
+    require!(
+        verify(proof, distributor.root, node.to_bytes()),
+        ErrorCode::InvalidProof
     );
-    emit!(NewClaimEvent {
-        claimant: claimant_account.key(),
-        timestamp: curr_ts
-    });
 
-    Ok(())
+    Ok(lockup)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::vesting_schedule::VestingSchedule;
+
+    const SECONDS_PER_DAY: i64 = 24 * 3600;
+
+    /// A distributor whose tree only holds the leaf of `claimant` with a lockup from start_ts to
+    /// end_ts.
+    fn single_leaf_distributor(claimant: Pubkey, start_ts: i64, end_ts: i64) -> MerkleDistributor {
+        let node = hashv(&[
+            &claimant.to_bytes(),
+            &10u64.to_le_bytes(),
+            &90u64.to_le_bytes(),
+            &start_ts.to_le_bytes(),
+            &end_ts.to_le_bytes(),
+        ]);
+        MerkleDistributor {
+            root: hashv(&[LEAF_PREFIX, &node.to_bytes()]).to_bytes(),
+            start_ts: 1_000,
+            end_ts: 2_000,
+            clawback_start_ts: 2_000 + SECONDS_PER_DAY,
+            ..MerkleDistributor::default()
+        }
+    }
+
+    #[test]
+    fn test_verify_leaf_lockup() {
+        let claimant = Pubkey::new_unique();
+        let distributor = single_leaf_distributor(claimant, 500, 1_500);
+
+        assert_eq!(
+            verify_leaf(
+                &distributor,
+                claimant,
+                None,
+                10,
+                90,
+                vec![],
+                Some(500),
+                Some(1_500)
+            )
+            .unwrap(),
+            (500, 1_500)
+        );
+        assert_eq!(
+            verify_leaf(
+                &distributor,
+                claimant,
+                None,
+                10,
+                90,
+                vec![],
+                Some(500),
+                Some(1_600)
+            )
+            .unwrap_err(),
+            ErrorCode::InvalidProof.into()
+        );
+    }
+
+    #[test]
+    fn test_verify_leaf_rejects_invalid_lockup() {
+        let claimant = Pubkey::new_unique();
+
+        let distributor = single_leaf_distributor(claimant, 1_500, 1_500);
+        assert_eq!(
+            verify_leaf(
+                &distributor,
+                claimant,
+                None,
+                10,
+                90,
+                vec![],
+                Some(1_500),
+                Some(1_500)
+            )
+            .unwrap_err(),
+            ErrorCode::StartTimestampAfterEnd.into()
+        );
+
+        // the leaf must finish vesting a day before the clawback, like the distributor
+        let end_ts = 2_001;
+        let distributor = single_leaf_distributor(claimant, 500, end_ts);
+        assert_eq!(
+            verify_leaf(
+                &distributor,
+                claimant,
+                None,
+                10,
+                90,
+                vec![],
+                Some(500),
+                Some(end_ts)
+            )
+            .unwrap_err(),
+            ErrorCode::InsufficientClawbackDelay.into()
+        );
+
+        // the cliff of the distributor's schedule must lie within the leaf's lockup
+        let distributor = MerkleDistributor {
+            vesting_schedule: VestingSchedule::CliffLinear {
+                cliff_offset: 600,
+                cliff_unlock_bps: 2_500,
+            },
+            ..single_leaf_distributor(claimant, 500, 1_100)
+        };
+        assert_eq!(
+            verify_leaf(
+                &distributor,
+                claimant,
+                None,
+                10,
+                90,
+                vec![],
+                Some(500),
+                Some(1_100)
+            )
+            .unwrap_err(),
+            ErrorCode::InvalidVestingSchedule.into()
+        );
+    }
+
+    #[test]
+    fn test_leaf_lockup_cliff_after_update_timestamps() {
+        let claimant = Pubkey::new_unique();
+        let mut distributor = MerkleDistributor {
+            vesting_schedule: VestingSchedule::CliffLinear {
+                cliff_offset: 200,
+                cliff_unlock_bps: 2_500,
+            },
+            ..single_leaf_distributor(claimant, 500, 1_100)
+        };
+        let verify = |distributor: &MerkleDistributor| {
+            verify_leaf(
+                distributor,
+                claimant,
+                None,
+                10,
+                90,
+                vec![],
+                Some(500),
+                Some(1_100),
+            )
+            .unwrap()
+        };
+        assert_eq!(verify(&distributor), (500, 1_100));
+
+        // moving the distributor's lockup leaves the leaf's lockup and its cliff untouched
+        distributor
+            .update_timestamps(400, 1_500, 2_500, 2_500 + SECONDS_PER_DAY)
+            .unwrap();
+        assert_eq!(verify(&distributor), (500, 1_100));
+
+        let claim_status = ClaimStatus {
+            claimant,
+            locked_amount: 90,
+            start_ts: 500,
+            end_ts: 1_100,
+            ..ClaimStatus::default()
+        };
+        let (start_ts, end_ts) = claim_status.lockup(distributor.start_ts, distributor.end_ts);
+        let unlocked_amount = |curr_ts| {
+            claim_status
+                .unlocked_amount(curr_ts, start_ts, end_ts, &distributor.vesting_schedule)
+                .unwrap()
+        };
+        assert_eq!(unlocked_amount(699), 0);
+        assert_eq!(unlocked_amount(700), 22);
+        assert_eq!(unlocked_amount(1_100), 90);
+    }
 }
@@ -0,0 +1,71 @@
+use anchor_lang::{context::Context, prelude::*, system_program::System, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claim_bitmap::ClaimBitmap, claim_bitmap_event::ClaimBitmapCreatedEvent,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::new_claim_bitmap] accounts.
+#[derive(Accounts)]
+#[instruction(chunk_index: u64)]
+pub struct NewClaimBitmap<'info> {
+    /// The [MerkleDistributor].
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim bitmap PDA
+    #[account(
+        init,
+        seeds = [
+            b"ClaimBitmap".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            chunk_index.to_le_bytes().as_ref()
+        ],
+        bump,
+        space = ClaimBitmap::LEN,
+        payer = payer
+    )]
+    pub claim_bitmap: AccountLoader<'info, ClaimBitmap>,
+
+    /// Payer of the [ClaimBitmap] rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the [ClaimBitmap] chunk tracking leaf indices from chunk_index * LEAVES_PER_CHUNK.
+/// Anyone can pay for a chunk, every chunk covering max_num_nodes must exist before its leaves can be claimed.
+/// CHECK:
+///     1. The distributor tracks claims with [ClaimBitmap] accounts
+///     2. The chunk covers at least one leaf index below max_num_nodes
+#[allow(clippy::result_large_err)]
+pub fn handle_new_claim_bitmap(ctx: Context<NewClaimBitmap>, chunk_index: u64) -> Result<()> {
+    let distributor = &ctx.accounts.distributor;
+
+    require!(distributor.bitmap_claims, ErrorCode::ClaimModeMismatch);
+    require!(
+        chunk_index < ClaimBitmap::num_chunks(distributor.max_num_nodes),
+        ErrorCode::MaxNodesExceeded
+    );
+
+    let mut claim_bitmap = ctx.accounts.claim_bitmap.load_init()?;
+    claim_bitmap.distributor = distributor.key();
+    claim_bitmap.chunk_index = chunk_index;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Created claim bitmap {} for distributor {}",
+        chunk_index,
+        distributor.key()
+    );
+    emit!(ClaimBitmapCreatedEvent {
+        distributor: distributor.key(),
+        chunk_index,
+    });
+
+    Ok(())
+}
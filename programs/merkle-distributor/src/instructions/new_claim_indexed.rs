@@ -0,0 +1,210 @@
+use anchor_lang::{
+    context::Context, prelude::*, solana_program::hash::hashv, Accounts, Key, Result,
+};
+use anchor_spl::{
+    token_interface,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use jito_merkle_verify::verify;
+
+use crate::{
+    error::ErrorCode,
+    instructions::new_claim::LEAF_PREFIX,
+    state::{
+        claim_bitmap::ClaimBitmap, claimed_event::NewClaimEvent,
+        merkle_distributor::MerkleDistributor,
+    },
+    utils::{get_transfer_fee, verify_attestation},
+};
+
+/// [merkle_distributor::new_claim_indexed] accounts.
+#[derive(Accounts)]
+#[instruction(index: u64)]
+pub struct NewClaimIndexed<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim bitmap chunk tracking the leaf index
+    #[account(
+        mut,
+        has_one = distributor,
+        constraint = claim_bitmap.load()?.chunk_index == ClaimBitmap::chunk_index_of(index) @ ErrorCode::ClaimBitmapMismatch
+    )]
+    pub claim_bitmap: AccountLoader<'info, ClaimBitmap>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = claimant.key(),
+        token::token_program = token_program,
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Who is claiming the tokens.
+    #[account(address = to.owner @ ErrorCode::OwnerMismatch)]
+    pub claimant: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or [Token-2022] program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: The instructions sysvar, holds the attestation.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+/// Claims the leaf at `index` from a [MerkleDistributor] tracking claims with [ClaimBitmap] accounts.
+/// No account is created for the claimant, the leaf is marked as claimed in the bitmap instead.
+/// Indexed leaves only carry unlocked tokens.
+/// 1. Marks the leaf index as claimed and increments num_nodes_claimed by 1
+/// 2. Transfers amount_unlocked to the claimant
+/// 3. Increments total_amount_claimed by amount_unlocked
+///
+/// CHECK:
+///     1. The distributor tracks claims with [ClaimBitmap] accounts
+///     2. The distributor has not been clawed back, is not paused and the claim deadline has not passed
+///     3. The leaf index is below max_num_nodes and has not been claimed
+///     4. The merkle proof is valid
+///     5. The claimant is the owner of the to account
+///     6. The vault holds every remaining claim, if the distributor requires full funding
+///     7. The transaction holds an unexpired attestation of the claimant, if the distributor has an
+///        attestation signer
+#[allow(clippy::result_large_err)]
+pub fn handle_new_claim_indexed(
+    ctx: Context<NewClaimIndexed>,
+    index: u64,
+    amount_unlocked: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let curr_ts = Clock::get()?.unix_timestamp;
+    let claimant = ctx.accounts.claimant.key();
+    let distributor = &mut ctx.accounts.distributor;
+
+    require!(distributor.bitmap_claims, ErrorCode::ClaimModeMismatch);
+    require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+    require!(
+        !distributor.claim_deadline_passed(curr_ts),
+        ErrorCode::ClaimDeadlinePassed
+    );
+    if let Some(attestation_signer) = distributor.attestation_signer {
+        verify_attestation(
+            &ctx.accounts.instructions_sysvar,
+            &attestation_signer,
+            &distributor.key(),
+            &claimant,
+            curr_ts,
+        )?;
+    }
+    require!(!distributor.paused, ErrorCode::DistributorPaused);
+    require!(
+        !distributor.require_full_funding || distributor.is_fully_funded(ctx.accounts.from.amount),
+        ErrorCode::VaultUnderfunded
+    );
+    require!(
+        index < distributor.max_num_nodes,
+        ErrorCode::MaxNodesExceeded
+    );
+
+    let mut claim_bitmap = ctx.accounts.claim_bitmap.load_mut()?;
+    require!(
+        !claim_bitmap.is_claimed(index),
+        ErrorCode::LeafAlreadyClaimed
+    );
+
+    // Verify the merkle proof, indexed leaves never have locked tokens.
+    let node = hashv(&[
+        &claimant.to_bytes(),
+        &amount_unlocked.to_le_bytes(),
+        &0u64.to_le_bytes(),
+        &index.to_le_bytes(),
+    ]);
+    let node = hashv(&[LEAF_PREFIX, &node.to_bytes()]);
+
+    require!(
+        verify(proof, distributor.root, node.to_bytes()),
+        ErrorCode::InvalidProof
+    );
+
+    claim_bitmap.set_claimed(index);
+
+    distributor.num_nodes_claimed = distributor
+        .num_nodes_claimed
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        distributor.num_nodes_claimed <= distributor.max_num_nodes,
+        ErrorCode::MaxNodesExceeded
+    );
+
+    let seeds = [
+        b"MerkleDistributor".as_ref(),
+        &distributor.mint.to_bytes(),
+        &distributor.version.to_le_bytes(),
+        &[distributor.bump],
+    ];
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.to.to_account_info(),
+                authority: distributor.to_account_info(),
+            },
+        )
+        .with_signer(&[&seeds[..]]),
+        amount_unlocked,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    let transfer_fee = get_transfer_fee(&ctx.accounts.mint.to_account_info(), amount_unlocked)?;
+
+    distributor.total_amount_claimed = distributor
+        .total_amount_claimed
+        .checked_add(amount_unlocked)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        distributor.total_amount_claimed <= distributor.max_total_claim,
+        ErrorCode::ExceededMaxClaim
+    );
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Claimed leaf {} with {} unlocked ({} withheld as transfer fee)",
+        index,
+        amount_unlocked,
+        transfer_fee,
+    );
+    emit!(NewClaimEvent {
+        distributor: distributor.key(),
+        claimant,
+        timestamp: curr_ts,
+        to: ctx.accounts.to.key(),
+        amount_unlocked,
+        amount_locked: 0,
+        start_ts: None,
+        end_ts: None,
+        index: Some(index),
+        mint_index: None,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use anchor_lang::{context::Context, prelude::*, system_program::System, Accounts, Key, Result};
+use anchor_spl::{
+    token_interface,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    error::ErrorCode,
+    instructions::new_claim::open_claim,
+    state::{
+        claim_status::ClaimStatus, claimed_event::NewClaimEvent, distributor_mint::DistributorMint,
+        merkle_distributor::MerkleDistributor,
+    },
+    utils::get_transfer_fee,
+};
+
+/// [merkle_distributor::new_claim_multi_mint] accounts.
+#[derive(Accounts)]
+#[instruction(mint_index: u8)]
+pub struct NewClaimMultiMint<'info> {
+    /// The multi-mint [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// [DistributorMint] of the claimed mint index.
+    #[account(
+        mut,
+        seeds = [
+            b"DistributorMint".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            [mint_index].as_ref()
+        ],
+        bump = distributor_mint.bump,
+        has_one = distributor,
+    )]
+    pub distributor_mint: Account<'info, DistributorMint>,
+
+    /// Claim status PDA of the claimant's leaf for the mint index
+    #[account(
+        init,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            [mint_index].as_ref()
+        ],
+        bump,
+        space = ClaimStatus::LEN,
+        payer = claimant
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// Distributor ATA containing the tokens of the mint.
+    #[account(
+        mut,
+        token::mint = distributor_mint.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor_mint.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to.
+    #[account(
+        mut,
+        token::mint = distributor_mint.mint,
+        token::authority = claimant.key(),
+        token::token_program = token_program,
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Who is claiming the tokens.
+    #[account(mut, address = to.owner @ ErrorCode::OwnerMismatch)]
+    pub claimant: Signer<'info>,
+
+    /// The claimed mint.
+    #[account(address = distributor_mint.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or [Token-2022] program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: The instructions sysvar, holds the attestation.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+/// Initializes a new claim of the leaf committing to (claimant, mint_index, amounts) from a
+/// multi-mint [MerkleDistributor], transferring the unlocked tokens of the mint to the claimant.
+/// A claimant claims each of their mints with its own instruction, all in the same transaction.
+/// CHECK:
+///     1. The same checks as [handle_new_claim](crate::instructions::handle_new_claim), except the
+///        distributor must be multi-mint
+///     2. The mint index is registered with the distributor
+///     3. The vault of the mint has not been clawed back
+///     4. The claimed tokens of the mint stay within its max_total_claim
+#[allow(clippy::result_large_err)]
+pub fn handle_new_claim_multi_mint(
+    ctx: Context<NewClaimMultiMint>,
+    mint_index: u8,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let curr_ts = Clock::get()?.unix_timestamp;
+    let distributor = &mut ctx.accounts.distributor;
+    let distributor_mint = &mut ctx.accounts.distributor_mint;
+    let claim_status = &mut ctx.accounts.claim_status;
+    let claimant = ctx.accounts.claimant.key();
+
+    require!(!distributor_mint.clawed_back, ErrorCode::ClaimExpired);
+
+    open_claim(
+        distributor,
+        claim_status,
+        None,
+        &ctx.accounts.instructions_sysvar,
+        claimant,
+        Some(mint_index),
+        ctx.accounts.from.amount,
+        curr_ts,
+        amount_unlocked,
+        amount_locked,
+        proof,
+        None,
+        None,
+    )?;
+
+    distributor_mint.total_amount_claimed = distributor_mint
+        .total_amount_claimed
+        .checked_add(claim_status.unlocked_amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        distributor_mint.total_amount_claimed <= distributor_mint.max_total_claim,
+        ErrorCode::ExceededMaxClaim
+    );
+
+    let seeds = [
+        b"MerkleDistributor".as_ref(),
+        &distributor.mint.to_bytes(),
+        &distributor.version.to_le_bytes(),
+        &[distributor.bump],
+    ];
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.to.to_account_info(),
+                authority: distributor.to_account_info(),
+            },
+        )
+        .with_signer(&[&seeds[..]]),
+        claim_status.unlocked_amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    let transfer_fee = get_transfer_fee(
+        &ctx.accounts.mint.to_account_info(),
+        claim_status.unlocked_amount,
+    )?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Created new claim of mint {} with locked {} and {} unlocked ({} withheld as transfer fee)",
+        mint_index,
+        claim_status.locked_amount,
+        claim_status.unlocked_amount,
+        transfer_fee,
+    );
+    emit!(NewClaimEvent {
+        distributor: distributor.key(),
+        claimant,
+        timestamp: curr_ts,
+        to: ctx.accounts.to.key(),
+        amount_unlocked,
+        amount_locked,
+        start_ts: None,
+        end_ts: None,
+        index: None,
+        mint_index: Some(mint_index),
+    });
+
+    Ok(())
+}
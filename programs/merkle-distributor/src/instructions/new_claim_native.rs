@@ -0,0 +1,130 @@
+use anchor_lang::{context::Context, prelude::*, system_program::System, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    instructions::new_claim::open_claim,
+    state::{
+        asset_kind::AssetKind, claim_status::ClaimStatus, claimed_event::NewClaimEvent,
+        closed_claims::ClosedClaims, merkle_distributor::MerkleDistributor, sol_vault::SolVault,
+    },
+};
+
+/// [merkle_distributor::new_claim_native] accounts.
+#[derive(Accounts)]
+pub struct NewClaimNative<'info> {
+    /// The native SOL [MerkleDistributor].
+    #[account(
+        mut,
+        constraint = distributor.asset_kind == AssetKind::NativeSol @ ErrorCode::AssetKindMismatch
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim status PDA
+    #[account(
+        init,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = ClaimStatus::LEN,
+        payer = claimant
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// CHECK: [ClosedClaims] chunk that would record the claimant, which may not exist yet.
+    #[account(
+        seeds = [
+            b"ClosedClaims".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            &[ClosedClaims::chunk_index_of(&claimant.key())]
+        ],
+        bump,
+    )]
+    pub closed_claims: UncheckedAccount<'info>,
+
+    /// [SolVault] holding the lamports to distribute.
+    #[account(mut, address = distributor.token_vault)]
+    pub sol_vault: Account<'info, SolVault>,
+
+    /// Who is claiming, receives the claimed lamports.
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: The instructions sysvar, holds the attestation.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+/// Initializes a new claim from a native SOL [MerkleDistributor], transferring the unlocked
+/// lamports to the claimant.
+/// CHECK:
+///     1. The same checks as [handle_new_claim](crate::instructions::handle_new_claim)
+///     2. The distributor distributes native SOL
+#[allow(clippy::result_large_err)]
+pub fn handle_new_claim_native(
+    ctx: Context<NewClaimNative>,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    proof: Vec<[u8; 32]>,
+    start_ts: Option<i64>,
+    end_ts: Option<i64>,
+) -> Result<()> {
+    let curr_ts = Clock::get()?.unix_timestamp;
+    let sol_vault = ctx.accounts.sol_vault.to_account_info();
+    let distributor = &mut ctx.accounts.distributor;
+    let claim_status = &mut ctx.accounts.claim_status;
+    let claimant = ctx.accounts.claimant.key();
+
+    open_claim(
+        distributor,
+        claim_status,
+        Some(&ctx.accounts.closed_claims),
+        &ctx.accounts.instructions_sysvar,
+        claimant,
+        None,
+        SolVault::balance(&sol_vault)?,
+        curr_ts,
+        amount_unlocked,
+        amount_locked,
+        proof,
+        start_ts,
+        end_ts,
+    )?;
+
+    SolVault::transfer(
+        &sol_vault,
+        &ctx.accounts.claimant.to_account_info(),
+        claim_status.unlocked_amount,
+    )?;
+
+    let (lockup_start_ts, lockup_end_ts) =
+        claim_status.lockup(distributor.start_ts, distributor.end_ts);
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Created new claim with locked {} and {} unlocked lamports with lockup start:{} end:{}",
+        claim_status.locked_amount,
+        claim_status.unlocked_amount,
+        lockup_start_ts,
+        lockup_end_ts,
+    );
+    emit!(NewClaimEvent {
+        distributor: distributor.key(),
+        claimant,
+        timestamp: curr_ts,
+        to: claimant,
+        amount_unlocked,
+        amount_locked,
+        start_ts,
+        end_ts,
+        index: None,
+        mint_index: None,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use anchor_lang::{context::Context, prelude::*, system_program::System, Accounts, Key, Result};
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    error::ErrorCode,
+    instructions::new_claim::process_new_claim,
+    state::{
+        claim_status::ClaimStatus, closed_claims::ClosedClaims,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::new_claim_on_behalf] accounts.
+#[derive(Accounts)]
+pub struct NewClaimOnBehalf<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim status PDA
+    #[account(
+        init,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = ClaimStatus::LEN,
+        payer = payer
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// CHECK: [ClosedClaims] chunk that would record the claimant, which may not exist yet.
+    #[account(
+        seeds = [
+            b"ClosedClaims".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            &[ClosedClaims::chunk_index_of(&claimant.key())]
+        ],
+        bump,
+    )]
+    pub closed_claims: UncheckedAccount<'info>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// The claimant's ATA, the only account the claimed tokens can be sent to.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = claimant.key(),
+        token::token_program = token_program,
+        address = get_associated_token_address_with_program_id(
+            &claimant.key(),
+            &distributor.mint,
+            &token_program.key(),
+        ) @ ErrorCode::OwnerMismatch
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Who the tokens are claimed for, only used to derive the claim status PDA and ATA
+    pub claimant: UncheckedAccount<'info>,
+
+    /// Who is running the claim, pays the rent for the claim status.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or [Token-2022] program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: The instructions sysvar, holds the attestation.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Claim fee receiver, for lamport fees.
+    #[account(mut, address = distributor.fee_receiver @ ErrorCode::FeeReceiverMismatch)]
+    pub fee_receiver: Option<SystemAccount<'info>>,
+
+    /// Claim fee receiver's token account, for token fees.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.fee_receiver,
+        token::token_program = token_program,
+    )]
+    pub fee_receiver_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+/// Initializes a new claim from the [MerkleDistributor] on behalf of the claimant.
+/// Same as [new_claim](crate::instructions::handle_new_claim), except anyone can pay for and
+/// send the transaction.
+/// CHECK:
+///     1. The distributor allows claiming on behalf of claimants
+///     2. The to account is the claimant's ATA
+///     3. All checks of [new_claim](crate::instructions::handle_new_claim)
+#[allow(clippy::result_large_err)]
+pub fn handle_new_claim_on_behalf(
+    ctx: Context<NewClaimOnBehalf>,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    proof: Vec<[u8; 32]>,
+    start_ts: Option<i64>,
+    end_ts: Option<i64>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.distributor.allow_claim_on_behalf,
+        ErrorCode::ClaimOnBehalfDisabled
+    );
+
+    process_new_claim(
+        &mut ctx.accounts.distributor,
+        &mut ctx.accounts.claim_status,
+        &ctx.accounts.closed_claims,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        ctx.accounts.fee_receiver.as_ref(),
+        ctx.accounts.fee_receiver_token_account.as_ref(),
+        ctx.accounts.claimant.key(),
+        amount_unlocked,
+        amount_locked,
+        proof,
+        start_ts,
+        end_ts,
+    )
+}
@@ -0,0 +1,239 @@
+use anchor_lang::{
+    context::Context, prelude::*, solana_program::hash::hashv, system_program::System, Accounts,
+    Key, Result,
+};
+use anchor_spl::{
+    token_interface,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use jito_merkle_verify::verify;
+
+use crate::{
+    error::ErrorCode,
+    instructions::new_claim::LEAF_PREFIX,
+    state::{
+        claimed_event::CumulativeClaimedEvent, cumulative_claim_status::CumulativeClaimStatus,
+        merkle_distributor::MerkleDistributor,
+    },
+    utils::{get_transfer_fee, verify_attestation},
+};
+
+/// [merkle_distributor::new_cumulative_claim] accounts.
+#[derive(Accounts)]
+pub struct NewCumulativeClaim<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Cumulative claim status PDA
+    #[account(
+        init,
+        seeds = [
+            b"CumulativeClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = CumulativeClaimStatus::LEN,
+        payer = claimant
+    )]
+    pub claim_status: Account<'info, CumulativeClaimStatus>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = claimant.key(),
+        token::token_program = token_program,
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Who is claiming the tokens.
+    #[account(mut, address = to.owner @ ErrorCode::OwnerMismatch)]
+    pub claimant: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or [Token-2022] program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: The instructions sysvar, holds the attestation.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+/// First claim of a claimant from a cumulative [MerkleDistributor].
+/// 1. Increments num_nodes_claimed by 1
+/// 2. Initializes the claim status
+/// 3. Pays out the cumulative amount in the leaf, see [process_cumulative_claim]
+///
+/// CHECK:
+///     1. Num nodes claimed is less than max_num_nodes
+///     2. All checks of [process_cumulative_claim]
+#[allow(clippy::result_large_err)]
+pub fn handle_new_cumulative_claim(
+    ctx: Context<NewCumulativeClaim>,
+    cumulative_amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    distributor.num_nodes_claimed = distributor
+        .num_nodes_claimed
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        distributor.num_nodes_claimed <= distributor.max_num_nodes,
+        ErrorCode::MaxNodesExceeded
+    );
+
+    ctx.accounts.claim_status.claimant = ctx.accounts.claimant.key();
+    ctx.accounts.claim_status.amount_claimed = 0;
+
+    process_cumulative_claim(
+        &mut ctx.accounts.distributor,
+        &mut ctx.accounts.claim_status,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.instructions_sysvar,
+        cumulative_amount,
+        proof,
+    )
+}
+
+/// Verifies the leaf (claimant, cumulative_amount, 0) against the current root and pays the
+/// difference between cumulative_amount and what the claimant has been paid so far.
+/// Shared by [handle_new_cumulative_claim] and
+/// [handle_claim_cumulative](crate::instructions::handle_claim_cumulative).
+/// CHECK:
+///     1. The distributor is cumulative
+///     2. The distributor has not been clawed back, is not paused and the claim deadline has not passed
+///     3. The vault holds every remaining claim, if the distributor requires full funding
+///     4. The merkle proof is valid
+///     5. The cumulative amount is more than what has already been paid
+///     6. The distributor amount claimed is ≤ than the max total claim
+///     7. The transaction holds an unexpired attestation of the claimant, if the distributor has an
+///        attestation signer
+#[allow(clippy::result_large_err)]
+pub(crate) fn process_cumulative_claim<'info>(
+    distributor: &mut Account<'info, MerkleDistributor>,
+    claim_status: &mut Account<'info, CumulativeClaimStatus>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    instructions_sysvar: &AccountInfo<'info>,
+    cumulative_amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let curr_ts = Clock::get()?.unix_timestamp;
+    require!(distributor.cumulative, ErrorCode::ClaimModeMismatch);
+    require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+    require!(
+        !distributor.claim_deadline_passed(curr_ts),
+        ErrorCode::ClaimDeadlinePassed
+    );
+    if let Some(attestation_signer) = distributor.attestation_signer {
+        verify_attestation(
+            instructions_sysvar,
+            &attestation_signer,
+            &distributor.key(),
+            &claim_status.claimant,
+            curr_ts,
+        )?;
+    }
+    require!(!distributor.paused, ErrorCode::DistributorPaused);
+    require!(
+        !distributor.require_full_funding || distributor.is_fully_funded(from.amount),
+        ErrorCode::VaultUnderfunded
+    );
+
+    // Cumulative leaves never have locked tokens
+    let node = hashv(&[
+        &claim_status.claimant.to_bytes(),
+        &cumulative_amount.to_le_bytes(),
+        &0u64.to_le_bytes(),
+    ]);
+    let node = hashv(&[LEAF_PREFIX, &node.to_bytes()]);
+
+    require!(
+        verify(proof, distributor.root, node.to_bytes()),
+        ErrorCode::InvalidProof
+    );
+
+    let amount = cumulative_amount
+        .checked_sub(claim_status.amount_claimed)
+        .ok_or(ErrorCode::InsufficientUnlockedTokens)?;
+    require!(amount > 0, ErrorCode::InsufficientUnlockedTokens);
+
+    claim_status.amount_claimed = cumulative_amount;
+
+    let seeds = [
+        b"MerkleDistributor".as_ref(),
+        &distributor.mint.to_bytes(),
+        &distributor.version.to_le_bytes(),
+        &[distributor.bump],
+    ];
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: from.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: distributor.to_account_info(),
+            },
+        )
+        .with_signer(&[&seeds[..]]),
+        amount,
+        mint.decimals,
+    )?;
+
+    let transfer_fee = get_transfer_fee(&mint.to_account_info(), amount)?;
+
+    distributor.total_amount_claimed = distributor
+        .total_amount_claimed
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        distributor.total_amount_claimed <= distributor.max_total_claim,
+        ErrorCode::ExceededMaxClaim
+    );
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Claimed {} ({} withheld as transfer fee), {} claimed in total",
+        amount,
+        transfer_fee,
+        cumulative_amount,
+    );
+    emit!(CumulativeClaimedEvent {
+        distributor: distributor.key(),
+        claimant: claim_status.claimant,
+        to: to.key(),
+        amount,
+        cumulative_amount,
+    });
+
+    Ok(())
+}
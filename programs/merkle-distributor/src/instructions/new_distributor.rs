@@ -1,16 +1,18 @@
-use anchor_lang::{account, context::Context, prelude::*, Accounts, Key, ToAccountInfo};
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, ToAccountInfo};
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-use crate::{error::ErrorCode, state::merkle_distributor::MerkleDistributor};
-
-const SECONDS_PER_HOUR: i64 = 3600; // 60 minutes * 60 seconds
-const HOURS_PER_DAY: i64 = 24;
-const SECONDS_PER_DAY: i64 = SECONDS_PER_HOUR * HOURS_PER_DAY; // 24 hours * 3600 seconds
+use crate::{
+    error::ErrorCode,
+    state::{
+        asset_kind::AssetKind, claim_fee::ClaimFee, distributor_event::NewDistributorEvent,
+        merkle_distributor::MerkleDistributor, vesting_schedule::VestingSchedule,
+    },
+};
 
-/// Accounts for [merkle_distributor::handle_new_distributor].
+/// Accounts for [merkle_distributor::new_distributor] and [merkle_distributor::new_distributor_v2].
 #[derive(Accounts)]
 #[instruction(version: u64)]
 pub struct NewDistributor<'info> {
@@ -29,20 +31,22 @@ pub struct NewDistributor<'info> {
     pub distributor: Account<'info, MerkleDistributor>,
 
     /// Clawback receiver token account
-    #[account(mut, token::mint = mint)]
-    pub clawback_receiver: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = mint, token::token_program = token_program)]
+    pub clawback_receiver: InterfaceAccount<'info, TokenAccount>,
 
     /// The mint to distribute.
-    pub mint: Account<'info, Mint>,
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
 
     /// Token vault
     #[account(
         init,
         associated_token::mint = mint,
         associated_token::authority=distributor,
+        associated_token::token_program = token_program,
         payer = admin,
     )]
-    pub token_vault: Account<'info, TokenAccount>,
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
 
     /// Admin wallet, responsible for creating the distributor and paying for the transaction.
     /// Also has the authority to set the clawback receiver and change itself.
@@ -55,18 +59,57 @@ pub struct NewDistributor<'info> {
     /// The [Associated Token] program.
     pub associated_token_program: Program<'info, AssociatedToken>,
 
-    /// The [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// The [Token] or [Token-2022] program that owns the mint.
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
-/// Creates a new [MerkleDistributor].
-/// After creating this [MerkleDistributor],
-/// the token_vault should be seeded with max_total_claim tokens.
-/// CHECK:
-///     1. The start timestamp is before the end timestamp
-///     2. The clawback timestamp is after the end timestamp
-///     3. The start, end, and clawback_start timestamps are all in the future
-///     4. The clawback start is at least one day after end timestamp
+/// Parameters of a new [MerkleDistributor], checked by [handle_new_distributor_v2].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct NewDistributorParams {
+    /// The 256-bit merkle root.
+    pub root: [u8; 32],
+    /// Maximum number of tokens that can ever be claimed from this [MerkleDistributor].
+    /// Multi-mint distributors set it per mint with
+    /// [add_distributor_mint](crate::instructions::handle_add_distributor_mint) instead.
+    pub max_total_claim: u64,
+    /// Maximum number of nodes in [MerkleDistributor].
+    pub max_num_nodes: u64,
+    /// Lockup time start (Unix Timestamp).
+    pub start_vesting_ts: i64,
+    /// Lockup time end (Unix Timestamp).
+    pub end_vesting_ts: i64,
+    /// Clawback start (Unix Timestamp).
+    pub clawback_start_ts: i64,
+    /// How locked tokens unlock between start_vesting_ts and end_vesting_ts.
+    pub vesting_schedule: VestingSchedule,
+    /// Whether anyone may claim on behalf of a claimant, into the claimant's token account.
+    pub allow_claim_on_behalf: bool,
+    /// The root can't be replaced once claims started after this time (Unix Timestamp).
+    pub root_lock_ts: i64,
+    /// Whether claims are tracked by leaf index in [ClaimBitmap](crate::state::claim_bitmap::ClaimBitmap) accounts.
+    pub bitmap_claims: bool,
+    /// Whether new claims fail until the vault holds every remaining claim.
+    pub require_full_funding: bool,
+    /// Whether leaves carry a cumulative amount that can be claimed again as it grows.
+    pub cumulative: bool,
+    /// New claims fail after this time (Unix Timestamp), if set.
+    pub claim_deadline_ts: Option<i64>,
+    /// Signer whose attestation of the claimant new claims require, if set.
+    pub attestation_signer: Option<Pubkey>,
+    /// Whether leaves are claimed from one of several mints.
+    pub multi_mint: bool,
+    /// Fee charged to open a claim.
+    pub claim_fee: ClaimFee,
+    /// Wallet receiving the claim fee.
+    pub fee_receiver: Pubkey,
+    /// Delay before the admin may recover a claim to a new claimant, if set.
+    pub claim_recovery_delay: Option<i64>,
+    /// Whether the admin may revoke the unvested locked tokens of claims.
+    pub revocable: bool,
+}
+
+/// Creates a new [MerkleDistributor] with a linear vesting schedule and none of the options of
+/// [NewDistributorParams], with the same checks as [handle_new_distributor_v2].
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::result_large_err)]
 pub fn handle_new_distributor(
@@ -79,7 +122,96 @@ pub fn handle_new_distributor(
     end_vesting_ts: i64,
     clawback_start_ts: i64,
 ) -> Result<()> {
-    let curr_ts = Clock::get()?.unix_timestamp;
+    handle_new_distributor_v2(
+        ctx,
+        version,
+        NewDistributorParams {
+            root,
+            max_total_claim,
+            max_num_nodes,
+            start_vesting_ts,
+            end_vesting_ts,
+            clawback_start_ts,
+            ..NewDistributorParams::default()
+        },
+    )
+}
+
+/// Creates a new [MerkleDistributor].
+/// After creating this [MerkleDistributor],
+/// the token_vault should be seeded with max_total_claim tokens, see
+/// [fund_distributor](crate::instructions::handle_fund_distributor).
+/// With require_full_funding, new claims fail until it is.
+/// CHECK:
+///     1. The start timestamp is before the end timestamp
+///     2. The clawback timestamp is after the end timestamp
+///     3. The start, end, and clawback_start timestamps are all in the future
+///     4. The clawback start is at least one day after end timestamp
+///     5. The vesting schedule fits between the start and end timestamps
+///     6. The distributor does not use both bitmap claims and cumulative claims
+///     7. The claim deadline, if any, is in the future and no later than the clawback start
+///     8. A multi-mint distributor does not use bitmap claims, cumulative claims or full funding
+///     9. A claim fee is at most the whole claim, has a fee receiver, and is only charged by
+///        distributors tracking claims with claim statuses of a single mint
+///     10. The claim recovery delay, if any, is positive and only set by distributors tracking
+///         claims with claim statuses of a single mint
+///     11. Only distributors tracking claims with claim statuses of a single mint are revocable
+///     12. No distributor with the same mint and version was closed, enforced by `init` since its
+///         [DistributorTombstone](crate::state::distributor_tombstone::DistributorTombstone) keeps
+///         the address in use
+#[allow(clippy::result_large_err)]
+pub fn handle_new_distributor_v2(
+    ctx: Context<NewDistributor>,
+    version: u64,
+    params: NewDistributorParams,
+) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    init_distributor(distributor, Clock::get()?.unix_timestamp, version, params)?;
+    distributor.bump = *ctx.bumps.get("distributor").unwrap();
+    distributor.mint = ctx.accounts.mint.key();
+    distributor.token_vault = ctx.accounts.token_vault.key();
+    distributor.clawback_receiver = ctx.accounts.clawback_receiver.key();
+    distributor.admin = ctx.accounts.admin.key();
+    distributor.asset_kind = AssetKind::Token;
+
+    log_new_distributor(distributor);
+
+    Ok(())
+}
+
+/// Validates the parameters of a new distributor and sets them, see the checks of
+/// [handle_new_distributor_v2]. Shared with
+/// [handle_new_distributor_native](crate::instructions::handle_new_distributor_native),
+/// callers set the bump, mint, vaults, clawback receiver, admin and asset kind.
+#[allow(clippy::result_large_err)]
+pub(crate) fn init_distributor(
+    distributor: &mut MerkleDistributor,
+    curr_ts: i64,
+    version: u64,
+    params: NewDistributorParams,
+) -> Result<()> {
+    let NewDistributorParams {
+        root,
+        max_total_claim,
+        max_num_nodes,
+        start_vesting_ts,
+        end_vesting_ts,
+        clawback_start_ts,
+        vesting_schedule,
+        allow_claim_on_behalf,
+        root_lock_ts,
+        bitmap_claims,
+        require_full_funding,
+        cumulative,
+        claim_deadline_ts,
+        attestation_signer,
+        multi_mint,
+        claim_fee,
+        fee_receiver,
+        claim_recovery_delay,
+        revocable,
+    } = params;
 
     require!(
         start_vesting_ts < end_vesting_ts,
@@ -91,44 +223,95 @@ pub fn handle_new_distributor(
         ErrorCode::TimestampsNotInFuture
     );
 
+    MerkleDistributor::validate_timestamps(
+        start_vesting_ts,
+        end_vesting_ts,
+        clawback_start_ts,
+        &vesting_schedule,
+    )?;
+
+    if let Some(claim_deadline_ts) = claim_deadline_ts {
+        require!(
+            claim_deadline_ts > curr_ts,
+            ErrorCode::TimestampsNotInFuture
+        );
+        require!(
+            claim_deadline_ts <= clawback_start_ts,
+            ErrorCode::ClaimDeadlineAfterClawback
+        );
+    }
+
+    // Claims are tracked either by leaf index or by cumulative amount, not both
+    require!(!(bitmap_claims && cumulative), ErrorCode::ClaimModeMismatch);
+
+    // The vault of a single mint can't be checked against the claims of every mint
     require!(
-        clawback_start_ts > end_vesting_ts,
-        ErrorCode::ClawbackDuringVesting
+        !multi_mint || !(bitmap_claims || cumulative || require_full_funding),
+        ErrorCode::ClaimModeMismatch
     );
 
-    // Ensure clawback_start_ts is at least one day after end_vesting_ts
+    // Fees are collected by the claims opening a claim status of a single mint
+    claim_fee.validate(&fee_receiver)?;
     require!(
-        clawback_start_ts
-            >= end_vesting_ts
-                .checked_add(SECONDS_PER_DAY)
-                .ok_or(ErrorCode::ArithmeticError)?,
-        ErrorCode::InsufficientClawbackDelay
+        claim_fee == ClaimFee::None || !(bitmap_claims || cumulative || multi_mint),
+        ErrorCode::ClaimModeMismatch
     );
 
-    let distributor = &mut ctx.accounts.distributor;
+    // Claims are recovered by moving their claim status
+    if let Some(claim_recovery_delay) = claim_recovery_delay {
+        require!(
+            claim_recovery_delay > 0,
+            ErrorCode::InvalidClaimRecoveryDelay
+        );
+        require!(
+            !(bitmap_claims || cumulative || multi_mint),
+            ErrorCode::ClaimModeMismatch
+        );
+    }
+
+    // Claims are revoked through their claim status
+    require!(
+        !revocable || !(bitmap_claims || cumulative || multi_mint),
+        ErrorCode::ClaimModeMismatch
+    );
 
-    distributor.bump = *ctx.bumps.get("distributor").unwrap();
     distributor.version = version;
     distributor.root = root;
-    distributor.mint = ctx.accounts.mint.key();
-    distributor.token_vault = ctx.accounts.token_vault.key();
     distributor.max_total_claim = max_total_claim;
     distributor.max_num_nodes = max_num_nodes;
     distributor.total_amount_claimed = 0;
     distributor.num_nodes_claimed = 0;
     distributor.start_ts = start_vesting_ts;
     distributor.end_ts = end_vesting_ts;
+    distributor.vesting_schedule = vesting_schedule;
     distributor.clawback_start_ts = clawback_start_ts;
-    distributor.clawback_receiver = ctx.accounts.clawback_receiver.key();
-    distributor.admin = ctx.accounts.admin.key();
     distributor.clawed_back = false;
+    distributor.allow_claim_on_behalf = allow_claim_on_behalf;
+    distributor.root_lock_ts = root_lock_ts;
+    distributor.bitmap_claims = bitmap_claims;
+    distributor.total_amount_funded = 0;
+    distributor.require_full_funding = require_full_funding;
+    distributor.cumulative = cumulative;
+    distributor.claim_deadline_ts = claim_deadline_ts;
+    distributor.attestation_signer = attestation_signer;
+    distributor.multi_mint = multi_mint;
+    distributor.num_mints = 0;
+    distributor.claim_fee = claim_fee;
+    distributor.fee_receiver = fee_receiver;
+    distributor.claim_recovery_delay = claim_recovery_delay;
+    distributor.revocable = revocable;
 
+    Ok(())
+}
+
+/// Logs and emits the parameters of a new distributor.
+pub(crate) fn log_new_distributor(distributor: &Account<MerkleDistributor>) {
     // Note: might get truncated, do not rely on
     msg! {
         "New distributor created with version = {}, mint={}, vault={} max_total_claim={}, max_nodes: {}, start_ts: {}, end_ts: {}, clawback_start: {}, clawback_receiver: {}",
             distributor.version,
             distributor.mint,
-            ctx.accounts.token_vault.key(),
+            distributor.token_vault,
             distributor.max_total_claim,
             distributor.max_num_nodes,
             distributor.start_ts,
@@ -136,6 +319,32 @@ pub fn handle_new_distributor(
             distributor.clawback_start_ts,
             distributor.clawback_receiver
     };
-
-    Ok(())
+    emit!(NewDistributorEvent {
+        distributor: distributor.key(),
+        version: distributor.version,
+        root: distributor.root,
+        mint: distributor.mint,
+        token_vault: distributor.token_vault,
+        max_total_claim: distributor.max_total_claim,
+        max_num_nodes: distributor.max_num_nodes,
+        start_ts: distributor.start_ts,
+        end_ts: distributor.end_ts,
+        vesting_schedule: distributor.vesting_schedule,
+        clawback_start_ts: distributor.clawback_start_ts,
+        clawback_receiver: distributor.clawback_receiver,
+        admin: distributor.admin,
+        allow_claim_on_behalf: distributor.allow_claim_on_behalf,
+        root_lock_ts: distributor.root_lock_ts,
+        bitmap_claims: distributor.bitmap_claims,
+        require_full_funding: distributor.require_full_funding,
+        cumulative: distributor.cumulative,
+        claim_deadline_ts: distributor.claim_deadline_ts,
+        attestation_signer: distributor.attestation_signer,
+        asset_kind: distributor.asset_kind,
+        multi_mint: distributor.multi_mint,
+        claim_fee: distributor.claim_fee,
+        fee_receiver: distributor.fee_receiver,
+        claim_recovery_delay: distributor.claim_recovery_delay,
+        revocable: distributor.revocable,
+    });
 }
@@ -0,0 +1,96 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key};
+
+use crate::{
+    error::ErrorCode,
+    instructions::new_distributor::{init_distributor, log_new_distributor, NewDistributorParams},
+    state::{
+        asset_kind::AssetKind, claim_fee::ClaimFee, merkle_distributor::MerkleDistributor,
+        sol_vault::SolVault,
+    },
+};
+
+/// Accounts for [merkle_distributor::handle_new_distributor_native].
+#[derive(Accounts)]
+#[instruction(version: u64)]
+pub struct NewDistributorNative<'info> {
+    /// [MerkleDistributor]. Native SOL distributors use [Pubkey::default] as their mint.
+    #[account(
+        init,
+        seeds = [
+            b"MerkleDistributor".as_ref(),
+            Pubkey::default().to_bytes().as_ref(),
+            version.to_le_bytes().as_ref()
+        ],
+        bump,
+        space = MerkleDistributor::LEN,
+        payer = admin
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// [SolVault] holding the lamports to distribute.
+    #[account(
+        init,
+        seeds = [
+            b"SolVault".as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = SolVault::LEN,
+        payer = admin
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+
+    /// Clawback receiver wallet
+    pub clawback_receiver: SystemAccount<'info>,
+
+    /// Admin wallet, responsible for creating the distributor and paying for the transaction.
+    /// Also has the authority to set the clawback receiver and change itself.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a new [MerkleDistributor] of native SOL, held in a [SolVault] instead of a token
+/// vault. Claims go through [new_claim_native](crate::instructions::handle_new_claim_native),
+/// [claim_locked_native](crate::instructions::handle_claim_locked_native) and
+/// [clawback_native](crate::instructions::handle_clawback_native), with the same vesting and
+/// clawback semantics as token distributors.
+/// Native SOL distributors track claims with [ClaimStatus](crate::state::claim_status::ClaimStatus)
+/// accounts, and can't be claimed on behalf of claimants.
+/// CHECK:
+///     1. The same checks as [handle_new_distributor_v2](crate::instructions::handle_new_distributor_v2)
+///     2. The parameters do not enable claims on behalf, bitmap, cumulative or multi-mint claims, a
+///        claim fee, claim recovery or revocation
+#[allow(clippy::result_large_err)]
+pub fn handle_new_distributor_native(
+    ctx: Context<NewDistributorNative>,
+    version: u64,
+    params: NewDistributorParams,
+) -> Result<()> {
+    require!(
+        !(params.allow_claim_on_behalf
+            || params.bitmap_claims
+            || params.cumulative
+            || params.multi_mint
+            || params.claim_fee != ClaimFee::None
+            || params.claim_recovery_delay.is_some()
+            || params.revocable),
+        ErrorCode::AssetKindMismatch
+    );
+
+    let distributor = &mut ctx.accounts.distributor;
+
+    init_distributor(distributor, Clock::get()?.unix_timestamp, version, params)?;
+    distributor.bump = *ctx.bumps.get("distributor").unwrap();
+    distributor.mint = Pubkey::default();
+    distributor.token_vault = ctx.accounts.sol_vault.key();
+    distributor.clawback_receiver = ctx.accounts.clawback_receiver.key();
+    distributor.admin = ctx.accounts.admin.key();
+    distributor.asset_kind = AssetKind::NativeSol;
+
+    log_new_distributor(distributor);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{distributor_event::PausedEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::pause] accounts.
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+}
+
+/// Pauses new claims and withdrawals of locked tokens. Clawback is not affected.
+#[allow(clippy::result_large_err)]
+pub fn handle_pause(ctx: Context<Pause>) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    distributor.paused = true;
+
+    // Note: might get truncated, do not rely on
+    msg!("paused distributor");
+    emit!(PausedEvent {
+        distributor: distributor.key(),
+        paused: distributor.paused,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use anchor_lang::{context::Context, prelude::*, system_program::System, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        admin_event::ClaimRecoveryProposedEvent, claim_recovery::ClaimRecovery,
+        claim_status::ClaimStatus, merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::propose_claim_recovery] accounts.
+#[derive(Accounts)]
+pub struct ProposeClaimRecovery<'info> {
+    /// The [MerkleDistributor].
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim Status PDA of the claimant whose claim is recovered.
+    #[account(
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// Claim Recovery PDA
+    #[account(
+        init,
+        seeds = [
+            b"ClaimRecovery".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = ClaimRecovery::LEN,
+        payer = admin
+    )]
+    pub claim_recovery: Account<'info, ClaimRecovery>,
+
+    /// CHECK: Claimant whose claim is recovered, only used to derive the PDAs
+    pub claimant: UncheckedAccount<'info>,
+
+    /// Admin signer, pays the rent for the claim recovery.
+    #[account(mut, address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Proposes to move the [ClaimStatus] of a claimant who lost their key to `new_claimant`, see
+/// [recover_claim](crate::instructions::handle_recover_claim). Until the distributor's claim
+/// recovery delay has passed, the claimant can move the claim themselves with
+/// [transfer_claim](crate::instructions::handle_transfer_claim) to defeat an unwanted recovery.
+/// CHECK:
+///     1. The admin signed the transaction
+///     2. The distributor opted into claim recovery at creation
+///     3. The distributor has not been clawed back
+///     4. The new claimant is not the claimant
+#[allow(clippy::result_large_err)]
+pub fn handle_propose_claim_recovery(
+    ctx: Context<ProposeClaimRecovery>,
+    new_claimant: Pubkey,
+) -> Result<()> {
+    let distributor = &ctx.accounts.distributor;
+
+    let claim_recovery_delay = distributor
+        .claim_recovery_delay
+        .ok_or(ErrorCode::ClaimRecoveryDisabled)?;
+    require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+    require!(
+        new_claimant != ctx.accounts.claimant.key(),
+        ErrorCode::NewClaimantMismatch
+    );
+
+    let recoverable_ts = Clock::get()?
+        .unix_timestamp
+        .checked_add(claim_recovery_delay)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    let claim_recovery = &mut ctx.accounts.claim_recovery;
+    claim_recovery.distributor = distributor.key();
+    claim_recovery.claimant = ctx.accounts.claimant.key();
+    claim_recovery.new_claimant = new_claimant;
+    claim_recovery.recoverable_ts = recoverable_ts;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Proposed recovery of the claim of {} to {}, recoverable from {}",
+        claim_recovery.claimant,
+        new_claimant,
+        recoverable_ts,
+    );
+    emit!(ClaimRecoveryProposedEvent {
+        distributor: distributor.key(),
+        claimant: claim_recovery.claimant,
+        new_claimant,
+        recoverable_ts,
+    });
+
+    Ok(())
+}
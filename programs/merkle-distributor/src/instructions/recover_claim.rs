@@ -0,0 +1,115 @@
+use anchor_lang::{context::Context, prelude::*, system_program::System, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    instructions::transfer_claim::move_claim,
+    state::{
+        claim_recovery::ClaimRecovery, claim_status::ClaimStatus, closed_claims::ClosedClaims,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::recover_claim] accounts.
+#[derive(Accounts)]
+pub struct RecoverClaim<'info> {
+    /// The [MerkleDistributor].
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim Recovery PDA, closed to the admin.
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            b"ClaimRecovery".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_recovery: Account<'info, ClaimRecovery>,
+
+    /// Claim Status PDA of the claimant, closed to the claimant once moved.
+    #[account(
+        mut,
+        close = claimant,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// CHECK: [ClosedClaims] chunk recording the claimant, created by the first claim it records.
+    #[account(
+        mut,
+        seeds = [
+            b"ClosedClaims".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            &[ClosedClaims::chunk_index_of(&claimant.key())]
+        ],
+        bump,
+    )]
+    pub closed_claims: UncheckedAccount<'info>,
+
+    /// Claim Status PDA of the new claimant.
+    #[account(
+        init,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            new_claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = ClaimStatus::LEN,
+        payer = admin
+    )]
+    pub new_claim_status: Account<'info, ClaimStatus>,
+
+    /// CHECK: Claimant whose claim is recovered, used to derive the PDAs. Paid the rent for its
+    /// claim status, so receives it back.
+    #[account(mut)]
+    pub claimant: UncheckedAccount<'info>,
+
+    /// The new claimant of the claim recovery, signs so a claim can't be moved onto the claim status
+    /// PDA of another claimant of the distributor.
+    #[account(address = claim_recovery.new_claimant @ ErrorCode::NewClaimantMismatch)]
+    pub new_claimant: Signer<'info>,
+
+    /// Admin signer, pays the rent for the new claim status and receives the rent of the claim
+    /// recovery.
+    #[account(mut, address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Executes a claim recovery proposed with
+/// [propose_claim_recovery](crate::instructions::handle_propose_claim_recovery), moving the
+/// [ClaimStatus] of the claimant and its remaining vesting to the new claimant like
+/// [transfer_claim](crate::instructions::handle_transfer_claim).
+/// CHECK:
+///     1. The admin and the new claimant signed the transaction
+///     2. The claim recovery delay has passed since the recovery was proposed
+///     3. The distributor has not been clawed back
+///     4. The new claimant does not have a claim status
+#[allow(clippy::result_large_err)]
+pub fn handle_recover_claim(ctx: Context<RecoverClaim>) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.claim_recovery.recoverable_ts,
+        ErrorCode::ClaimRecoveryTimelocked
+    );
+
+    move_claim(
+        &ctx.accounts.distributor,
+        &ctx.accounts.claim_status,
+        &ctx.accounts.closed_claims,
+        *ctx.bumps.get("closed_claims").unwrap(),
+        &mut ctx.accounts.new_claim_status,
+        ctx.accounts.new_claimant.key(),
+        &ctx.accounts.system_program,
+        true,
+    )
+}
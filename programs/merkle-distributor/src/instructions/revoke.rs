@@ -0,0 +1,330 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+use anchor_spl::{
+    token_interface,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        asset_kind::AssetKind, claim_status::ClaimStatus, claimed_event::ClaimRevokedEvent,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::revoke] accounts.
+#[derive(Accounts)]
+pub struct Revoke<'info> {
+    /// The token [MerkleDistributor].
+    #[account(
+        mut,
+        constraint = distributor.asset_kind == AssetKind::Token @ ErrorCode::AssetKindMismatch,
+        constraint = distributor.revocable @ ErrorCode::RevokeDisabled,
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim Status PDA
+    #[account(
+        mut,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// The Clawback token account, receives the revoked tokens.
+    #[account(mut, address = distributor.clawback_receiver)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Whose claim is revoked, only used to derive the claim status PDA
+    pub claimant: UncheckedAccount<'info>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or [Token-2022] program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Revokes the locked tokens of an opened claim that have not vested yet, for instance when a
+/// grantee leaves. The locked tokens unlocked so far stay withdrawable with
+/// [claim_locked](crate::instructions::handle_claim_locked), the rest is removed from
+/// max_total_claim and sent to the clawback receiver.
+/// See [revoke_unclaimed](crate::instructions::handle_revoke_unclaimed) for leaves that have not
+/// been claimed yet. Only distributors created revocable allow it, and claims of native SOL
+/// distributors can't be revoked.
+/// CHECK:
+///     1. The admin signed the transaction
+///     2. The distributor is a revocable token distributor
+///     3. The distributor has not been clawed back
+///     4. The claim has locked tokens that have not vested yet
+#[allow(clippy::result_large_err)]
+pub fn handle_revoke(ctx: Context<Revoke>) -> Result<()> {
+    let vault_amount = ctx.accounts.from.amount;
+    process_revoke(
+        &mut ctx.accounts.distributor,
+        &mut ctx.accounts.claim_status,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        vault_amount,
+    )
+}
+
+/// Freezes the vesting of claim_status, removes the revoked tokens from max_total_claim and
+/// transfers them out of a vault holding `vault_amount` to the clawback receiver. Only the vault's
+/// surplus over the remaining claims is transferred, revoked tokens the vault was never funded with
+/// are only removed from max_total_claim.
+/// Shared by [handle_revoke] and [handle_revoke_unclaimed](crate::instructions::handle_revoke_unclaimed).
+#[allow(clippy::result_large_err)]
+pub(crate) fn process_revoke<'info>(
+    distributor: &mut Account<'info, MerkleDistributor>,
+    claim_status: &mut Account<'info, ClaimStatus>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    vault_amount: u64,
+) -> Result<()> {
+    require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+
+    let curr_ts = Clock::get()?.unix_timestamp;
+    let (start_ts, end_ts) = claim_status.lockup(distributor.start_ts, distributor.end_ts);
+    let revoked_amount =
+        claim_status.revoke(curr_ts, start_ts, end_ts, &distributor.vesting_schedule)?;
+    require!(revoked_amount > 0, ErrorCode::NothingToRevoke);
+
+    distributor.max_total_claim = distributor
+        .max_total_claim
+        .checked_sub(revoked_amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // Never dip into the tokens owed to the remaining claims of an underfunded vault
+    let amount = revoked_amount.min(distributor.surplus(vault_amount));
+    if amount > 0 {
+        let seeds = [
+            b"MerkleDistributor".as_ref(),
+            &distributor.mint.to_bytes(),
+            &distributor.version.to_le_bytes(),
+            &[distributor.bump],
+        ];
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: from.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: distributor.to_account_info(),
+                },
+            )
+            .with_signer(&[&seeds[..]]),
+            amount,
+            mint.decimals,
+        )?;
+    }
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Revoked {} unvested tokens of {}, {} sent to the clawback receiver, {} locked tokens vested",
+        revoked_amount,
+        claim_status.claimant,
+        amount,
+        claim_status.locked_amount,
+    );
+    emit!(ClaimRevokedEvent {
+        distributor: distributor.key(),
+        claimant: claim_status.claimant,
+        to: to.key(),
+        revoked_amount,
+        amount,
+        locked_amount: claim_status.locked_amount,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_spl::token;
+
+    use super::*;
+    use crate::{
+        state::vesting_schedule::VestingSchedule,
+        test_utils::{process_instruction, set_unix_timestamp, transferred_amounts, TestAccount},
+    };
+
+    /// A revocable distributor vesting from 1_000 to 2_000, where 1_000 tokens can be claimed
+    fn revocable_distributor() -> MerkleDistributor {
+        MerkleDistributor {
+            start_ts: 1_000,
+            end_ts: 2_000,
+            max_total_claim: 1_000,
+            revocable: true,
+            ..MerkleDistributor::default()
+        }
+    }
+
+    /// A claim of 100 locked tokens vesting against the distributor's lockup
+    fn locked_claim() -> ClaimStatus {
+        ClaimStatus {
+            locked_amount: 100,
+            ..ClaimStatus::default()
+        }
+    }
+
+    /// Revoke accounts for the claim of a new claimant, with a vault holding `vault_amount`
+    fn revoke_accounts(
+        distributor_state: MerkleDistributor,
+        claim_status_state: ClaimStatus,
+        vault_amount: u64,
+    ) -> (crate::accounts::Revoke, [TestAccount; 8]) {
+        let (distributor, claimant, admin, from, to) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let claim_status = Pubkey::find_program_address(
+            &[
+                b"ClaimStatus".as_ref(),
+                claimant.as_ref(),
+                distributor.as_ref(),
+            ],
+            &crate::ID,
+        )
+        .0;
+        let distributor_state = MerkleDistributor {
+            admin,
+            clawback_receiver: to,
+            token_vault: from,
+            ..distributor_state
+        };
+        let claim_status_state = ClaimStatus {
+            claimant,
+            ..claim_status_state
+        };
+
+        (
+            crate::accounts::Revoke {
+                distributor,
+                claim_status,
+                from,
+                to,
+                claimant,
+                admin,
+                mint: Pubkey::default(),
+                token_program: token::ID,
+            },
+            [
+                TestAccount::program(distributor, &distributor_state, MerkleDistributor::LEN),
+                TestAccount::program(claim_status, &claim_status_state, ClaimStatus::LEN),
+                TestAccount::token_account(from, Pubkey::default(), distributor, vault_amount),
+                TestAccount::token_account(to, Pubkey::default(), admin, 0),
+                TestAccount::wallet(claimant),
+                TestAccount::wallet(admin),
+                TestAccount::mint(Pubkey::default()),
+                TestAccount::executable(token::ID),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_revoke_rejects_native_distributor() {
+        let (ix_accounts, mut accounts) = revoke_accounts(
+            MerkleDistributor {
+                asset_kind: AssetKind::NativeSol,
+                revocable: true,
+                ..MerkleDistributor::default()
+            },
+            ClaimStatus::default(),
+            100,
+        );
+        assert_eq!(
+            process_instruction(ix_accounts, crate::instruction::Revoke {}, &mut accounts),
+            Err(Error::from(ErrorCode::AssetKindMismatch).into())
+        );
+    }
+
+    #[test]
+    fn test_revoke_rejects_distributor_not_revocable() {
+        let (ix_accounts, mut accounts) =
+            revoke_accounts(MerkleDistributor::default(), ClaimStatus::default(), 100);
+        assert_eq!(
+            process_instruction(ix_accounts, crate::instruction::Revoke {}, &mut accounts),
+            Err(Error::from(ErrorCode::RevokeDisabled).into())
+        );
+    }
+
+    #[test]
+    fn test_revoke_freezes_vesting() {
+        set_unix_timestamp(1_250);
+        let (ix_accounts, mut accounts) =
+            revoke_accounts(revocable_distributor(), locked_claim(), 1_000);
+        process_instruction(ix_accounts, crate::instruction::Revoke {}, &mut accounts).unwrap();
+
+        // The 25 tokens vested so far stay with the claim, fully unlocked from now on
+        let claim_status = ClaimStatus::try_deserialize(&mut accounts[1].data.as_slice()).unwrap();
+        assert_eq!(claim_status.locked_amount, 25);
+        let (start_ts, end_ts) = claim_status.lockup(1_000, 2_000);
+        for curr_ts in [1_250, 2_000] {
+            assert_eq!(
+                claim_status
+                    .amount_withdrawable(curr_ts, start_ts, end_ts, &VestingSchedule::Linear)
+                    .unwrap(),
+                25
+            );
+        }
+
+        // The 75 unvested tokens are no longer claimable and go to the clawback receiver
+        let distributor =
+            MerkleDistributor::try_deserialize(&mut accounts[0].data.as_slice()).unwrap();
+        assert_eq!(distributor.max_total_claim, 925);
+        assert_eq!(transferred_amounts(), vec![75]);
+
+        // Nothing is left to revoke
+        let (ix_accounts, mut accounts) =
+            revoke_accounts(revocable_distributor(), claim_status, 925);
+        assert_eq!(
+            process_instruction(ix_accounts, crate::instruction::Revoke {}, &mut accounts),
+            Err(Error::from(ErrorCode::NothingToRevoke).into())
+        );
+    }
+
+    #[test]
+    fn test_revoke_caps_transfer_at_vault_surplus() {
+        set_unix_timestamp(1_250);
+        // Once the 75 revoked tokens are removed, the vault owes 925 tokens to the remaining claims
+        for (vault_amount, transferred) in [(950, vec![25]), (900, vec![])] {
+            let (ix_accounts, mut accounts) =
+                revoke_accounts(revocable_distributor(), locked_claim(), vault_amount);
+            process_instruction(ix_accounts, crate::instruction::Revoke {}, &mut accounts).unwrap();
+
+            let distributor =
+                MerkleDistributor::try_deserialize(&mut accounts[0].data.as_slice()).unwrap();
+            assert_eq!(distributor.max_total_claim, 925);
+            assert_eq!(transferred_amounts(), transferred);
+        }
+    }
+}
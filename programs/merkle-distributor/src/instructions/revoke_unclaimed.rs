@@ -0,0 +1,430 @@
+use anchor_lang::{context::Context, prelude::*, system_program::System, Accounts, Key, Result};
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    error::ErrorCode,
+    instructions::{
+        close_claim_status::is_claim_closed, new_claim::verify_leaf, revoke::process_revoke,
+    },
+    state::{
+        asset_kind::AssetKind, claim_status::ClaimStatus, claimed_event::NewClaimEvent,
+        closed_claims::ClosedClaims, merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::revoke_unclaimed] accounts.
+#[derive(Accounts)]
+pub struct RevokeUnclaimed<'info> {
+    /// The token [MerkleDistributor].
+    #[account(
+        mut,
+        constraint = distributor.asset_kind == AssetKind::Token @ ErrorCode::AssetKindMismatch,
+        constraint = distributor.revocable @ ErrorCode::RevokeDisabled,
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim status PDA, opened on behalf of the claimant
+    #[account(
+        init,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = ClaimStatus::LEN,
+        payer = admin
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// CHECK: [ClosedClaims] chunk that would record the claimant, which may not exist yet.
+    #[account(
+        seeds = [
+            b"ClosedClaims".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            &[ClosedClaims::chunk_index_of(&claimant.key())]
+        ],
+        bump,
+    )]
+    pub closed_claims: UncheckedAccount<'info>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// The Clawback token account, receives the revoked tokens.
+    #[account(mut, address = distributor.clawback_receiver)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// The claimant's ATA, receives the unlocked tokens of the leaf.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = claimant.key(),
+        token::token_program = token_program,
+        address = get_associated_token_address_with_program_id(
+            &claimant.key(),
+            &distributor.mint,
+            &token_program.key(),
+        ) @ ErrorCode::OwnerMismatch
+    )]
+    pub claimant_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Whose claim is revoked, only used to derive the claim status PDA and ATA
+    pub claimant: UncheckedAccount<'info>,
+
+    /// Admin signer, pays the rent for the claim status.
+    #[account(mut, address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or [Token-2022] program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Revokes the unvested locked tokens of a leaf that has not been claimed yet.
+/// Opens the claim on behalf of the claimant, sending the unlocked tokens of the leaf to the
+/// claimant's ATA without charging the claim fee, then revokes it like
+/// [revoke](crate::instructions::handle_revoke). The locked tokens unlocked so far stay
+/// withdrawable by the claimant. Only distributors created revocable allow it.
+/// CHECK:
+///     1. The admin signed the transaction
+///     2. The distributor has not been clawed back and the claim deadline has not passed
+///     3. The distributor is a revocable token distributor tracking claims with [ClaimStatus] accounts, is
+///        not cumulative and is not multi-mint
+///     4. The claimant has not closed a previous claim
+///     5. Num nodes claimed is less than max_num_nodes
+///     6. The merkle proof is valid, and so is a lockup committed in the leaf
+///     7. The leaf has locked tokens that have not vested yet
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::result_large_err)]
+pub fn handle_revoke_unclaimed(
+    ctx: Context<RevokeUnclaimed>,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    proof: Vec<[u8; 32]>,
+    start_ts: Option<i64>,
+    end_ts: Option<i64>,
+) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+    let claim_status = &mut ctx.accounts.claim_status;
+    let claimant = ctx.accounts.claimant.key();
+    let curr_ts = Clock::get()?.unix_timestamp;
+
+    require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+    require!(
+        !distributor.claim_deadline_passed(curr_ts),
+        ErrorCode::ClaimDeadlinePassed
+    );
+    require!(
+        !distributor.bitmap_claims && !distributor.cumulative && !distributor.multi_mint,
+        ErrorCode::ClaimModeMismatch
+    );
+
+    // The ClaimStatus PDA can be re-created after it is closed, so check the closed claims as well
+    require!(
+        !is_claim_closed(&ctx.accounts.closed_claims, &claimant)?,
+        ErrorCode::ClaimAlreadyClosed
+    );
+
+    distributor.num_nodes_claimed = distributor
+        .num_nodes_claimed
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        distributor.num_nodes_claimed <= distributor.max_num_nodes,
+        ErrorCode::MaxNodesExceeded
+    );
+
+    let lockup = verify_leaf(
+        distributor,
+        claimant,
+        None,
+        amount_unlocked,
+        amount_locked,
+        proof,
+        start_ts,
+        end_ts,
+    )?;
+
+    claim_status.claimant = claimant;
+    claim_status.locked_amount = amount_locked;
+    claim_status.unlocked_amount = amount_unlocked;
+    claim_status.locked_amount_withdrawn = 0;
+    (claim_status.start_ts, claim_status.end_ts) = lockup;
+
+    distributor.total_amount_claimed = distributor
+        .total_amount_claimed
+        .checked_add(amount_unlocked)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        distributor.total_amount_claimed <= distributor.max_total_claim,
+        ErrorCode::ExceededMaxClaim
+    );
+
+    let seeds = [
+        b"MerkleDistributor".as_ref(),
+        &distributor.mint.to_bytes(),
+        &distributor.version.to_le_bytes(),
+        &[distributor.bump],
+    ];
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.claimant_token_account.to_account_info(),
+                authority: distributor.to_account_info(),
+            },
+        )
+        .with_signer(&[&seeds[..]]),
+        amount_unlocked,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Opened claim of {} with {} unlocked before revoking it",
+        claimant,
+        amount_unlocked,
+    );
+    emit!(NewClaimEvent {
+        distributor: distributor.key(),
+        claimant,
+        timestamp: curr_ts,
+        to: ctx.accounts.claimant_token_account.key(),
+        amount_unlocked,
+        amount_locked,
+        start_ts,
+        end_ts,
+        index: None,
+        mint_index: None,
+    });
+
+    // The vault account is not reloaded after the transfer above
+    let vault_amount = ctx
+        .accounts
+        .from
+        .amount
+        .checked_sub(amount_unlocked)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    process_revoke(
+        distributor,
+        claim_status,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        vault_amount,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::solana_program::hash::hashv;
+    use anchor_spl::{associated_token::get_associated_token_address, token};
+
+    use super::*;
+    use crate::{
+        instructions::new_claim::LEAF_PREFIX,
+        test_utils::{process_instruction, set_unix_timestamp, transferred_amounts, TestAccount},
+    };
+
+    /// A revocable distributor vesting from 1_000 to 2_000, whose tree only holds the leaf of
+    /// `claimant` with 10 unlocked and 90 locked tokens
+    fn revocable_distributor(claimant: Pubkey) -> MerkleDistributor {
+        let node = hashv(&[
+            &claimant.to_bytes(),
+            &10u64.to_le_bytes(),
+            &90u64.to_le_bytes(),
+        ]);
+        MerkleDistributor {
+            root: hashv(&[LEAF_PREFIX, &node.to_bytes()]).to_bytes(),
+            start_ts: 1_000,
+            end_ts: 2_000,
+            max_total_claim: 100,
+            max_num_nodes: 1,
+            revocable: true,
+            ..MerkleDistributor::default()
+        }
+    }
+
+    /// RevokeUnclaimed accounts for the leaf of `claimant`, with a vault holding `vault_amount`
+    fn revoke_unclaimed_accounts(
+        distributor_state: MerkleDistributor,
+        claimant: Pubkey,
+        vault_amount: u64,
+    ) -> (crate::accounts::RevokeUnclaimed, [TestAccount; 11]) {
+        let (distributor, admin, from, to) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let claim_status = Pubkey::find_program_address(
+            &[
+                b"ClaimStatus".as_ref(),
+                claimant.as_ref(),
+                distributor.as_ref(),
+            ],
+            &crate::ID,
+        )
+        .0;
+        let closed_claims = Pubkey::find_program_address(
+            &[
+                b"ClosedClaims".as_ref(),
+                distributor.as_ref(),
+                &[ClosedClaims::chunk_index_of(&claimant)],
+            ],
+            &crate::ID,
+        )
+        .0;
+        let claimant_token_account = get_associated_token_address(&claimant, &Pubkey::default());
+        let distributor_state = MerkleDistributor {
+            admin,
+            clawback_receiver: to,
+            token_vault: from,
+            ..distributor_state
+        };
+
+        (
+            crate::accounts::RevokeUnclaimed {
+                distributor,
+                claim_status,
+                closed_claims,
+                from,
+                to,
+                claimant_token_account,
+                claimant,
+                admin,
+                mint: Pubkey::default(),
+                token_program: token::ID,
+                system_program: System::id(),
+            },
+            [
+                TestAccount::program(distributor, &distributor_state, MerkleDistributor::LEN),
+                // CPIs are no-ops in tests, pass the claim status as if init had created it
+                TestAccount {
+                    owner: crate::ID,
+                    data: vec![0; ClaimStatus::LEN],
+                    ..TestAccount::wallet(claim_status)
+                },
+                TestAccount::wallet(closed_claims),
+                TestAccount::token_account(from, Pubkey::default(), distributor, vault_amount),
+                TestAccount::token_account(to, Pubkey::default(), admin, 0),
+                TestAccount::token_account(claimant_token_account, Pubkey::default(), claimant, 0),
+                TestAccount::wallet(claimant),
+                TestAccount::wallet(admin),
+                TestAccount::mint(Pubkey::default()),
+                TestAccount::executable(token::ID),
+                TestAccount::executable(System::id()),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_revoke_unclaimed_rejects_native_distributor() {
+        let (ix_accounts, mut accounts) = revoke_unclaimed_accounts(
+            MerkleDistributor {
+                asset_kind: AssetKind::NativeSol,
+                revocable: true,
+                ..MerkleDistributor::default()
+            },
+            Pubkey::new_unique(),
+            100,
+        );
+        assert_eq!(
+            process_instruction(
+                ix_accounts,
+                crate::instruction::RevokeUnclaimed {
+                    amount_unlocked: 0,
+                    amount_locked: 100,
+                    proof: vec![],
+                    start_ts: None,
+                    end_ts: None,
+                },
+                &mut accounts
+            ),
+            Err(Error::from(ErrorCode::AssetKindMismatch).into())
+        );
+    }
+
+    #[test]
+    fn test_revoke_unclaimed_rejects_distributor_not_revocable() {
+        let (ix_accounts, mut accounts) =
+            revoke_unclaimed_accounts(MerkleDistributor::default(), Pubkey::new_unique(), 100);
+        assert_eq!(
+            process_instruction(
+                ix_accounts,
+                crate::instruction::RevokeUnclaimed {
+                    amount_unlocked: 0,
+                    amount_locked: 100,
+                    proof: vec![],
+                    start_ts: None,
+                    end_ts: None,
+                },
+                &mut accounts
+            ),
+            Err(Error::from(ErrorCode::RevokeDisabled).into())
+        );
+    }
+
+    #[test]
+    fn test_revoke_unclaimed() {
+        set_unix_timestamp(1_500);
+        // Once the 10 unlocked tokens are sent to the claimant and the 45 unvested tokens are
+        // revoked, the vault owes 45 tokens to the claim
+        for (vault_amount, transferred) in [(100, vec![10, 45]), (80, vec![10, 25])] {
+            let claimant = Pubkey::new_unique();
+            let (ix_accounts, mut accounts) =
+                revoke_unclaimed_accounts(revocable_distributor(claimant), claimant, vault_amount);
+            process_instruction(
+                ix_accounts,
+                crate::instruction::RevokeUnclaimed {
+                    amount_unlocked: 10,
+                    amount_locked: 90,
+                    proof: vec![],
+                    start_ts: None,
+                    end_ts: None,
+                },
+                &mut accounts,
+            )
+            .unwrap();
+
+            let claim_status =
+                ClaimStatus::try_deserialize(&mut accounts[1].data.as_slice()).unwrap();
+            assert_eq!(claim_status.claimant, claimant);
+            assert_eq!(claim_status.unlocked_amount, 10);
+            assert_eq!(claim_status.locked_amount, 45);
+            assert_eq!(claim_status.end_ts, 1_500);
+
+            let distributor =
+                MerkleDistributor::try_deserialize(&mut accounts[0].data.as_slice()).unwrap();
+            assert_eq!(distributor.num_nodes_claimed, 1);
+            assert_eq!(distributor.total_amount_claimed, 10);
+            assert_eq!(distributor.max_total_claim, 55);
+            assert_eq!(transferred_amounts(), transferred);
+        }
+    }
+}
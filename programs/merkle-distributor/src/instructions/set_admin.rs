@@ -5,9 +5,12 @@ use anchor_lang::{
     Accounts, Result,
 };
 
-use crate::{error::ErrorCode, state::merkle_distributor::MerkleDistributor};
+use crate::{
+    error::ErrorCode,
+    state::{admin_event::PendingAdminSetEvent, merkle_distributor::MerkleDistributor},
+};
 
-/// [merkle_distributor::set_clawback_receiver] accounts.
+/// [merkle_distributor::set_admin] accounts.
 #[derive(Accounts)]
 pub struct SetAdmin<'info> {
     /// The [MerkleDistributor].
@@ -24,7 +27,9 @@ pub struct SetAdmin<'info> {
     pub new_admin: AccountInfo<'info>,
 }
 
-/// Sets new admin account
+/// Proposes a new admin account, which becomes admin once it signs
+/// [accept_admin](crate::instructions::handle_accept_admin).
+/// Replaces any pending proposal.
 /// CHECK:
 ///     1. The new admin is not the same as the old one
 #[allow(clippy::result_large_err)]
@@ -36,10 +41,14 @@ pub fn handle_set_admin(ctx: Context<SetAdmin>) -> Result<()> {
         ErrorCode::SameAdmin
     );
 
-    distributor.admin = ctx.accounts.new_admin.key();
+    distributor.pending_admin = ctx.accounts.new_admin.key();
 
     // Note: might get truncated, do not rely on
-    msg!("set new admin to {}", ctx.accounts.new_admin.key());
+    msg!("proposed new admin {}", ctx.accounts.new_admin.key());
+    emit!(PendingAdminSetEvent {
+        distributor: distributor.key(),
+        pending_admin: distributor.pending_admin,
+    });
 
     Ok(())
 }
@@ -4,27 +4,40 @@ use anchor_lang::{
     prelude::*,
     Accounts, Result, ToAccountInfo,
 };
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::TokenAccount;
 
-use crate::{error::ErrorCode, state::merkle_distributor::MerkleDistributor};
+use crate::{
+    error::ErrorCode,
+    state::{
+        admin_event::PendingClawbackReceiverSetEvent, asset_kind::AssetKind,
+        merkle_distributor::MerkleDistributor,
+    },
+};
 
 /// [merkle_distributor::set_clawback_receiver] accounts.
 #[derive(Accounts)]
 pub struct SetClawbackReceiver<'info> {
-    /// The [MerkleDistributor].
-    #[account(mut)]
+    /// The token [MerkleDistributor], see
+    /// [set_clawback_receiver_native](crate::instructions::handle_set_clawback_receiver_native)
+    /// for native SOL distributors.
+    #[account(
+        mut,
+        constraint = distributor.asset_kind == AssetKind::Token @ ErrorCode::AssetKindMismatch
+    )]
     pub distributor: Account<'info, MerkleDistributor>,
 
     /// New clawback account
     #[account(token::mint=distributor.mint)]
-    pub new_clawback_account: Account<'info, TokenAccount>,
+    pub new_clawback_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Admin signer
     #[account(mut, address = distributor.admin @ ErrorCode::Unauthorized)]
     pub admin: Signer<'info>,
 }
 
-/// Sets new clawback receiver token account
+/// Proposes a new clawback receiver token account, which becomes the clawback receiver once its
+/// owner signs [accept_clawback_receiver](crate::instructions::handle_accept_clawback_receiver).
+/// Replaces any pending proposal.
 /// CHECK:
 ///     1. The new clawback receiver is not the same as the old one
 #[allow(clippy::result_large_err)]
@@ -38,14 +51,18 @@ pub fn handle_set_clawback_receiver(ctx: Context<SetClawbackReceiver>) -> Result
 
     let new_clawback_account = *ctx.accounts.new_clawback_account.to_account_info().key;
 
-    distributor.clawback_receiver = new_clawback_account;
+    distributor.pending_clawback_receiver = new_clawback_account;
 
     // Note: might get truncated, do not rely on
     msg!(
-        "set new clawback receiver ATA to {}, owned by {}",
+        "proposed new clawback receiver ATA {}, owned by {}",
         new_clawback_account,
         ctx.accounts.new_clawback_account.owner
     );
+    emit!(PendingClawbackReceiverSetEvent {
+        distributor: distributor.key(),
+        pending_clawback_receiver: distributor.pending_clawback_receiver,
+    });
 
     Ok(())
 }
@@ -0,0 +1,144 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        admin_event::PendingClawbackReceiverSetEvent, asset_kind::AssetKind,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::set_clawback_receiver_native] accounts.
+#[derive(Accounts)]
+pub struct SetClawbackReceiverNative<'info> {
+    /// The native SOL [MerkleDistributor].
+    #[account(
+        mut,
+        constraint = distributor.asset_kind == AssetKind::NativeSol @ ErrorCode::AssetKindMismatch
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// New clawback receiver wallet
+    pub new_clawback_receiver: SystemAccount<'info>,
+
+    /// Admin signer
+    #[account(mut, address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+}
+
+/// Proposes a new clawback receiver wallet for a native SOL distributor, which becomes the
+/// clawback receiver once it signs
+/// [accept_clawback_receiver_native](crate::instructions::handle_accept_clawback_receiver_native).
+/// Same as [set_clawback_receiver](crate::instructions::handle_set_clawback_receiver).
+/// CHECK:
+///     1. The new clawback receiver is not the same as the old one
+#[allow(clippy::result_large_err)]
+pub fn handle_set_clawback_receiver_native(ctx: Context<SetClawbackReceiverNative>) -> Result<()> {
+    let new_clawback_receiver = ctx.accounts.new_clawback_receiver.key();
+    require!(
+        ctx.accounts.distributor.clawback_receiver != new_clawback_receiver,
+        ErrorCode::SameClawbackReceiver
+    );
+
+    let distributor = &mut ctx.accounts.distributor;
+
+    distributor.pending_clawback_receiver = new_clawback_receiver;
+
+    // Note: might get truncated, do not rely on
+    msg!("proposed new clawback receiver {}", new_clawback_receiver);
+    emit!(PendingClawbackReceiverSetEvent {
+        distributor: distributor.key(),
+        pending_clawback_receiver: distributor.pending_clawback_receiver,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::AccountDeserialize;
+
+    use super::*;
+    use crate::test_utils::{process_instruction, TestAccount};
+
+    fn distributor_account(key: Pubkey, admin: Pubkey, asset_kind: AssetKind) -> TestAccount {
+        let distributor = MerkleDistributor {
+            admin,
+            clawback_receiver: Pubkey::new_unique(),
+            asset_kind,
+            ..MerkleDistributor::default()
+        };
+        TestAccount::program(key, &distributor, MerkleDistributor::LEN)
+    }
+
+    #[test]
+    fn test_change_native_clawback_receiver() {
+        let (distributor, admin, new_clawback_receiver) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut accounts = [
+            distributor_account(distributor, admin, AssetKind::NativeSol),
+            TestAccount::wallet(new_clawback_receiver),
+            TestAccount::wallet(admin),
+        ];
+        process_instruction(
+            crate::accounts::SetClawbackReceiverNative {
+                distributor,
+                new_clawback_receiver,
+                admin,
+            },
+            crate::instruction::SetClawbackReceiverNative {},
+            &mut accounts,
+        )
+        .unwrap();
+
+        let [distributor_account, new_clawback_receiver_account, _] = accounts;
+        let mut accounts = [distributor_account, new_clawback_receiver_account];
+        process_instruction(
+            crate::accounts::AcceptClawbackReceiverNative {
+                distributor,
+                new_clawback_receiver,
+            },
+            crate::instruction::AcceptClawbackReceiverNative {},
+            &mut accounts,
+        )
+        .unwrap();
+
+        let state = MerkleDistributor::try_deserialize(&mut &accounts[0].data[..]).unwrap();
+        assert_eq!(state.clawback_receiver, new_clawback_receiver);
+        assert_eq!(state.pending_clawback_receiver, Pubkey::default());
+    }
+
+    #[test]
+    fn test_native_clawback_receiver_rejects_token_distributor() {
+        let (distributor, admin, new_clawback_receiver) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut accounts = [
+            distributor_account(distributor, admin, AssetKind::Token),
+            TestAccount::wallet(new_clawback_receiver),
+            TestAccount::wallet(admin),
+        ];
+        assert_eq!(
+            process_instruction(
+                crate::accounts::SetClawbackReceiverNative {
+                    distributor,
+                    new_clawback_receiver,
+                    admin,
+                },
+                crate::instruction::SetClawbackReceiverNative {},
+                &mut accounts
+            ),
+            Err(Error::from(ErrorCode::AssetKindMismatch).into())
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use anchor_lang::{context::Context, prelude::*, system_program::System, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    instructions::close_claim_status::record_closed_claim,
+    state::{
+        claim_status::ClaimStatus, claimed_event::ClaimTransferredEvent,
+        closed_claims::ClosedClaims, merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::transfer_claim] accounts.
+#[derive(Accounts)]
+pub struct TransferClaim<'info> {
+    /// The [MerkleDistributor].
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim Status PDA of the current claimant, closed once moved.
+    #[account(
+        mut,
+        close = claimant,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// CHECK: [ClosedClaims] chunk recording the claimant, created by the first claim it records.
+    #[account(
+        mut,
+        seeds = [
+            b"ClosedClaims".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            &[ClosedClaims::chunk_index_of(&claimant.key())]
+        ],
+        bump,
+    )]
+    pub closed_claims: UncheckedAccount<'info>,
+
+    /// Claim Status PDA of the new claimant.
+    #[account(
+        init,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            new_claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = ClaimStatus::LEN,
+        payer = claimant
+    )]
+    pub new_claim_status: Account<'info, ClaimStatus>,
+
+    /// Current claimant, pays the rent for the new claim status and receives the rent of the old one.
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// Claimant the claim is moved to, signs so a claim can't be moved onto the claim status PDA
+    /// of another claimant of the distributor.
+    pub new_claimant: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Moves the [ClaimStatus] of the claimant, and its remaining vesting, to a new claimant, for
+/// instance when the claimant's key is compromised or about to be lost.
+/// The claimant is recorded in its [ClosedClaims] chunk so its leaf can't be claimed again.
+/// The new claimant can't claim its own leaf afterwards, so it should not be a claimant of the
+/// distributor. No tokens move, so it works the same for token and native SOL distributors.
+/// CHECK:
+///     1. The claimant and the new claimant signed the transaction
+///     2. The distributor has not been clawed back
+///     3. The new claimant does not have a claim status
+#[allow(clippy::result_large_err)]
+pub fn handle_transfer_claim(ctx: Context<TransferClaim>) -> Result<()> {
+    move_claim(
+        &ctx.accounts.distributor,
+        &ctx.accounts.claim_status,
+        &ctx.accounts.closed_claims,
+        *ctx.bumps.get("closed_claims").unwrap(),
+        &mut ctx.accounts.new_claim_status,
+        ctx.accounts.new_claimant.key(),
+        &ctx.accounts.system_program,
+        false,
+    )
+}
+
+/// Copies claim_status to the new claim status of `new_claimant` and records the old claimant
+/// as closed, callers close claim_status.
+/// Shared by [handle_transfer_claim] and
+/// [handle_recover_claim](crate::instructions::handle_recover_claim).
+#[allow(clippy::result_large_err)]
+pub(crate) fn move_claim<'info>(
+    distributor: &Account<'info, MerkleDistributor>,
+    claim_status: &Account<'info, ClaimStatus>,
+    closed_claims: &UncheckedAccount<'info>,
+    closed_claims_bump: u8,
+    new_claim_status: &mut Account<'info, ClaimStatus>,
+    new_claimant: Pubkey,
+    system_program: &Program<'info, System>,
+    recovered: bool,
+) -> Result<()> {
+    require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+
+    new_claim_status.claimant = new_claimant;
+    new_claim_status.locked_amount = claim_status.locked_amount;
+    new_claim_status.locked_amount_withdrawn = claim_status.locked_amount_withdrawn;
+    new_claim_status.unlocked_amount = claim_status.unlocked_amount;
+    new_claim_status.start_ts = claim_status.start_ts;
+    new_claim_status.end_ts = claim_status.end_ts;
+
+    record_closed_claim(
+        &distributor.key(),
+        closed_claims,
+        closed_claims_bump,
+        &claim_status.to_account_info(),
+        &claim_status.claimant,
+        system_program,
+    )?;
+
+    let locked_amount_remaining = claim_status
+        .locked_amount
+        .checked_sub(claim_status.locked_amount_withdrawn)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Moved claim of {} to {} with {} locked tokens remaining",
+        claim_status.claimant,
+        new_claimant,
+        locked_amount_remaining,
+    );
+    emit!(ClaimTransferredEvent {
+        distributor: distributor.key(),
+        claimant: claim_status.claimant,
+        new_claimant,
+        locked_amount_remaining,
+        recovered,
+    });
+
+    Ok(())
+}
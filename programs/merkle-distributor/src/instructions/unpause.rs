@@ -0,0 +1,40 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{distributor_event::PausedEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::unpause] accounts.
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+}
+
+/// Resumes claims on a paused [MerkleDistributor].
+#[allow(clippy::result_large_err)]
+pub fn handle_unpause(ctx: Context<Unpause>) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    distributor.paused = false;
+
+    // Note: might get truncated, do not rely on
+    msg!("unpaused distributor");
+    emit!(PausedEvent {
+        distributor: distributor.key(),
+        paused: distributor.paused,
+    });
+
+    Ok(())
+}
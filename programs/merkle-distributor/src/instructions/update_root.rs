@@ -0,0 +1,83 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{merkle_distributor::MerkleDistributor, root_updated_event::RootUpdatedEvent},
+};
+
+/// [merkle_distributor::update_root] accounts.
+#[derive(Accounts)]
+pub struct UpdateRoot<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+}
+
+/// Replaces the merkle root, max_total_claim and max_num_nodes of the [MerkleDistributor].
+/// CHECK:
+///     1. The distributor has not been clawed back
+///     2. The distributor is cumulative, no nodes have been claimed yet, or the root lock time has not passed
+///     3. The new max_total_claim and max_num_nodes cover what has already been claimed
+///     4. No leaf has been claimed yet, if the distributor tracks claims with
+///        [ClaimBitmap](crate::state::claim_bitmap::ClaimBitmap) accounts, since their bits index
+///        leaves of the old root
+#[allow(clippy::result_large_err)]
+pub fn handle_update_root(
+    ctx: Context<UpdateRoot>,
+    root: [u8; 32],
+    max_total_claim: u64,
+    max_num_nodes: u64,
+) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+    let curr_ts = Clock::get()?.unix_timestamp;
+
+    require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+    require!(
+        distributor.cumulative
+            || distributor.num_nodes_claimed == 0
+            || curr_ts < distributor.root_lock_ts,
+        ErrorCode::RootLocked
+    );
+    require!(
+        !distributor.bitmap_claims || distributor.num_nodes_claimed == 0,
+        ErrorCode::RootLocked
+    );
+    require!(
+        max_total_claim >= distributor.total_amount_claimed,
+        ErrorCode::ExceededMaxClaim
+    );
+    require!(
+        max_num_nodes >= distributor.num_nodes_claimed,
+        ErrorCode::MaxNodesExceeded
+    );
+
+    let old_root = distributor.root;
+    distributor.root = root;
+    distributor.max_total_claim = max_total_claim;
+    distributor.max_num_nodes = max_num_nodes;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "updated root with max_total_claim={}, max_nodes: {}",
+        max_total_claim,
+        max_num_nodes
+    );
+    emit!(RootUpdatedEvent {
+        distributor: distributor.key(),
+        old_root,
+        new_root: root,
+        max_total_claim,
+        max_num_nodes,
+    });
+
+    Ok(())
+}
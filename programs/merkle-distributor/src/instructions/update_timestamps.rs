@@ -0,0 +1,147 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{distributor_event::TimestampsUpdatedEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::update_timestamps] accounts.
+#[derive(Accounts)]
+pub struct UpdateTimestamps<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+}
+
+/// Extends the claim window of the [MerkleDistributor]. Pass the current value of any
+/// timestamp that should not change.
+/// Claims with their own lockup in the leaf are not affected by a new start or end.
+/// A cliff is relative to the start, so it moves with it.
+/// CHECK:
+///     1. The distributor has not been clawed back
+///     2. The clawback start is not moved earlier
+///     3. The start and end timestamps only move before vesting starts, and to the future
+///     4. All invariants of [new_distributor_v2](crate::instructions::handle_new_distributor_v2) still hold
+#[allow(clippy::result_large_err)]
+pub fn handle_update_timestamps(
+    ctx: Context<UpdateTimestamps>,
+    start_vesting_ts: i64,
+    end_vesting_ts: i64,
+    clawback_start_ts: i64,
+) -> Result<()> {
+    let curr_ts = Clock::get()?.unix_timestamp;
+    let distributor = &mut ctx.accounts.distributor;
+
+    require!(!distributor.clawed_back, ErrorCode::ClawbackAlreadyClaimed);
+
+    distributor.update_timestamps(curr_ts, start_vesting_ts, end_vesting_ts, clawback_start_ts)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Updated timestamps to start_ts: {}, end_ts: {}, clawback_start: {}",
+        distributor.start_ts,
+        distributor.end_ts,
+        distributor.clawback_start_ts
+    );
+    emit!(TimestampsUpdatedEvent {
+        distributor: distributor.key(),
+        start_ts: distributor.start_ts,
+        end_ts: distributor.end_ts,
+        clawback_start_ts: distributor.clawback_start_ts,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        state::vesting_schedule::VestingSchedule,
+        test_utils::{process_instruction, set_unix_timestamp, TestAccount},
+    };
+
+    const SECONDS_PER_DAY: i64 = 24 * 3600;
+
+    fn update_timestamps(
+        distributor: &MerkleDistributor,
+        start_vesting_ts: i64,
+        end_vesting_ts: i64,
+        clawback_start_ts: i64,
+    ) -> std::result::Result<MerkleDistributor, ProgramError> {
+        let key = Pubkey::new_unique();
+        let mut accounts = [
+            TestAccount::program(key, distributor, MerkleDistributor::LEN),
+            TestAccount::wallet(distributor.admin),
+        ];
+        process_instruction(
+            crate::accounts::UpdateTimestamps {
+                distributor: key,
+                admin: distributor.admin,
+            },
+            crate::instruction::UpdateTimestamps {
+                start_vesting_ts,
+                end_vesting_ts,
+                clawback_start_ts,
+            },
+            &mut accounts,
+        )?;
+        Ok(MerkleDistributor::try_deserialize(&mut accounts[0].data.as_slice()).unwrap())
+    }
+
+    #[test]
+    fn test_update_timestamps_moves_lockup() {
+        set_unix_timestamp(500);
+        let distributor = MerkleDistributor {
+            admin: Pubkey::new_unique(),
+            start_ts: 1_000,
+            end_ts: 2_000,
+            clawback_start_ts: 2_000 + SECONDS_PER_DAY,
+            vesting_schedule: VestingSchedule::CliffLinear {
+                cliff_offset: 200,
+                cliff_unlock_bps: 2_500,
+            },
+            ..MerkleDistributor::default()
+        };
+
+        let updated =
+            update_timestamps(&distributor, 1_500, 2_500, 3_000 + SECONDS_PER_DAY).unwrap();
+        assert_eq!(updated.start_ts, 1_500);
+        assert_eq!(updated.end_ts, 2_500);
+        assert_eq!(updated.clawback_start_ts, 3_000 + SECONDS_PER_DAY);
+        assert_eq!(updated.vesting_schedule, distributor.vesting_schedule);
+
+        // the lockup end must stay after the cliff
+        assert_eq!(
+            update_timestamps(&distributor, 1_500, 1_700, 2_000 + SECONDS_PER_DAY).unwrap_err(),
+            Error::from(ErrorCode::InvalidVestingSchedule).into()
+        );
+    }
+
+    #[test]
+    fn test_update_timestamps_rejects_clawed_back_distributor() {
+        set_unix_timestamp(500);
+        let distributor = MerkleDistributor {
+            admin: Pubkey::new_unique(),
+            start_ts: 1_000,
+            end_ts: 2_000,
+            clawback_start_ts: 2_000 + SECONDS_PER_DAY,
+            clawed_back: true,
+            ..MerkleDistributor::default()
+        };
+
+        assert_eq!(
+            update_timestamps(&distributor, 1_000, 2_000, 3_000 + SECONDS_PER_DAY).unwrap_err(),
+            Error::from(ErrorCode::ClawbackAlreadyClaimed).into()
+        );
+    }
+}
@@ -18,6 +18,9 @@ use solana_security_txt::security_txt;
 pub mod error;
 pub mod instructions;
 pub mod state;
+#[cfg(test)]
+mod test_utils;
+pub mod utils;
 
 security_txt! {
     // Required fields
@@ -77,14 +80,152 @@ pub mod merkle_distributor {
         )
     }
 
+    /// Creates a distributor with the options of [NewDistributorParams], see [new_distributor] for
+    /// how to avoid frontrunning.
+    #[allow(clippy::result_large_err)]
+    pub fn new_distributor_v2(
+        ctx: Context<NewDistributor>,
+        version: u64,
+        params: NewDistributorParams,
+    ) -> Result<()> {
+        handle_new_distributor_v2(ctx, version, params)
+    }
+
+    /// Grows a distributor created by an earlier version of the program so it deserializes again.
+    #[allow(clippy::result_large_err)]
+    pub fn migrate_distributor(ctx: Context<MigrateDistributor>) -> Result<()> {
+        handle_migrate_distributor(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn fund_distributor(ctx: Context<FundDistributor>, amount: u64) -> Result<()> {
+        handle_fund_distributor(ctx, amount)
+    }
+
+    /// Creates a native SOL distributor, see [new_distributor] for how to avoid frontrunning.
+    #[allow(clippy::result_large_err)]
+    pub fn new_distributor_native(
+        ctx: Context<NewDistributorNative>,
+        version: u64,
+        params: NewDistributorParams,
+    ) -> Result<()> {
+        handle_new_distributor_native(ctx, version, params)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn fund_distributor_native(ctx: Context<FundDistributorNative>, amount: u64) -> Result<()> {
+        handle_fund_distributor_native(ctx, amount)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_native(
+        ctx: Context<NewClaimNative>,
+        amount_unlocked: u64,
+        amount_locked: u64,
+        proof: Vec<[u8; 32]>,
+        start_ts: Option<i64>,
+        end_ts: Option<i64>,
+    ) -> Result<()> {
+        handle_new_claim_native(ctx, amount_unlocked, amount_locked, proof, start_ts, end_ts)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn claim_locked_native(ctx: Context<ClaimLockedNative>) -> Result<()> {
+        handle_claim_locked_native(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn clawback_native(ctx: Context<ClawbackNative>) -> Result<()> {
+        handle_clawback_native(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn add_distributor_mint(
+        ctx: Context<AddDistributorMint>,
+        mint_index: u8,
+        max_total_claim: u64,
+    ) -> Result<()> {
+        handle_add_distributor_mint(ctx, mint_index, max_total_claim)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_multi_mint(
+        ctx: Context<NewClaimMultiMint>,
+        mint_index: u8,
+        amount_unlocked: u64,
+        amount_locked: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_new_claim_multi_mint(ctx, mint_index, amount_unlocked, amount_locked, proof)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn claim_locked_multi_mint(
+        ctx: Context<ClaimLockedMultiMint>,
+        mint_index: u8,
+    ) -> Result<()> {
+        handle_claim_locked_multi_mint(ctx, mint_index)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn clawback_multi_mint(ctx: Context<ClawbackMultiMint>, mint_index: u8) -> Result<()> {
+        handle_clawback_multi_mint(ctx, mint_index)
+    }
+
     #[allow(clippy::result_large_err)]
     pub fn new_claim(
         ctx: Context<NewClaim>,
         amount_unlocked: u64,
         amount_locked: u64,
         proof: Vec<[u8; 32]>,
+        start_ts: Option<i64>,
+        end_ts: Option<i64>,
+    ) -> Result<()> {
+        handle_new_claim(ctx, amount_unlocked, amount_locked, proof, start_ts, end_ts)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_bitmap(ctx: Context<NewClaimBitmap>, chunk_index: u64) -> Result<()> {
+        handle_new_claim_bitmap(ctx, chunk_index)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_indexed(
+        ctx: Context<NewClaimIndexed>,
+        index: u64,
+        amount_unlocked: u64,
+        proof: Vec<[u8; 32]>,
     ) -> Result<()> {
-        handle_new_claim(ctx, amount_unlocked, amount_locked, proof)
+        handle_new_claim_indexed(ctx, index, amount_unlocked, proof)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn close_claim_bitmap(ctx: Context<CloseClaimBitmap>) -> Result<()> {
+        handle_close_claim_bitmap(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn new_cumulative_claim(
+        ctx: Context<NewCumulativeClaim>,
+        cumulative_amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_new_cumulative_claim(ctx, cumulative_amount, proof)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn claim_cumulative(
+        ctx: Context<ClaimCumulative>,
+        cumulative_amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_claim_cumulative(ctx, cumulative_amount, proof)
+    }
+
+    /// Grows a claim status created by an earlier version of the program so it deserializes again.
+    #[allow(clippy::result_large_err)]
+    pub fn migrate_claim_status(ctx: Context<MigrateClaimStatus>) -> Result<()> {
+        handle_migrate_claim_status(ctx)
     }
 
     #[allow(clippy::result_large_err)]
@@ -92,18 +233,181 @@ pub mod merkle_distributor {
         handle_claim_locked(ctx)
     }
 
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_on_behalf(
+        ctx: Context<NewClaimOnBehalf>,
+        amount_unlocked: u64,
+        amount_locked: u64,
+        proof: Vec<[u8; 32]>,
+        start_ts: Option<i64>,
+        end_ts: Option<i64>,
+    ) -> Result<()> {
+        handle_new_claim_on_behalf(ctx, amount_unlocked, amount_locked, proof, start_ts, end_ts)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn claim_locked_on_behalf(ctx: Context<ClaimLockedOnBehalf>) -> Result<()> {
+        handle_claim_locked_on_behalf(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_to(
+        ctx: Context<NewClaimTo>,
+        amount_unlocked: u64,
+        amount_locked: u64,
+        proof: Vec<[u8; 32]>,
+        start_ts: Option<i64>,
+        end_ts: Option<i64>,
+    ) -> Result<()> {
+        handle_new_claim_to(ctx, amount_unlocked, amount_locked, proof, start_ts, end_ts)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn claim_locked_to(ctx: Context<ClaimLockedTo>) -> Result<()> {
+        handle_claim_locked_to(ctx)
+    }
+
     #[allow(clippy::result_large_err)]
     pub fn clawback(ctx: Context<Clawback>) -> Result<()> {
         handle_clawback(ctx)
     }
 
+    #[allow(clippy::result_large_err)]
+    pub fn clawback_surplus(ctx: Context<ClawbackSurplus>) -> Result<()> {
+        handle_clawback_surplus(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn close_distributor(ctx: Context<CloseDistributor>) -> Result<()> {
+        handle_close_distributor(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn close_claim_status(ctx: Context<CloseClaimStatus>) -> Result<()> {
+        handle_close_claim_status(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn close_closed_claims(ctx: Context<CloseClosedClaims>) -> Result<()> {
+        handle_close_closed_claims(ctx)
+    }
+
     #[allow(clippy::result_large_err)]
     pub fn set_clawback_receiver(ctx: Context<SetClawbackReceiver>) -> Result<()> {
         handle_set_clawback_receiver(ctx)
     }
 
+    #[allow(clippy::result_large_err)]
+    pub fn accept_clawback_receiver(ctx: Context<AcceptClawbackReceiver>) -> Result<()> {
+        handle_accept_clawback_receiver(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn set_clawback_receiver_native(ctx: Context<SetClawbackReceiverNative>) -> Result<()> {
+        handle_set_clawback_receiver_native(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn accept_clawback_receiver_native(
+        ctx: Context<AcceptClawbackReceiverNative>,
+    ) -> Result<()> {
+        handle_accept_clawback_receiver_native(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn cancel_pending_clawback_receiver(
+        ctx: Context<CancelPendingClawbackReceiver>,
+    ) -> Result<()> {
+        handle_cancel_pending_clawback_receiver(ctx)
+    }
+
     #[allow(clippy::result_large_err)]
     pub fn set_admin(ctx: Context<SetAdmin>) -> Result<()> {
         handle_set_admin(ctx)
     }
+
+    #[allow(clippy::result_large_err)]
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        handle_accept_admin(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn cancel_pending_admin(ctx: Context<CancelPendingAdmin>) -> Result<()> {
+        handle_cancel_pending_admin(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn update_timestamps(
+        ctx: Context<UpdateTimestamps>,
+        start_vesting_ts: i64,
+        end_vesting_ts: i64,
+        clawback_start_ts: i64,
+    ) -> Result<()> {
+        handle_update_timestamps(ctx, start_vesting_ts, end_vesting_ts, clawback_start_ts)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn pause(ctx: Context<Pause>) -> Result<()> {
+        handle_pause(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
+        handle_unpause(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn update_root(
+        ctx: Context<UpdateRoot>,
+        root: [u8; 32],
+        max_total_claim: u64,
+        max_num_nodes: u64,
+    ) -> Result<()> {
+        handle_update_root(ctx, root, max_total_claim, max_num_nodes)
+    }
+
+    /// Revokes the unvested locked tokens of an opened claim, sending them to the clawback receiver.
+    #[allow(clippy::result_large_err)]
+    pub fn revoke(ctx: Context<Revoke>) -> Result<()> {
+        handle_revoke(ctx)
+    }
+
+    /// Opens an unclaimed leaf on behalf of the claimant and revokes its unvested locked tokens.
+    #[allow(clippy::result_large_err)]
+    pub fn revoke_unclaimed(
+        ctx: Context<RevokeUnclaimed>,
+        amount_unlocked: u64,
+        amount_locked: u64,
+        proof: Vec<[u8; 32]>,
+        start_ts: Option<i64>,
+        end_ts: Option<i64>,
+    ) -> Result<()> {
+        handle_revoke_unclaimed(ctx, amount_unlocked, amount_locked, proof, start_ts, end_ts)
+    }
+
+    /// Moves the claim of the signing claimant, and its remaining vesting, to a new claimant.
+    #[allow(clippy::result_large_err)]
+    pub fn transfer_claim(ctx: Context<TransferClaim>) -> Result<()> {
+        handle_transfer_claim(ctx)
+    }
+
+    /// Proposes to move the claim of a claimant to a new claimant once the claim recovery delay has
+    /// passed.
+    #[allow(clippy::result_large_err)]
+    pub fn propose_claim_recovery(
+        ctx: Context<ProposeClaimRecovery>,
+        new_claimant: Pubkey,
+    ) -> Result<()> {
+        handle_propose_claim_recovery(ctx, new_claimant)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn cancel_claim_recovery(ctx: Context<CancelClaimRecovery>) -> Result<()> {
+        handle_cancel_claim_recovery(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn recover_claim(ctx: Context<RecoverClaim>) -> Result<()> {
+        handle_recover_claim(ctx)
+    }
 }
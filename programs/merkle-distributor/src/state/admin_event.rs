@@ -0,0 +1,63 @@
+use anchor_lang::{event, prelude::*};
+
+/// Emitted when the admin proposes a new admin, or cancels the proposal.
+#[event]
+pub struct PendingAdminSetEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Proposed admin, default if the proposal was cancelled.
+    pub pending_admin: Pubkey,
+}
+
+/// Emitted when the proposed admin accepts the admin role.
+#[event]
+pub struct AdminChangedEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Admin before the change.
+    pub old_admin: Pubkey,
+    /// Admin after the change.
+    pub new_admin: Pubkey,
+}
+
+/// Emitted when the admin proposes a new clawback receiver, or cancels the proposal.
+#[event]
+pub struct PendingClawbackReceiverSetEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Proposed clawback receiver, default if the proposal was cancelled.
+    pub pending_clawback_receiver: Pubkey,
+}
+
+/// Emitted when the owner of the proposed clawback receiver accepts it.
+#[event]
+pub struct ClawbackReceiverChangedEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Clawback receiver before the change.
+    pub old_clawback_receiver: Pubkey,
+    /// Clawback receiver after the change.
+    pub new_clawback_receiver: Pubkey,
+}
+
+/// Emitted when the admin proposes to recover a claim to a new claimant.
+#[event]
+pub struct ClaimRecoveryProposedEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Claimant whose claim is recovered.
+    pub claimant: Pubkey,
+    /// Claimant the claim is moved to.
+    pub new_claimant: Pubkey,
+    /// The recovery can be executed from this time (Unix Timestamp).
+    pub recoverable_ts: i64,
+}
+
+/// Emitted when the admin cancels the recovery of a claim.
+#[event]
+pub struct ClaimRecoveryCancelledEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Claimant whose claim was to be recovered.
+    pub claimant: Pubkey,
+}
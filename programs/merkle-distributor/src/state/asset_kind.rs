@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+/// What a [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor) distributes.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AssetKind {
+    /// SPL Token or Token-2022 tokens of the distributor's mint, held in its token vault.
+    #[default]
+    Token,
+    /// Lamports held in a [SolVault](crate::state::sol_vault::SolVault).
+    NativeSol,
+}
@@ -0,0 +1,116 @@
+// The padding check generated by the bytemuck derive behind `#[account(zero_copy)]` declares a
+// tuple struct whose field is never read.
+#![allow(dead_code)]
+
+use anchor_lang::prelude::*;
+
+/// Number of u64 words in a [ClaimBitmap].
+const WORDS_PER_CHUNK: usize = 1024;
+
+/// Tracks which leaves of a [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor)
+/// have been claimed, one bit per leaf index. Used instead of a
+/// [ClaimStatus](crate::state::claim_status::ClaimStatus) per claimant by distributors created
+/// with bitmap claims. Each chunk covers [ClaimBitmap::LEAVES_PER_CHUNK] consecutive leaf indices.
+#[account(zero_copy)]
+pub struct ClaimBitmap {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Covers leaf indices from chunk_index * LEAVES_PER_CHUNK up to the next chunk.
+    pub chunk_index: u64,
+    /// One bit per leaf index, set once the leaf has been claimed.
+    pub bits: [u64; WORDS_PER_CHUNK],
+}
+
+impl ClaimBitmap {
+    pub const LEN: usize = 8 + std::mem::size_of::<ClaimBitmap>();
+
+    /// Number of leaves tracked by a single chunk.
+    pub const LEAVES_PER_CHUNK: u64 = WORDS_PER_CHUNK as u64 * u64::BITS as u64;
+
+    /// Returns the index of the chunk tracking `leaf_index`.
+    pub fn chunk_index_of(leaf_index: u64) -> u64 {
+        leaf_index / Self::LEAVES_PER_CHUNK
+    }
+
+    /// Returns the number of chunks needed to track `num_leaves` leaves.
+    pub fn num_chunks(num_leaves: u64) -> u64 {
+        num_leaves / Self::LEAVES_PER_CHUNK + u64::from(num_leaves % Self::LEAVES_PER_CHUNK != 0)
+    }
+
+    /// Returns whether `leaf_index` has been claimed. `leaf_index` must belong to this chunk.
+    pub fn is_claimed(&self, leaf_index: u64) -> bool {
+        let (word, mask) = Self::position(leaf_index);
+        self.bits[word] & mask != 0
+    }
+
+    /// Marks `leaf_index` as claimed. `leaf_index` must belong to this chunk.
+    pub fn set_claimed(&mut self, leaf_index: u64) {
+        let (word, mask) = Self::position(leaf_index);
+        self.bits[word] |= mask;
+    }
+
+    fn position(leaf_index: u64) -> (usize, u64) {
+        let bit = leaf_index % Self::LEAVES_PER_CHUNK;
+        (
+            (bit / u64::BITS as u64) as usize,
+            1 << (bit % u64::BITS as u64),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use super::*;
+
+    #[test]
+    fn test_chunk_index_of() {
+        assert_eq!(ClaimBitmap::chunk_index_of(0), 0);
+        assert_eq!(
+            ClaimBitmap::chunk_index_of(ClaimBitmap::LEAVES_PER_CHUNK - 1),
+            0
+        );
+        assert_eq!(
+            ClaimBitmap::chunk_index_of(ClaimBitmap::LEAVES_PER_CHUNK),
+            1
+        );
+    }
+
+    #[test]
+    fn test_num_chunks() {
+        assert_eq!(ClaimBitmap::num_chunks(0), 0);
+        assert_eq!(ClaimBitmap::num_chunks(1), 1);
+        assert_eq!(ClaimBitmap::num_chunks(ClaimBitmap::LEAVES_PER_CHUNK), 1);
+        assert_eq!(
+            ClaimBitmap::num_chunks(ClaimBitmap::LEAVES_PER_CHUNK + 1),
+            2
+        );
+    }
+
+    #[test]
+    fn test_set_claimed() {
+        let mut claim_bitmap = ClaimBitmap::zeroed();
+        let leaf_indices = [0, 1, 63, 64, ClaimBitmap::LEAVES_PER_CHUNK - 1];
+
+        for leaf_index in leaf_indices {
+            assert!(!claim_bitmap.is_claimed(leaf_index));
+            claim_bitmap.set_claimed(leaf_index);
+            assert!(claim_bitmap.is_claimed(leaf_index));
+        }
+
+        let num_claimed: u32 = claim_bitmap.bits.iter().map(|w| w.count_ones()).sum();
+        assert_eq!(num_claimed, leaf_indices.len() as u32);
+        assert!(!claim_bitmap.is_claimed(2));
+    }
+
+    #[test]
+    fn test_set_claimed_in_later_chunk() {
+        let mut claim_bitmap = ClaimBitmap::zeroed();
+        claim_bitmap.chunk_index = 1;
+
+        claim_bitmap.set_claimed(ClaimBitmap::LEAVES_PER_CHUNK + 5);
+        assert!(claim_bitmap.is_claimed(ClaimBitmap::LEAVES_PER_CHUNK + 5));
+        assert_eq!(claim_bitmap.bits[0], 1 << 5);
+    }
+}
@@ -0,0 +1,19 @@
+use anchor_lang::{event, prelude::*};
+
+/// Emitted when a [ClaimBitmap](crate::state::claim_bitmap::ClaimBitmap) chunk is created.
+#[event]
+pub struct ClaimBitmapCreatedEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Index of the chunk.
+    pub chunk_index: u64,
+}
+
+/// Emitted when a [ClaimBitmap](crate::state::claim_bitmap::ClaimBitmap) chunk is closed.
+#[event]
+pub struct ClaimBitmapClosedEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Index of the chunk.
+    pub chunk_index: u64,
+}
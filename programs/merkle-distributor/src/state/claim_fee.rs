@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::{ErrorCode, ErrorCode::ArithmeticError},
+    state::vesting_schedule::MAX_BPS,
+};
+
+/// Fee charged when a claim is opened, collected by the distributor's fee receiver.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClaimFee {
+    /// Claims are free.
+    #[default]
+    None,
+    /// Flat amount of lamports, paid by whoever sends the claim to the fee receiver wallet.
+    Lamports {
+        /// Fee in lamports
+        amount: u64,
+    },
+    /// Portion of the unlocked tokens of the claim, withheld from the claimant and sent to a token
+    /// account of the fee receiver.
+    Bps {
+        /// Fee in basis points of the unlocked tokens
+        bps: u16,
+    },
+}
+
+impl ClaimFee {
+    /// Checks the fee is at most the whole claim and has a receiver.
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self, fee_receiver: &Pubkey) -> Result<()> {
+        match *self {
+            ClaimFee::None => {}
+            ClaimFee::Lamports { .. } => {
+                require!(
+                    *fee_receiver != Pubkey::default(),
+                    ErrorCode::InvalidClaimFee
+                );
+            }
+            ClaimFee::Bps { bps } => {
+                require!(
+                    bps <= MAX_BPS && *fee_receiver != Pubkey::default(),
+                    ErrorCode::InvalidClaimFee
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Lamports paid by whoever sends the claim.
+    pub fn lamports(&self) -> u64 {
+        match *self {
+            ClaimFee::Lamports { amount } => amount,
+            _ => 0,
+        }
+    }
+
+    /// Tokens withheld from the `amount_unlocked` tokens of a claim, rounded down.
+    #[allow(clippy::result_large_err)]
+    pub fn token_amount(&self, amount_unlocked: u64) -> Result<u64> {
+        match *self {
+            ClaimFee::Bps { bps } => {
+                let amount = (amount_unlocked as u128)
+                    .checked_mul(bps as u128)
+                    .ok_or(ArithmeticError)?
+                    .checked_div(MAX_BPS as u128)
+                    .ok_or(ArithmeticError)?;
+                Ok(u64::try_from(amount).map_err(|_| ArithmeticError)?)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        let fee_receiver = Pubkey::new_unique();
+        assert!(ClaimFee::None.validate(&Pubkey::default()).is_ok());
+        assert!(ClaimFee::Lamports { amount: 5_000 }
+            .validate(&fee_receiver)
+            .is_ok());
+        assert!(ClaimFee::Bps { bps: MAX_BPS }
+            .validate(&fee_receiver)
+            .is_ok());
+
+        assert!(ClaimFee::Bps { bps: MAX_BPS + 1 }
+            .validate(&fee_receiver)
+            .is_err());
+        assert!(ClaimFee::Lamports { amount: 5_000 }
+            .validate(&Pubkey::default())
+            .is_err());
+        assert!(ClaimFee::Bps { bps: 100 }
+            .validate(&Pubkey::default())
+            .is_err());
+    }
+
+    #[test]
+    fn test_fee_amounts() {
+        let fee = ClaimFee::Lamports { amount: 5_000 };
+        assert_eq!(fee.lamports(), 5_000);
+        assert_eq!(fee.token_amount(1_000), Ok(0));
+
+        let fee = ClaimFee::Bps { bps: 250 };
+        assert_eq!(fee.lamports(), 0);
+        assert_eq!(fee.token_amount(1_000), Ok(25));
+        // rounded down
+        assert_eq!(fee.token_amount(39), Ok(0));
+        assert_eq!(fee.token_amount(u64::MAX), Ok(u64::MAX / 40));
+
+        assert_eq!(ClaimFee::None.token_amount(1_000), Ok(0));
+        assert_eq!(
+            ClaimFee::Bps { bps: MAX_BPS }.token_amount(1_000),
+            Ok(1_000)
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Recovery of the [ClaimStatus](crate::state::claim_status::ClaimStatus) of a claimant to a new
+/// claimant, proposed by the admin. Can be executed once the distributor's claim recovery delay has
+/// passed, giving the claimant time to move the claim themselves if the recovery is unwanted.
+#[account]
+#[derive(Default)]
+pub struct ClaimRecovery {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Claimant whose claim is recovered.
+    pub claimant: Pubkey,
+    /// Claimant the claim is moved to.
+    pub new_claimant: Pubkey,
+    /// The recovery can be executed from this time (Unix Timestamp).
+    pub recoverable_ts: i64,
+}
+
+impl ClaimRecovery {
+    pub const LEN: usize = 8 + std::mem::size_of::<ClaimRecovery>();
+}
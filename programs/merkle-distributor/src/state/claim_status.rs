@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
 
-use crate::error::ErrorCode::ArithmeticError;
+use crate::{error::ErrorCode::ArithmeticError, state::vesting_schedule::VestingSchedule};
 
 /// Holds whether or not a claimant has claimed tokens.
+/// New fields are only appended, and must default to zero bytes, so accounts created before can be
+/// grown with [migrate_claim_status](crate::instructions::handle_migrate_claim_status).
 #[account]
 #[derive(Default)]
 pub struct ClaimStatus {
@@ -14,64 +16,93 @@ pub struct ClaimStatus {
     pub locked_amount_withdrawn: u64,
     /// Unlocked amount
     pub unlocked_amount: u64,
+    /// Lockup start (Unix Timestamp) committed in the leaf, 0 if the distributor's lockup is used
+    pub start_ts: i64,
+    /// Lockup end (Unix Timestamp) committed in the leaf, 0 if the distributor's lockup is used
+    pub end_ts: i64,
 }
 
 impl ClaimStatus {
     pub const LEN: usize = 8 + std::mem::size_of::<ClaimStatus>();
 
+    /// Returns the (start_ts, end_ts) lockup this claim vests against: its own if the leaf
+    /// committed one, otherwise the distributor's
+    pub fn lockup(&self, distributor_start_ts: i64, distributor_end_ts: i64) -> (i64, i64) {
+        // end_ts is always after start_ts, so (0, 0) can never be a real lockup
+        if self.start_ts == 0 && self.end_ts == 0 {
+            (distributor_start_ts, distributor_end_ts)
+        } else {
+            (self.start_ts, self.end_ts)
+        }
+    }
+
     /// Returns amount withdrawable, factoring in unlocked tokens and previous withdraws.
     /// payout is difference between the amount unlocked and the amount withdrawn
     #[allow(clippy::result_large_err)]
-    pub fn amount_withdrawable(&self, curr_ts: i64, start_ts: i64, end_ts: i64) -> Result<u64> {
+    pub fn amount_withdrawable(
+        &self,
+        curr_ts: i64,
+        start_ts: i64,
+        end_ts: i64,
+        vesting_schedule: &VestingSchedule,
+    ) -> Result<u64> {
         let amount = self
-            .unlocked_amount(curr_ts, start_ts, end_ts)?
+            .unlocked_amount(curr_ts, start_ts, end_ts, vesting_schedule)?
             .checked_sub(self.locked_amount_withdrawn)
             .ok_or(ArithmeticError)?;
 
         Ok(amount)
     }
 
-    /// Total amount unlocked
-    /// Equal to (time_into_unlock / total_unlock_time) * locked_amount  
-    /// Multiplication safety:
-    ///    The maximum possible product is (2^64 -1) * (2^64 -1) = 2^128 - 2^65 + 1
-    ///    which is less than 2^128 - 1 (the maximum value of a u128), meaning that
-    ///    the multiplication will never overflow
-    /// Truncation from u128 to u64:
-    ///     Casting a u128 to a u64 will truncate the 64 higher order bits, which rounds
-    ///     down from the user.
-    ///     in order to avoid truncation, the final result must be less than 2^64 - 1.
-    ///     Rewriting the terms, we get (time_into_unlock * locked_amount) / total_unlock_time < 2^64 - 1
-    ///     We know time_into_unlock and total_unlock_time are both approximately the same size, so we can
-    ///     approximate the above as:
-    ///         b < 2^64 -1.
-    ///     Since b is a i64, this is always true, so no truncation can occur
+    /// Total amount unlocked, following the distributor's [VestingSchedule]
     #[allow(clippy::result_large_err)]
-    pub fn unlocked_amount(&self, curr_ts: i64, start_ts: i64, end_ts: i64) -> Result<u64> {
-        if curr_ts >= start_ts {
-            if curr_ts >= end_ts {
-                Ok(self.locked_amount)
-            } else {
-                let time_into_unlock = curr_ts.checked_sub(start_ts).ok_or(ArithmeticError)?;
-                let total_unlock_time = end_ts.checked_sub(start_ts).ok_or(ArithmeticError)?;
+    pub fn unlocked_amount(
+        &self,
+        curr_ts: i64,
+        start_ts: i64,
+        end_ts: i64,
+        vesting_schedule: &VestingSchedule,
+    ) -> Result<u64> {
+        vesting_schedule.unlocked_amount(self.locked_amount, curr_ts, start_ts, end_ts)
+    }
+
+    /// Freezes vesting at curr_ts: the locked tokens unlocked so far stay withdrawable and the rest
+    /// are removed from the claim. Returns the amount removed.
+    /// The claim then vests against a lockup ending at curr_ts, which fully unlocks the frozen
+    /// amount under every [VestingSchedule].
+    #[allow(clippy::result_large_err)]
+    pub fn revoke(
+        &mut self,
+        curr_ts: i64,
+        start_ts: i64,
+        end_ts: i64,
+        vesting_schedule: &VestingSchedule,
+    ) -> Result<u64> {
+        let vested_amount = self.unlocked_amount(curr_ts, start_ts, end_ts, vesting_schedule)?;
+        let revoked_amount = self
+            .locked_amount
+            .checked_sub(vested_amount)
+            .ok_or(ArithmeticError)?;
 
-                let amount = ((time_into_unlock as u128)
-                    .checked_mul(self.locked_amount as u128)
-                    .ok_or(ArithmeticError)?)
-                .checked_div(total_unlock_time as u128)
-                .ok_or(ArithmeticError)? as u64;
+        self.locked_amount = vested_amount;
+        self.start_ts = start_ts.min(curr_ts.checked_sub(1).ok_or(ArithmeticError)?);
+        self.end_ts = curr_ts;
 
-                Ok(amount)
-            }
-        } else {
-            Ok(0)
-        }
+        Ok(revoked_amount)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::vesting_schedule::MAX_BPS;
+
+    fn cliff(cliff_offset: i64, cliff_unlock_bps: u16) -> VestingSchedule {
+        VestingSchedule::CliffLinear {
+            cliff_offset,
+            cliff_unlock_bps,
+        }
+    }
 
     #[test]
     fn test_normal_unlocking_scenario() {
@@ -80,12 +111,13 @@ mod tests {
             locked_amount: 100,
             unlocked_amount: 0,
             locked_amount_withdrawn: 0,
+            ..ClaimStatus::default()
         };
         let curr_ts = 50;
         let start_ts = 0;
         let end_ts = 100;
         assert_eq!(
-            claim_status.unlocked_amount(curr_ts, start_ts, end_ts),
+            claim_status.unlocked_amount(curr_ts, start_ts, end_ts, &VestingSchedule::Linear),
             Ok(50)
         );
     }
@@ -97,15 +129,31 @@ mod tests {
             locked_amount: 100,
             locked_amount_withdrawn: 0,
             unlocked_amount: 0,
+            ..ClaimStatus::default()
         };
         let start_ts = 0;
         let end_ts = 100;
 
-        assert_eq!(claim_status.unlocked_amount(0, start_ts, end_ts), Ok(0));
-        assert_eq!(claim_status.unlocked_amount(25, start_ts, end_ts), Ok(25));
-        assert_eq!(claim_status.unlocked_amount(50, start_ts, end_ts), Ok(50));
-        assert_eq!(claim_status.unlocked_amount(75, start_ts, end_ts), Ok(75));
-        assert_eq!(claim_status.unlocked_amount(100, start_ts, end_ts), Ok(100));
+        assert_eq!(
+            claim_status.unlocked_amount(0, start_ts, end_ts, &VestingSchedule::Linear),
+            Ok(0)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(25, start_ts, end_ts, &VestingSchedule::Linear),
+            Ok(25)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(50, start_ts, end_ts, &VestingSchedule::Linear),
+            Ok(50)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(75, start_ts, end_ts, &VestingSchedule::Linear),
+            Ok(75)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(100, start_ts, end_ts, &VestingSchedule::Linear),
+            Ok(100)
+        );
     }
 
     #[test]
@@ -121,6 +169,7 @@ mod tests {
             locked_amount,
             unlocked_amount: 0,
             locked_amount_withdrawn: 0,
+            ..ClaimStatus::default()
         };
 
         // Use large values for time_into_unlock and total_unlock_time, but ensure they are within i64 range
@@ -134,7 +183,7 @@ mod tests {
 
             // Perform the calculation using the function
             let calculated_amount = claim_status
-                .unlocked_amount(curr_ts, start_ts, end_ts)
+                .unlocked_amount(curr_ts, start_ts, end_ts, &VestingSchedule::Linear)
                 .unwrap();
 
             // Assert that the calculated amount matches the expected amount and is within u64 bounds
@@ -150,12 +199,13 @@ mod tests {
             locked_amount: 100,
             unlocked_amount: 0,
             locked_amount_withdrawn: 0,
+            ..ClaimStatus::default()
         };
         let curr_ts = 150;
         let start_ts = 0;
         let end_ts = 100;
         assert_eq!(
-            claim_status.unlocked_amount(curr_ts, start_ts, end_ts),
+            claim_status.unlocked_amount(curr_ts, start_ts, end_ts, &VestingSchedule::Linear),
             Ok(100)
         );
     }
@@ -167,12 +217,13 @@ mod tests {
             locked_amount: 100,
             unlocked_amount: 0,
             locked_amount_withdrawn: 0,
+            ..ClaimStatus::default()
         };
         let curr_ts = 50;
         let start_ts = 100;
         let end_ts = 100;
         assert_eq!(
-            claim_status.unlocked_amount(curr_ts, start_ts, end_ts),
+            claim_status.unlocked_amount(curr_ts, start_ts, end_ts, &VestingSchedule::Linear),
             Ok(0)
         );
     }
@@ -186,7 +237,10 @@ mod tests {
         let start_ts = 100;
         let end_ts = 50;
 
-        assert_eq!(claim_status.unlocked_amount(75, start_ts, end_ts), Ok(0));
+        assert_eq!(
+            claim_status.unlocked_amount(75, start_ts, end_ts, &VestingSchedule::Linear),
+            Ok(0)
+        );
     }
 
     #[test]
@@ -206,12 +260,339 @@ mod tests {
                 locked_amount: 100,
                 unlocked_amount: 0,
                 locked_amount_withdrawn,
+                ..ClaimStatus::default()
+            };
+
+            assert_eq!(
+                claim_status.amount_withdrawable(curr_ts, 0, 100, &VestingSchedule::Linear),
+                Ok(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_cliff_nothing_unlocked_before_cliff() {
+        let claim_status = ClaimStatus {
+            locked_amount: 100,
+            ..Default::default()
+        };
+        let (start_ts, end_ts) = (0, 100);
+        let vesting_schedule = cliff(25, 2_500);
+
+        for curr_ts in [-10, 0, 10, 24] {
+            assert_eq!(
+                claim_status.unlocked_amount(curr_ts, start_ts, end_ts, &vesting_schedule),
+                Ok(0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_cliff_unlocking() {
+        let claim_status = ClaimStatus {
+            claimant: Pubkey::new_unique(),
+            locked_amount: 100,
+            unlocked_amount: 0,
+            locked_amount_withdrawn: 0,
+            ..ClaimStatus::default()
+        };
+        let (start_ts, end_ts) = (0, 100);
+        let vesting_schedule = cliff(20, 4_000);
+
+        // 40% at the cliff, the remaining 60 unlocks linearly over the 80 seconds after it
+        assert_eq!(
+            claim_status.unlocked_amount(20, start_ts, end_ts, &vesting_schedule),
+            Ok(40)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(40, start_ts, end_ts, &vesting_schedule),
+            Ok(55)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(60, start_ts, end_ts, &vesting_schedule),
+            Ok(70)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(100, start_ts, end_ts, &vesting_schedule),
+            Ok(100)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(150, start_ts, end_ts, &vesting_schedule),
+            Ok(100)
+        );
+    }
+
+    #[test]
+    fn test_cliff_full_unlock_at_cliff() {
+        let claim_status = ClaimStatus {
+            locked_amount: 100,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            claim_status.unlocked_amount(50, 0, 100, &cliff(50, MAX_BPS)),
+            Ok(100)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(49, 0, 100, &cliff(50, MAX_BPS)),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn test_cliff_zero_bps_matches_linear_after_cliff() {
+        let claim_status = ClaimStatus {
+            locked_amount: 100,
+            ..Default::default()
+        };
+
+        // no lump sum, linear from the cliff to the end
+        assert_eq!(
+            claim_status.unlocked_amount(50, 0, 100, &cliff(0, 0)),
+            claim_status.unlocked_amount(50, 0, 100, &VestingSchedule::Linear)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(75, 0, 100, &cliff(50, 0)),
+            Ok(50)
+        );
+    }
+
+    #[test]
+    fn test_cliff_unlocked_amount_no_truncation() {
+        let claim_status = ClaimStatus {
+            locked_amount: u64::MAX,
+            ..Default::default()
+        };
+        let (start_ts, end_ts, cliff_ts) = (0, i64::MAX, i64::MAX / 2);
+
+        let cliff_amount = u64::MAX as u128 * 3_333 / MAX_BPS as u128;
+        assert_eq!(
+            claim_status.unlocked_amount(cliff_ts, start_ts, end_ts, &cliff(cliff_ts, 3_333)),
+            Ok(cliff_amount as u64)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(end_ts, start_ts, end_ts, &cliff(cliff_ts, 3_333)),
+            Ok(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_cliff_partial_withdraw() {
+        for (curr_ts, expected, locked_amount_withdrawn) in [
+            (10, 0, 0),    // before cliff, nothing withdrawable
+            (20, 40, 0),   // cliff amount withdrawable
+            (20, 0, 40),   // cliff amount withdrawn, nothing withdrawable
+            (60, 30, 40),  // 7/10th unlocked, 4/10th withdrawn, 30 withdrawable
+            (100, 0, 100), // fully unlocked and withdrawn
+        ] {
+            let claim_status = ClaimStatus {
+                claimant: Pubkey::new_unique(),
+                locked_amount: 100,
+                unlocked_amount: 0,
+                locked_amount_withdrawn,
+                ..ClaimStatus::default()
             };
 
             assert_eq!(
-                claim_status.amount_withdrawable(curr_ts, 0, 100),
+                claim_status.amount_withdrawable(curr_ts, 0, 100, &cliff(20, 4_000)),
+                Ok(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_step_unlocking() {
+        let claim_status = ClaimStatus {
+            claimant: Pubkey::new_unique(),
+            locked_amount: 120,
+            unlocked_amount: 0,
+            locked_amount_withdrawn: 0,
+            ..ClaimStatus::default()
+        };
+        // 4 quarterly tranches of 30
+        let (start_ts, end_ts) = (0, 100);
+        let vesting_schedule = VestingSchedule::Step { period: 25 };
+
+        for (curr_ts, expected) in [
+            (-1, 0),
+            (0, 0),
+            (24, 0),
+            (25, 30),
+            (49, 30),
+            (50, 60),
+            (75, 90),
+            (99, 90),
+            (100, 120),
+            (150, 120),
+        ] {
+            assert_eq!(
+                claim_status.unlocked_amount(curr_ts, start_ts, end_ts, &vesting_schedule),
                 Ok(expected)
             );
         }
     }
+
+    #[test]
+    fn test_step_uneven_last_period() {
+        let claim_status = ClaimStatus {
+            locked_amount: 100,
+            ..Default::default()
+        };
+        // periods end at 40, 80 and the shorter last period at 100
+        let vesting_schedule = VestingSchedule::Step { period: 40 };
+
+        assert_eq!(
+            claim_status.unlocked_amount(40, 0, 100, &vesting_schedule),
+            Ok(33)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(80, 0, 100, &vesting_schedule),
+            Ok(66)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(99, 0, 100, &vesting_schedule),
+            Ok(66)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(100, 0, 100, &vesting_schedule),
+            Ok(100)
+        );
+    }
+
+    #[test]
+    fn test_step_unlocked_amount_no_truncation() {
+        let claim_status = ClaimStatus {
+            locked_amount: u64::MAX,
+            ..Default::default()
+        };
+        let (start_ts, end_ts) = (0, i64::MAX);
+        let vesting_schedule = VestingSchedule::Step {
+            period: i64::MAX / 4,
+        };
+
+        for curr_ts in [0, end_ts / 2, end_ts - 1, end_ts] {
+            let periods_elapsed = (curr_ts / (i64::MAX / 4)).min(5) as u128;
+            let expected_amount = if curr_ts == end_ts {
+                u64::MAX as u128
+            } else {
+                periods_elapsed * u64::MAX as u128 / 5
+            };
+
+            assert_eq!(
+                claim_status
+                    .unlocked_amount(curr_ts, start_ts, end_ts, &vesting_schedule)
+                    .unwrap() as u128,
+                expected_amount
+            );
+        }
+    }
+
+    #[test]
+    fn test_lockup() {
+        let claim_status = ClaimStatus::default();
+        assert_eq!(claim_status.lockup(100, 200), (100, 200));
+
+        let claim_status = ClaimStatus {
+            start_ts: 150,
+            end_ts: 300,
+            ..ClaimStatus::default()
+        };
+        assert_eq!(claim_status.lockup(100, 200), (150, 300));
+    }
+
+    #[test]
+    fn test_step_partial_withdraw() {
+        for (curr_ts, expected, locked_amount_withdrawn) in [
+            (10, 0, 0),    // nothing unlocked
+            (25, 25, 0),   // first tranche withdrawable
+            (30, 0, 25),   // first tranche withdrawn, nothing withdrawable
+            (60, 25, 25),  // second tranche withdrawable
+            (100, 50, 50), // last two tranches withdrawable
+        ] {
+            let claim_status = ClaimStatus {
+                claimant: Pubkey::new_unique(),
+                locked_amount: 100,
+                unlocked_amount: 0,
+                locked_amount_withdrawn,
+                ..ClaimStatus::default()
+            };
+
+            assert_eq!(
+                claim_status.amount_withdrawable(
+                    curr_ts,
+                    0,
+                    100,
+                    &VestingSchedule::Step { period: 25 }
+                ),
+                Ok(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_revoke() {
+        let mut claim_status = ClaimStatus {
+            claimant: Pubkey::new_unique(),
+            locked_amount: 100,
+            locked_amount_withdrawn: 20,
+            unlocked_amount: 10,
+            ..ClaimStatus::default()
+        };
+
+        assert_eq!(
+            claim_status.revoke(40, 0, 100, &VestingSchedule::Linear),
+            Ok(60)
+        );
+        assert_eq!(claim_status.locked_amount, 40);
+        assert_eq!(claim_status.lockup(0, 100), (0, 40));
+
+        // the frozen amount stays withdrawable, nothing vests after the revocation
+        for curr_ts in [40, 41, 100, 1_000] {
+            assert_eq!(
+                claim_status.amount_withdrawable(curr_ts, 0, 40, &VestingSchedule::Linear),
+                Ok(20)
+            );
+        }
+
+        // nothing left to revoke
+        assert_eq!(
+            claim_status.revoke(50, 0, 40, &VestingSchedule::Linear),
+            Ok(0)
+        );
+        assert_eq!(claim_status.locked_amount, 40);
+    }
+
+    #[test]
+    fn test_revoke_schedules() {
+        let claim_status = ClaimStatus {
+            claimant: Pubkey::new_unique(),
+            locked_amount: 1_000,
+            ..ClaimStatus::default()
+        };
+
+        for (vesting_schedule, revoked_amount) in [
+            (VestingSchedule::Step { period: 30 }, 750),
+            (cliff(20, MAX_BPS / 2), 313),
+            (cliff(60, MAX_BPS / 2), 1_000),
+        ] {
+            let mut revoked = ClaimStatus { ..claim_status };
+            assert_eq!(
+                revoked.revoke(50, 0, 100, &vesting_schedule),
+                Ok(revoked_amount)
+            );
+            let (start_ts, end_ts) = revoked.lockup(0, 100);
+            assert_eq!(
+                revoked.unlocked_amount(1_000, start_ts, end_ts, &vesting_schedule),
+                Ok(1_000 - revoked_amount)
+            );
+        }
+
+        // revoked before vesting starts
+        let mut revoked = ClaimStatus { ..claim_status };
+        assert_eq!(
+            revoked.revoke(50, 100, 200, &VestingSchedule::Linear),
+            Ok(1_000)
+        );
+        assert_eq!(revoked.lockup(100, 200), (49, 50));
+    }
 }
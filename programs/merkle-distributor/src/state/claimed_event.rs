@@ -3,17 +3,133 @@ use anchor_lang::{event, prelude::*};
 /// Emitted when a new claim is created.
 #[event]
 pub struct NewClaimEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
     /// User that claimed.
     pub claimant: Pubkey,
     /// Timestamp.
     pub timestamp: i64,
+    /// Token account the unlocked tokens were sent to.
+    pub to: Pubkey,
+    /// Unlocked amount in the leaf, sent to the `to` account.
+    pub amount_unlocked: u64,
+    /// Locked amount in the leaf, withdrawable as it unlocks.
+    pub amount_locked: u64,
+    /// Lockup start committed in the leaf. None if the distributor's lockup is used.
+    pub start_ts: Option<i64>,
+    /// Lockup end committed in the leaf. None if the distributor's lockup is used.
+    pub end_ts: Option<i64>,
+    /// Leaf index for distributors with bitmap claims. None otherwise.
+    pub index: Option<u64>,
+    /// Index of the claimed mint for multi-mint distributors. None otherwise.
+    pub mint_index: Option<u8>,
 }
 
 /// Emitted when tokens are claimed.
 #[event]
 pub struct ClaimedEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
     /// User that claimed.
     pub claimant: Pubkey,
     /// Amount of tokens to distribute.
     pub amount: u64,
+    /// Token account the tokens were sent to.
+    pub to: Pubkey,
+    /// Locked tokens left to withdraw after this claim.
+    pub locked_amount_remaining: u64,
+    /// Index of the claimed mint for multi-mint distributors. None otherwise.
+    pub mint_index: Option<u8>,
+}
+
+/// Emitted when a claimant is paid by a cumulative distributor.
+#[event]
+pub struct CumulativeClaimedEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// User that claimed.
+    pub claimant: Pubkey,
+    /// Token account the tokens were sent to.
+    pub to: Pubkey,
+    /// Amount of tokens paid by this claim.
+    pub amount: u64,
+    /// Cumulative amount paid to the claimant, including this claim.
+    pub cumulative_amount: u64,
+}
+
+/// Emitted when a claimant closes their [ClaimStatus](crate::state::claim_status::ClaimStatus).
+#[event]
+pub struct ClaimStatusClosedEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// User that closed the claim status.
+    pub claimant: Pubkey,
+    /// Whether the claimant was recorded in its
+    /// [ClosedClaims](crate::state::closed_claims::ClosedClaims) chunk so the leaf can't be
+    /// claimed again.
+    pub recorded: bool,
+}
+
+/// Emitted when a [ClosedClaims](crate::state::closed_claims::ClosedClaims) chunk is closed.
+#[event]
+pub struct ClosedClaimsClosedEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Index of the chunk.
+    pub chunk_index: u8,
+}
+
+/// Emitted when a claim pays the distributor's claim fee.
+#[event]
+pub struct ClaimFeePaidEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// User that claimed.
+    pub claimant: Pubkey,
+    /// Wallet collecting the claim fee.
+    pub fee_receiver: Pubkey,
+    /// Lamports paid by whoever sent the claim.
+    pub lamports: u64,
+    /// Tokens withheld from the unlocked amount of the claim.
+    pub amount: u64,
+}
+
+/// Emitted when the admin revokes the unvested locked tokens of a claimant.
+#[event]
+pub struct ClaimRevokedEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// User whose claim was revoked.
+    pub claimant: Pubkey,
+    /// Clawback token account the revoked tokens were sent to.
+    pub to: Pubkey,
+    /// Unvested locked tokens removed from the claim and from max_total_claim.
+    pub revoked_amount: u64,
+    /// Revoked tokens transferred out of the vault, less than revoked_amount if the vault was
+    /// underfunded.
+    pub amount: u64,
+    /// Locked tokens vested before the revocation, still withdrawable by the claimant.
+    pub locked_amount: u64,
+}
+
+/// Emitted when a claim is moved to a new claimant.
+#[event]
+pub struct ClaimTransferredEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Claimant the claim was moved from, recorded so its leaf can't be claimed again.
+    pub claimant: Pubkey,
+    /// Claimant the claim was moved to.
+    pub new_claimant: Pubkey,
+    /// Locked tokens left to withdraw by the new claimant.
+    pub locked_amount_remaining: u64,
+    /// Whether the admin recovered the claim, rather than the claimant moving it.
+    pub recovered: bool,
+}
+
+/// Emitted when a claim status created by an earlier version of the program is migrated.
+#[event]
+pub struct ClaimStatusMigratedEvent {
+    /// The [ClaimStatus](crate::state::claim_status::ClaimStatus).
+    pub claim_status: Pubkey,
 }
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+
+/// Claimants of a [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor) whose
+/// [ClaimStatus](crate::state::claim_status::ClaimStatus) was closed before clawback, so their leaf
+/// can never be claimed again. Claimants are spread over 256 chunks by the first byte of their
+/// pubkey. Each chunk stores its claimants sorted right after [ClosedClaims::LEN], one 32 byte
+/// pubkey each, and grows by one record per closed claim.
+#[account]
+#[derive(Default)]
+pub struct ClosedClaims {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// First byte of the pubkey of every claimant recorded in this chunk.
+    pub chunk_index: u8,
+}
+
+impl ClosedClaims {
+    pub const LEN: usize = 8 + std::mem::size_of::<ClosedClaims>();
+
+    /// Size of a single closed claim record.
+    pub const CLOSED_CLAIM_LEN: usize = 32;
+
+    /// Returns the index of the chunk recording `claimant`.
+    pub fn chunk_index_of(claimant: &Pubkey) -> u8 {
+        claimant.as_ref()[0]
+    }
+
+    /// Returns the sorted claimants recorded in the chunk account data.
+    pub fn closed_claims(data: &[u8]) -> &[u8] {
+        data.get(Self::LEN..).unwrap_or_default()
+    }
+
+    /// Number of closed claims recorded in the chunk account data.
+    pub fn num_closed_claims(data: &[u8]) -> usize {
+        Self::closed_claims(data).len() / Self::CLOSED_CLAIM_LEN
+    }
+
+    /// Returns true if `claimant` closed their claim, meaning their leaf has been consumed.
+    pub fn is_claim_closed(data: &[u8], claimant: &Pubkey) -> bool {
+        Self::search_closed_claims(data, claimant).is_ok()
+    }
+
+    /// Records `claimant` as closed, keeping the records sorted.
+    /// `data` must already have room for one more record at the end.
+    pub fn insert_closed_claim(data: &mut [u8], claimant: &Pubkey) {
+        let num_closed = Self::num_closed_claims(data) - 1;
+        let index = match Self::search_closed_claims(
+            &data[..data.len() - Self::CLOSED_CLAIM_LEN],
+            claimant,
+        ) {
+            Ok(index) | Err(index) => index,
+        };
+
+        let start = Self::LEN + index * Self::CLOSED_CLAIM_LEN;
+        let end = Self::LEN + num_closed * Self::CLOSED_CLAIM_LEN;
+        data.copy_within(start..end, start + Self::CLOSED_CLAIM_LEN);
+        data[start..start + Self::CLOSED_CLAIM_LEN].copy_from_slice(claimant.as_ref());
+    }
+
+    fn search_closed_claims(data: &[u8], claimant: &Pubkey) -> std::result::Result<usize, usize> {
+        let closed_claims = Self::closed_claims(data);
+        let num_closed = closed_claims.len() / Self::CLOSED_CLAIM_LEN;
+
+        let (mut low, mut high) = (0, num_closed);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let start = mid * Self::CLOSED_CLAIM_LEN;
+            match closed_claims[start..start + Self::CLOSED_CLAIM_LEN].cmp(claimant.as_ref()) {
+                std::cmp::Ordering::Less => low = mid + 1,
+                std::cmp::Ordering::Greater => high = mid,
+                std::cmp::Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insert(data: &mut Vec<u8>, claimant: &Pubkey) {
+        data.extend_from_slice(&[0; ClosedClaims::CLOSED_CLAIM_LEN]);
+        ClosedClaims::insert_closed_claim(data, claimant);
+    }
+
+    #[test]
+    fn test_no_closed_claims() {
+        let data = vec![0; ClosedClaims::LEN];
+        assert_eq!(ClosedClaims::num_closed_claims(&data), 0);
+        assert!(!ClosedClaims::is_claim_closed(&data, &Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn test_insert_closed_claims_sorted() {
+        let mut data = vec![0; ClosedClaims::LEN];
+        let claimants: Vec<Pubkey> = (0..10).map(|_| Pubkey::new_unique()).collect();
+        for claimant in claimants.iter().rev() {
+            insert(&mut data, claimant);
+        }
+
+        assert_eq!(ClosedClaims::num_closed_claims(&data), 10);
+        for claimant in &claimants {
+            assert!(ClosedClaims::is_claim_closed(&data, claimant));
+        }
+        assert!(!ClosedClaims::is_claim_closed(&data, &Pubkey::new_unique()));
+
+        let closed_claims = ClosedClaims::closed_claims(&data);
+        assert!(closed_claims
+            .chunks(ClosedClaims::CLOSED_CLAIM_LEN)
+            .zip(closed_claims.chunks(ClosedClaims::CLOSED_CLAIM_LEN).skip(1))
+            .all(|(a, b)| a < b));
+    }
+
+    #[test]
+    fn test_closed_claims_ignore_account_header() {
+        // the header must never be interpreted as a closed claim
+        let claimant = Pubkey::new_unique();
+        let mut data = vec![0; ClosedClaims::LEN];
+        data[8..40].copy_from_slice(claimant.as_ref());
+        assert!(!ClosedClaims::is_claim_closed(&data, &claimant));
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// Tracks how much a claimant has been paid by a cumulative
+/// [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+/// Leaves of a cumulative distributor hold the claimant's lifetime entitlement, every claim
+/// pays the difference with amount_claimed.
+#[account]
+#[derive(Default)]
+pub struct CumulativeClaimStatus {
+    /// Authority that claimed the tokens.
+    pub claimant: Pubkey,
+    /// Cumulative amount paid out to the claimant.
+    pub amount_claimed: u64,
+}
+
+impl CumulativeClaimStatus {
+    pub const LEN: usize = 8 + std::mem::size_of::<CumulativeClaimStatus>();
+}
@@ -0,0 +1,146 @@
+use anchor_lang::{event, prelude::*};
+
+use crate::state::{asset_kind::AssetKind, claim_fee::ClaimFee, vesting_schedule::VestingSchedule};
+
+/// Emitted when a [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor) is created.
+#[event]
+pub struct NewDistributorEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// The 256-bit merkle root.
+    pub root: [u8; 32],
+    /// Mint of the token to be distributed.
+    pub mint: Pubkey,
+    /// Token Address of the vault.
+    pub token_vault: Pubkey,
+    /// Maximum number of tokens that can ever be claimed.
+    pub max_total_claim: u64,
+    /// Maximum number of nodes.
+    pub max_num_nodes: u64,
+    /// Lockup time start (Unix Timestamp).
+    pub start_ts: i64,
+    /// Lockup time end (Unix Timestamp).
+    pub end_ts: i64,
+    /// How locked tokens unlock between start_ts and end_ts.
+    pub vesting_schedule: VestingSchedule,
+    /// Clawback start (Unix Timestamp).
+    pub clawback_start_ts: i64,
+    /// Clawback receiver.
+    pub clawback_receiver: Pubkey,
+    /// Admin wallet.
+    pub admin: Pubkey,
+    /// Whether anyone can claim on behalf of claimants.
+    pub allow_claim_on_behalf: bool,
+    /// The admin can update the root before this time (Unix Timestamp), or before the first claim.
+    pub root_lock_ts: i64,
+    /// Whether claims are tracked by leaf index in claim bitmaps.
+    pub bitmap_claims: bool,
+    /// Whether new claims are refused until the vault covers every remaining claim.
+    pub require_full_funding: bool,
+    /// Whether leaves hold each claimant's cumulative entitlement.
+    pub cumulative: bool,
+    /// New claims are refused from this time (Unix Timestamp), if any.
+    pub claim_deadline_ts: Option<i64>,
+    /// Signs the attestations claimants need to claim, if any.
+    pub attestation_signer: Option<Pubkey>,
+    /// Whether the distributor distributes tokens or native SOL.
+    pub asset_kind: AssetKind,
+    /// Whether leaves commit to the index of one of several mints.
+    pub multi_mint: bool,
+    /// Fee charged when a claim is opened.
+    pub claim_fee: ClaimFee,
+    /// Wallet collecting the claim fee.
+    pub fee_receiver: Pubkey,
+    /// Timelock on the admin recovering claims, in seconds, if the admin can recover claims.
+    pub claim_recovery_delay: Option<i64>,
+    /// Whether the admin can revoke the unvested locked tokens of claims.
+    pub revocable: bool,
+}
+
+/// Emitted when the admin registers a mint of a multi-mint distributor.
+#[event]
+pub struct DistributorMintAddedEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Index of the mint committed in the leaves.
+    pub mint_index: u8,
+    /// Mint of the token.
+    pub mint: Pubkey,
+    /// Token Address of the vault.
+    pub token_vault: Pubkey,
+    /// Maximum number of tokens of the mint that can ever be claimed.
+    pub max_total_claim: u64,
+}
+
+/// Emitted when the admin funds the token vault.
+#[event]
+pub struct FundedEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Amount of tokens the vault received, net of transfer fees.
+    pub amount: u64,
+    /// Total amount of tokens funded so far.
+    pub total_amount_funded: u64,
+}
+
+/// Emitted when the remaining tokens are clawed back.
+#[event]
+pub struct ClawbackEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Token account the tokens were sent to.
+    pub to: Pubkey,
+    /// Amount of tokens clawed back.
+    pub amount: u64,
+}
+
+/// Emitted when the admin claws back the tokens in the vault beyond what can still be claimed.
+#[event]
+pub struct SurplusClawbackEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Token account the tokens were sent to.
+    pub to: Pubkey,
+    /// Amount of tokens clawed back.
+    pub amount: u64,
+}
+
+/// Emitted when the admin moves the lockup or clawback timestamps.
+#[event]
+pub struct TimestampsUpdatedEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Lockup time start (Unix Timestamp).
+    pub start_ts: i64,
+    /// Lockup time end (Unix Timestamp).
+    pub end_ts: i64,
+    /// Clawback start (Unix Timestamp).
+    pub clawback_start_ts: i64,
+}
+
+/// Emitted when the admin pauses or unpauses claims.
+#[event]
+pub struct PausedEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Whether claims are paused.
+    pub paused: bool,
+}
+
+/// Emitted when the distributor and its token vault are closed.
+#[event]
+pub struct DistributorClosedEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Account the rent was sent to.
+    pub receiver: Pubkey,
+}
+
+/// Emitted when a distributor created by an earlier version of the program is migrated.
+#[event]
+pub struct DistributorMigratedEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+}
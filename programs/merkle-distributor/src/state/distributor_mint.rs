@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+/// A mint of a multi-mint [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor)
+/// and the vault holding its tokens. Leaves of the distributor commit to its mint_index.
+#[account]
+#[derive(Default)]
+pub struct DistributorMint {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Index of the mint committed in the leaves.
+    pub mint_index: u8,
+    /// Bump seed.
+    pub bump: u8,
+    /// [Mint] of the token.
+    pub mint: Pubkey,
+    /// Associated token account of the distributor for the mint.
+    pub token_vault: Pubkey,
+    /// Maximum number of tokens of the mint that can ever be claimed.
+    pub max_total_claim: u64,
+    /// Total amount of tokens of the mint that have been claimed.
+    pub total_amount_claimed: u64,
+    /// Whether the vault has been clawed back.
+    pub clawed_back: bool,
+}
+
+impl DistributorMint {
+    pub const LEN: usize = 8 + std::mem::size_of::<DistributorMint>();
+}
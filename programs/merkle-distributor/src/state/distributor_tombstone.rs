@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Left at the address of a [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor)
+/// closed by [close_distributor](crate::instructions::handle_close_distributor).
+/// [new_distributor](crate::instructions::handle_new_distributor) can't create a distributor at an
+/// address that is still in use, so the old [ClaimStatus](crate::state::claim_status::ClaimStatus)
+/// accounts can never claim from a new distributor at the same address.
+#[account]
+#[derive(Default)]
+pub struct DistributorTombstone {
+    /// Admin of the closed distributor, can still close its claim bitmaps and closed claims chunks.
+    pub admin: Pubkey,
+}
+
+impl DistributorTombstone {
+    pub const LEN: usize = 8 + std::mem::size_of::<DistributorTombstone>();
+}
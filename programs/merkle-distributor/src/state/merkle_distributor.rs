@@ -3,7 +3,18 @@ use anchor_lang::{
     prelude::{Pubkey, *},
 };
 
+use crate::{
+    error::ErrorCode,
+    state::{asset_kind::AssetKind, claim_fee::ClaimFee, vesting_schedule::VestingSchedule},
+};
+
+const SECONDS_PER_HOUR: i64 = 3600; // 60 minutes * 60 seconds
+const HOURS_PER_DAY: i64 = 24;
+const SECONDS_PER_DAY: i64 = SECONDS_PER_HOUR * HOURS_PER_DAY; // 24 hours * 3600 seconds
+
 /// State for the account which distributes tokens.
+/// New fields are only appended, and must default to zero bytes, so accounts created before can be
+/// grown with [migrate_distributor](crate::instructions::handle_migrate_distributor).
 #[account]
 #[derive(Default, Debug)]
 pub struct MerkleDistributor {
@@ -13,11 +24,13 @@ pub struct MerkleDistributor {
     pub version: u64,
     /// The 256-bit merkle root.
     pub root: [u8; 32],
-    /// [Mint] of the token to be distributed.
+    /// [Mint] of the token to be distributed, [Pubkey::default] for native SOL.
     pub mint: Pubkey,
-    /// Token Address of the vault
+    /// Token Address of the vault, the [SolVault](crate::state::sol_vault::SolVault) for native SOL
     pub token_vault: Pubkey,
     /// Maximum number of tokens that can ever be claimed from this [MerkleDistributor].
+    /// Multi-mint distributors cap each mint in its
+    /// [DistributorMint](crate::state::distributor_mint::DistributorMint) instead.
     pub max_total_claim: u64,
     /// Maximum number of nodes in [MerkleDistributor].
     pub max_num_nodes: u64,
@@ -37,8 +50,326 @@ pub struct MerkleDistributor {
     pub admin: Pubkey,
     /// Whether or not the distributor has been clawed back
     pub clawed_back: bool,
+    /// How locked tokens unlock between start_ts and end_ts
+    pub vesting_schedule: VestingSchedule,
+    /// Whether anyone can claim on behalf of a claimant, sending the tokens to the claimant's
+    /// associated token account
+    pub allow_claim_on_behalf: bool,
+    /// The root can be updated by the admin before this time (Unix Timestamp),
+    /// or at any time as long as no nodes have been claimed
+    pub root_lock_ts: i64,
+    /// Whether claims are paused by the admin. Does not affect clawback
+    pub paused: bool,
+    /// Admin proposed by the current admin, becomes admin once it accepts. Default if none
+    pub pending_admin: Pubkey,
+    /// Clawback receiver proposed by the admin, becomes clawback receiver once its owner
+    /// accepts. Default if none
+    pub pending_clawback_receiver: Pubkey,
+    /// Whether claims are tracked by leaf index in [ClaimBitmap](crate::state::claim_bitmap::ClaimBitmap)
+    /// accounts instead of a [ClaimStatus](crate::state::claim_status::ClaimStatus) per claimant.
+    /// Only supports leaves without locked tokens
+    pub bitmap_claims: bool,
+    /// Total amount of tokens deposited into the vault with
+    /// [fund_distributor](crate::instructions::handle_fund_distributor), net of transfer fees.
+    /// Does not include tokens transferred to the vault directly
+    pub total_amount_funded: u64,
+    /// Whether new claims are refused until the vault holds every token that can still be claimed
+    pub require_full_funding: bool,
+    /// Whether leaves hold each claimant's cumulative entitlement, tracked in
+    /// [CumulativeClaimStatus](crate::state::cumulative_claim_status::CumulativeClaimStatus) accounts.
+    /// The admin can update the root at any time to grow entitlements
+    pub cumulative: bool,
+    /// New claims are refused from this time (Unix Timestamp), none if claims stay open until clawback.
+    /// Claims opened before it keep vesting until clawback
+    pub claim_deadline_ts: Option<i64>,
+    /// Signs the attestations claimants need to claim, none if claims don't need one.
+    /// See [verify_attestation](crate::utils::verify_attestation)
+    pub attestation_signer: Option<Pubkey>,
+    /// Whether the distributor distributes tokens of its mint or native SOL
+    pub asset_kind: AssetKind,
+    /// Whether leaves commit to the index of one of several mints, each registered with its vault in a
+    /// [DistributorMint](crate::state::distributor_mint::DistributorMint).
+    /// Amounts and totals of the distributor add up the amounts of every mint
+    pub multi_mint: bool,
+    /// Number of [DistributorMint](crate::state::distributor_mint::DistributorMint) accounts registered,
+    /// the next one takes this index
+    pub num_mints: u8,
+    /// Fee charged when a claim is opened
+    pub claim_fee: ClaimFee,
+    /// Wallet collecting the claim fee, lamport fees are sent to it and token fees to a token
+    /// account it owns. Default if there is no claim fee
+    pub fee_receiver: Pubkey,
+    /// Seconds the admin has to wait between proposing and executing the recovery of a claim to a
+    /// new claimant, none if the admin can't recover claims.
+    /// See [propose_claim_recovery](crate::instructions::handle_propose_claim_recovery)
+    pub claim_recovery_delay: Option<i64>,
+    /// Whether the admin can revoke the unvested locked tokens of claims.
+    /// See [revoke](crate::instructions::handle_revoke)
+    pub revocable: bool,
 }
 
 impl MerkleDistributor {
-    pub const LEN: usize = 8 + std::mem::size_of::<MerkleDistributor>();
+    /// Bytes reserved after the fields, so fields appended later fit in accounts created now.
+    pub const RESERVED_LEN: usize = 128;
+
+    pub const LEN: usize = 8 + std::mem::size_of::<MerkleDistributor>() + Self::RESERVED_LEN;
+
+    /// Checks the lockup and clawback timestamps are consistent with each other and the vesting schedule.
+    /// CHECK:
+    ///     1. The start timestamp is before the end timestamp
+    ///     2. The clawback timestamp is after the end timestamp
+    ///     3. The clawback start is at least one day after end timestamp
+    ///     4. The vesting schedule fits between the start and end timestamps
+    #[allow(clippy::result_large_err)]
+    pub fn validate_timestamps(
+        start_ts: i64,
+        end_ts: i64,
+        clawback_start_ts: i64,
+        vesting_schedule: &VestingSchedule,
+    ) -> Result<()> {
+        require!(start_ts < end_ts, ErrorCode::StartTimestampAfterEnd);
+
+        require!(clawback_start_ts > end_ts, ErrorCode::ClawbackDuringVesting);
+
+        // Ensure clawback_start_ts is at least one day after end_ts
+        require!(
+            clawback_start_ts
+                >= end_ts
+                    .checked_add(SECONDS_PER_DAY)
+                    .ok_or(ErrorCode::ArithmeticError)?,
+            ErrorCode::InsufficientClawbackDelay
+        );
+
+        vesting_schedule.validate(start_ts, end_ts)
+    }
+
+    /// Moves the lockup and clawback timestamps, keeping the invariants of
+    /// [validate_timestamps](Self::validate_timestamps).
+    /// CHECK:
+    ///     1. The clawback start is not moved earlier
+    ///     2. The lockup is only moved before vesting starts, and to the future
+    ///     3. A moved clawback start is in the future
+    ///     4. A [VestingSchedule::CliffLinear] cliff still lies within the new lockup
+    #[allow(clippy::result_large_err)]
+    pub fn update_timestamps(
+        &mut self,
+        curr_ts: i64,
+        start_ts: i64,
+        end_ts: i64,
+        clawback_start_ts: i64,
+    ) -> Result<()> {
+        require!(
+            clawback_start_ts >= self.clawback_start_ts,
+            ErrorCode::ClawbackStartNotExtended
+        );
+
+        if start_ts != self.start_ts || end_ts != self.end_ts {
+            require!(curr_ts < self.start_ts, ErrorCode::VestingAlreadyStarted);
+            require!(
+                start_ts > curr_ts && end_ts > curr_ts,
+                ErrorCode::TimestampsNotInFuture
+            );
+        }
+
+        if clawback_start_ts != self.clawback_start_ts {
+            require!(
+                clawback_start_ts > curr_ts,
+                ErrorCode::TimestampsNotInFuture
+            );
+        }
+
+        Self::validate_timestamps(start_ts, end_ts, clawback_start_ts, &self.vesting_schedule)?;
+
+        self.start_ts = start_ts;
+        self.end_ts = end_ts;
+        self.clawback_start_ts = clawback_start_ts;
+
+        Ok(())
+    }
+
+    /// Returns true if the claim deadline has passed and new claims are refused.
+    pub fn claim_deadline_passed(&self, curr_ts: i64) -> bool {
+        matches!(self.claim_deadline_ts, Some(claim_deadline_ts) if curr_ts >= claim_deadline_ts)
+    }
+
+    /// Amount of tokens that can still be claimed.
+    pub fn remaining_claim(&self) -> u64 {
+        self.max_total_claim
+            .saturating_sub(self.total_amount_claimed)
+    }
+
+    /// Tokens in a vault holding `vault_amount` beyond what can still be claimed.
+    pub fn surplus(&self, vault_amount: u64) -> u64 {
+        vault_amount.saturating_sub(self.remaining_claim())
+    }
+
+    /// Returns true if a vault holding `vault_amount` tokens covers every remaining claim.
+    pub fn is_fully_funded(&self, vault_amount: u64) -> bool {
+        vault_amount >= self.remaining_claim()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_distributor() -> MerkleDistributor {
+        MerkleDistributor {
+            start_ts: 1_000,
+            end_ts: 2_000,
+            clawback_start_ts: 2_000 + SECONDS_PER_DAY,
+            ..MerkleDistributor::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_timestamps() {
+        let linear = VestingSchedule::Linear;
+        assert!(
+            MerkleDistributor::validate_timestamps(0, 100, 100 + SECONDS_PER_DAY, &linear).is_ok()
+        );
+        assert_eq!(
+            MerkleDistributor::validate_timestamps(100, 100, 100 + SECONDS_PER_DAY, &linear)
+                .unwrap_err(),
+            ErrorCode::StartTimestampAfterEnd.into()
+        );
+        assert_eq!(
+            MerkleDistributor::validate_timestamps(0, 100, 100, &linear).unwrap_err(),
+            ErrorCode::ClawbackDuringVesting.into()
+        );
+        assert_eq!(
+            MerkleDistributor::validate_timestamps(0, 100, 99 + SECONDS_PER_DAY, &linear)
+                .unwrap_err(),
+            ErrorCode::InsufficientClawbackDelay.into()
+        );
+        assert_eq!(
+            MerkleDistributor::validate_timestamps(
+                0,
+                100,
+                100 + SECONDS_PER_DAY,
+                &VestingSchedule::Step { period: 101 }
+            )
+            .unwrap_err(),
+            ErrorCode::InvalidVestingSchedule.into()
+        );
+    }
+
+    #[test]
+    fn test_update_timestamps_extends_clawback() {
+        let mut distributor = new_distributor();
+        let clawback_start_ts = distributor.clawback_start_ts;
+
+        // after vesting ended, the clawback can still be pushed later
+        distributor
+            .update_timestamps(3_000, 1_000, 2_000, clawback_start_ts + 1)
+            .unwrap();
+        assert_eq!(distributor.clawback_start_ts, clawback_start_ts + 1);
+
+        assert_eq!(
+            distributor
+                .update_timestamps(3_000, 1_000, 2_000, clawback_start_ts)
+                .unwrap_err(),
+            ErrorCode::ClawbackStartNotExtended.into()
+        );
+    }
+
+    #[test]
+    fn test_update_timestamps_shifts_lockup_before_vesting() {
+        let mut distributor = new_distributor();
+
+        distributor
+            .update_timestamps(500, 1_500, 2_500, 2_500 + SECONDS_PER_DAY)
+            .unwrap();
+        assert_eq!(distributor.start_ts, 1_500);
+        assert_eq!(distributor.end_ts, 2_500);
+        assert_eq!(distributor.clawback_start_ts, 2_500 + SECONDS_PER_DAY);
+
+        // the lockup can't move into the past
+        assert_eq!(
+            new_distributor()
+                .update_timestamps(500, 400, 2_000, 2_000 + SECONDS_PER_DAY)
+                .unwrap_err(),
+            ErrorCode::TimestampsNotInFuture.into()
+        );
+
+        // the clawback delay must still hold after moving the end
+        assert_eq!(
+            new_distributor()
+                .update_timestamps(500, 1_000, 2_001, 2_000 + SECONDS_PER_DAY)
+                .unwrap_err(),
+            ErrorCode::InsufficientClawbackDelay.into()
+        );
+    }
+
+    #[test]
+    fn test_update_timestamps_keeps_cliff_within_lockup() {
+        let mut distributor = MerkleDistributor {
+            vesting_schedule: VestingSchedule::CliffLinear {
+                cliff_offset: 200,
+                cliff_unlock_bps: 2_500,
+            },
+            ..new_distributor()
+        };
+
+        distributor
+            .update_timestamps(500, 1_500, 2_500, 2_500 + SECONDS_PER_DAY)
+            .unwrap();
+
+        // a shorter lockup must still end after the cliff
+        assert_eq!(
+            distributor
+                .update_timestamps(500, 1_500, 1_700, 2_500 + SECONDS_PER_DAY)
+                .unwrap_err(),
+            ErrorCode::InvalidVestingSchedule.into()
+        );
+    }
+
+    #[test]
+    fn test_update_timestamps_after_vesting_started() {
+        let mut distributor = new_distributor();
+
+        assert_eq!(
+            distributor
+                .update_timestamps(1_000, 1_500, 2_000, 2_000 + SECONDS_PER_DAY)
+                .unwrap_err(),
+            ErrorCode::VestingAlreadyStarted.into()
+        );
+        assert_eq!(distributor.start_ts, 1_000);
+    }
+
+    #[test]
+    fn test_claim_deadline_passed() {
+        let mut distributor = new_distributor();
+        assert!(!distributor.claim_deadline_passed(i64::MAX));
+
+        distributor.claim_deadline_ts = Some(1_500);
+        assert!(!distributor.claim_deadline_passed(1_499));
+        assert!(distributor.claim_deadline_passed(1_500));
+        assert!(distributor.claim_deadline_passed(1_501));
+    }
+
+    #[test]
+    fn test_is_fully_funded() {
+        let distributor = MerkleDistributor {
+            max_total_claim: 100,
+            total_amount_claimed: 40,
+            ..MerkleDistributor::default()
+        };
+        assert_eq!(distributor.remaining_claim(), 60);
+        assert!(distributor.is_fully_funded(60));
+        assert!(distributor.is_fully_funded(61));
+        assert!(!distributor.is_fully_funded(59));
+    }
+
+    #[test]
+    fn test_surplus() {
+        let distributor = MerkleDistributor {
+            max_total_claim: 100,
+            total_amount_claimed: 40,
+            ..MerkleDistributor::default()
+        };
+        assert_eq!(distributor.surplus(0), 0);
+        assert_eq!(distributor.surplus(60), 0);
+        assert_eq!(distributor.surplus(75), 15);
+    }
 }
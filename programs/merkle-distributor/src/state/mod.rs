@@ -1,3 +1,17 @@
+pub mod admin_event;
+pub mod asset_kind;
+pub mod claim_bitmap;
+pub mod claim_bitmap_event;
+pub mod claim_fee;
+pub mod claim_recovery;
 pub mod claim_status;
 pub mod claimed_event;
+pub mod closed_claims;
+pub mod cumulative_claim_status;
+pub mod distributor_event;
+pub mod distributor_mint;
+pub mod distributor_tombstone;
 pub mod merkle_distributor;
+pub mod root_updated_event;
+pub mod sol_vault;
+pub mod vesting_schedule;
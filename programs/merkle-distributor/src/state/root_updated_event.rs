@@ -0,0 +1,16 @@
+use anchor_lang::{event, prelude::*};
+
+/// Emitted when the admin replaces the merkle root.
+#[event]
+pub struct RootUpdatedEvent {
+    /// The [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+    pub distributor: Pubkey,
+    /// Root before the update.
+    pub old_root: [u8; 32],
+    /// Root after the update.
+    pub new_root: [u8; 32],
+    /// New maximum number of tokens that can be claimed.
+    pub max_total_claim: u64,
+    /// New maximum number of nodes.
+    pub max_num_nodes: u64,
+}
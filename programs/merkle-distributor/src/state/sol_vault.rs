@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+/// Program owned vault holding the lamports of a native SOL
+/// [MerkleDistributor](crate::state::merkle_distributor::MerkleDistributor).
+/// Everything above its rent exempt minimum can be claimed.
+#[account]
+#[derive(Default)]
+pub struct SolVault {}
+
+impl SolVault {
+    pub const LEN: usize = 8 + std::mem::size_of::<SolVault>();
+
+    /// Lamports in `vault` above its rent exempt minimum.
+    #[allow(clippy::result_large_err)]
+    pub fn balance(vault: &AccountInfo) -> Result<u64> {
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(vault.data_len());
+        Ok(vault.lamports().saturating_sub(rent_exempt_minimum))
+    }
+
+    /// Moves `amount` lamports out of `vault` into `to`. The vault is owned by the program, so
+    /// its lamports are debited directly instead of through the system program.
+    #[allow(clippy::result_large_err)]
+    pub fn transfer(vault: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+        require!(
+            amount <= Self::balance(vault)?,
+            ErrorCode::InsufficientVaultBalance
+        );
+        **vault.try_borrow_mut_lamports()? -= amount;
+        **to.try_borrow_mut_lamports()? = to
+            .lamports()
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+use anchor_lang::prelude::*;
+
+use crate::error::{ErrorCode, ErrorCode::ArithmeticError};
+
+/// Denominator for basis point fractions.
+pub const MAX_BPS: u16 = 10_000;
+
+/// How locked tokens unlock between the start and end of the lockup.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VestingSchedule {
+    /// Tokens unlock continuously from start_ts to end_ts.
+    #[default]
+    Linear,
+    /// Tokens unlock in equal tranches at the end of every period after start_ts.
+    /// If the lockup is not a multiple of the period, the last tranche unlocks at end_ts.
+    Step {
+        /// Length of a period in seconds
+        period: i64,
+    },
+    /// Nothing unlocks before the cliff, cliff_unlock_bps of the locked tokens unlock at the cliff
+    /// and the remainder unlocks continuously from the cliff to end_ts.
+    /// The cliff is relative to start_ts, so it applies to every lockup vesting on this schedule.
+    CliffLinear {
+        /// Cliff, in seconds after start_ts
+        cliff_offset: i64,
+        /// Portion of the locked tokens unlocked at the cliff, in basis points
+        cliff_unlock_bps: u16,
+    },
+}
+
+impl VestingSchedule {
+    /// Checks the schedule is consistent with the lockup start and end.
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self, start_ts: i64, end_ts: i64) -> Result<()> {
+        match *self {
+            VestingSchedule::Linear => {}
+            VestingSchedule::Step { period } => {
+                let total_unlock_time = end_ts.checked_sub(start_ts).ok_or(ArithmeticError)?;
+                require!(
+                    period > 0 && period <= total_unlock_time,
+                    ErrorCode::InvalidVestingSchedule
+                );
+            }
+            VestingSchedule::CliffLinear {
+                cliff_offset,
+                cliff_unlock_bps,
+            } => {
+                let total_unlock_time = end_ts.checked_sub(start_ts).ok_or(ArithmeticError)?;
+                require!(
+                    cliff_offset >= 0
+                        && cliff_offset < total_unlock_time
+                        && cliff_unlock_bps <= MAX_BPS,
+                    ErrorCode::InvalidVestingSchedule
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Amount of `locked_amount` unlocked at curr_ts for a lockup from start_ts to end_ts.
+    #[allow(clippy::result_large_err)]
+    pub fn unlocked_amount(
+        &self,
+        locked_amount: u64,
+        curr_ts: i64,
+        start_ts: i64,
+        end_ts: i64,
+    ) -> Result<u64> {
+        match *self {
+            VestingSchedule::Linear => {
+                linear_unlocked_amount(locked_amount, curr_ts, start_ts, end_ts)
+            }
+            VestingSchedule::Step { period } => {
+                step_unlocked_amount(locked_amount, curr_ts, start_ts, end_ts, period)
+            }
+            VestingSchedule::CliffLinear {
+                cliff_offset,
+                cliff_unlock_bps,
+            } => {
+                let cliff_ts = start_ts.checked_add(cliff_offset).ok_or(ArithmeticError)?;
+                if curr_ts < cliff_ts {
+                    return Ok(0);
+                }
+
+                // cliff_unlock_bps <= MAX_BPS, so the result always fits in a u64
+                let cliff_amount = ((locked_amount as u128)
+                    .checked_mul(cliff_unlock_bps as u128)
+                    .ok_or(ArithmeticError)?)
+                .checked_div(MAX_BPS as u128)
+                .ok_or(ArithmeticError)? as u64;
+                let remaining_amount = locked_amount
+                    .checked_sub(cliff_amount)
+                    .ok_or(ArithmeticError)?;
+
+                let amount = cliff_amount
+                    .checked_add(linear_unlocked_amount(
+                        remaining_amount,
+                        curr_ts,
+                        cliff_ts,
+                        end_ts,
+                    )?)
+                    .ok_or(ArithmeticError)?;
+
+                Ok(amount)
+            }
+        }
+    }
+}
+
+/// Amount unlocked when linearly unlocking `locked_amount` from start_ts to end_ts
+/// Equal to (time_into_unlock / total_unlock_time) * locked_amount
+/// Multiplication safety:
+///    The maximum possible product is (2^64 -1) * (2^64 -1) = 2^128 - 2^65 + 1
+///    which is less than 2^128 - 1 (the maximum value of a u128), meaning that
+///    the multiplication will never overflow
+/// Truncation from u128 to u64:
+///     Casting a u128 to a u64 will truncate the 64 higher order bits, which rounds
+///     down from the user.
+///     in order to avoid truncation, the final result must be less than 2^64 - 1.
+///     Rewriting the terms, we get (time_into_unlock * locked_amount) / total_unlock_time < 2^64 - 1
+///     We know time_into_unlock and total_unlock_time are both approximately the same size, so we can
+///     approximate the above as:
+///         b < 2^64 -1.
+///     Since b is a i64, this is always true, so no truncation can occur
+#[allow(clippy::result_large_err)]
+fn linear_unlocked_amount(
+    locked_amount: u64,
+    curr_ts: i64,
+    start_ts: i64,
+    end_ts: i64,
+) -> Result<u64> {
+    if curr_ts >= start_ts {
+        if curr_ts >= end_ts {
+            Ok(locked_amount)
+        } else {
+            let time_into_unlock = curr_ts.checked_sub(start_ts).ok_or(ArithmeticError)?;
+            let total_unlock_time = end_ts.checked_sub(start_ts).ok_or(ArithmeticError)?;
+
+            let amount = ((time_into_unlock as u128)
+                .checked_mul(locked_amount as u128)
+                .ok_or(ArithmeticError)?)
+            .checked_div(total_unlock_time as u128)
+            .ok_or(ArithmeticError)? as u64;
+
+            Ok(amount)
+        }
+    } else {
+        Ok(0)
+    }
+}
+
+/// Amount unlocked when unlocking `locked_amount` in tranches every `period` seconds from start_ts to end_ts
+/// Equal to (periods_elapsed / total_periods) * locked_amount
+/// periods_elapsed < total_periods before end_ts, so the same overflow and truncation reasoning as
+/// [linear_unlocked_amount] applies.
+#[allow(clippy::result_large_err)]
+fn step_unlocked_amount(
+    locked_amount: u64,
+    curr_ts: i64,
+    start_ts: i64,
+    end_ts: i64,
+    period: i64,
+) -> Result<u64> {
+    if curr_ts >= start_ts {
+        if curr_ts >= end_ts {
+            Ok(locked_amount)
+        } else {
+            let time_into_unlock = curr_ts.checked_sub(start_ts).ok_or(ArithmeticError)?;
+            let total_unlock_time = end_ts.checked_sub(start_ts).ok_or(ArithmeticError)?;
+
+            let periods_elapsed = time_into_unlock
+                .checked_div(period)
+                .ok_or(ArithmeticError)?;
+            // round up, the last period may be shorter than the others
+            let mut total_periods = total_unlock_time
+                .checked_div(period)
+                .ok_or(ArithmeticError)?;
+            if total_unlock_time
+                .checked_rem(period)
+                .ok_or(ArithmeticError)?
+                != 0
+            {
+                total_periods = total_periods.checked_add(1).ok_or(ArithmeticError)?;
+            }
+
+            let amount = ((periods_elapsed as u128)
+                .checked_mul(locked_amount as u128)
+                .ok_or(ArithmeticError)?)
+            .checked_div(total_periods as u128)
+            .ok_or(ArithmeticError)? as u64;
+
+            Ok(amount)
+        }
+    } else {
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_step() {
+        assert!(VestingSchedule::Step { period: 10 }
+            .validate(0, 100)
+            .is_ok());
+        assert!(VestingSchedule::Step { period: 100 }
+            .validate(0, 100)
+            .is_ok());
+        assert!(VestingSchedule::Step { period: 0 }
+            .validate(0, 100)
+            .is_err());
+        assert!(VestingSchedule::Step { period: -10 }
+            .validate(0, 100)
+            .is_err());
+        assert!(VestingSchedule::Step { period: 101 }
+            .validate(0, 100)
+            .is_err());
+    }
+
+    #[test]
+    fn test_validate_cliff_linear() {
+        let cliff = |cliff_offset, cliff_unlock_bps| VestingSchedule::CliffLinear {
+            cliff_offset,
+            cliff_unlock_bps,
+        };
+        assert!(cliff(0, 0).validate(0, 100).is_ok());
+        assert!(cliff(99, MAX_BPS).validate(0, 100).is_ok());
+        assert!(cliff(99, MAX_BPS).validate(1_000, 1_100).is_ok());
+        assert!(cliff(-1, 0).validate(0, 100).is_err());
+        assert!(cliff(100, 0).validate(0, 100).is_err());
+        assert!(cliff(100, 0).validate(1_000, 1_100).is_err());
+        assert!(cliff(50, MAX_BPS + 1).validate(0, 100).is_err());
+    }
+}
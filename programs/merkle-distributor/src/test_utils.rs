@@ -0,0 +1,234 @@
+//! Runs instructions through the program entrypoint in unit tests, without a validator.
+//! Accounts are serialized in memory like the runtime does, so the program can realloc them, and the
+//! clock is stubbed per test thread. CPIs are not executed, they succeed without effect and are
+//! recorded, so only the checks before a CPI, the instructions it was made with and the state changes
+//! made by the program itself can be tested here.
+
+use std::{
+    cell::{Cell, RefCell},
+    sync::Once,
+};
+
+use anchor_lang::{
+    solana_program::{
+        account_info::AccountInfo,
+        clock::Clock,
+        entrypoint::{deserialize, ProgramResult, MAX_PERMITTED_DATA_INCREASE, SUCCESS},
+        instruction::Instruction,
+        program_pack::Pack,
+        program_stubs::{set_syscall_stubs, SyscallStubs},
+        rent::Rent,
+    },
+    AccountSerialize, InstructionData, ToAccountMetas,
+};
+use anchor_spl::{
+    token,
+    token_2022::spl_token_2022::{
+        instruction::TokenInstruction,
+        state::{Account as TokenAccount, AccountState, Mint},
+    },
+};
+use solana_program::{pubkey::Pubkey, system_program};
+
+thread_local! {
+    static UNIX_TIMESTAMP: Cell<i64> = const { Cell::new(0) };
+    static INVOKED_INSTRUCTIONS: RefCell<Vec<Instruction>> = const { RefCell::new(Vec::new()) };
+}
+
+static SET_SYSCALL_STUBS: Once = Once::new();
+
+struct TestSyscallStubs;
+
+impl SyscallStubs for TestSyscallStubs {
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        _account_infos: &[AccountInfo],
+        _signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        INVOKED_INSTRUCTIONS.with(|ixs| ixs.borrow_mut().push(instruction.clone()));
+        Ok(())
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            unix_timestamp: UNIX_TIMESTAMP.with(Cell::get),
+            ..Clock::default()
+        };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        SUCCESS
+    }
+}
+
+fn init_syscall_stubs() {
+    SET_SYSCALL_STUBS.call_once(|| {
+        set_syscall_stubs(Box::new(TestSyscallStubs));
+    });
+}
+
+/// Sets the unix timestamp returned by [Clock::get] on the current test thread.
+pub fn set_unix_timestamp(unix_timestamp: i64) {
+    init_syscall_stubs();
+    UNIX_TIMESTAMP.with(|ts| ts.set(unix_timestamp));
+}
+
+/// Returns the CPIs made by the last [process_instruction] on the current test thread.
+pub fn invoked_instructions() -> Vec<Instruction> {
+    INVOKED_INSTRUCTIONS.with(|ixs| ixs.borrow().clone())
+}
+
+/// Returns the amounts of the token transfers made by the last [process_instruction] on the current
+/// test thread.
+pub fn transferred_amounts() -> Vec<u64> {
+    invoked_instructions()
+        .iter()
+        .filter_map(|ix| match TokenInstruction::unpack(&ix.data) {
+            Ok(TokenInstruction::TransferChecked { amount, .. }) => Some(amount),
+            _ => None,
+        })
+        .collect()
+}
+
+/// An account passed to [process_instruction].
+pub struct TestAccount {
+    pub key: Pubkey,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: Pubkey,
+    pub executable: bool,
+}
+
+impl TestAccount {
+    /// A system account without data, like a wallet.
+    pub fn wallet(key: Pubkey) -> Self {
+        Self {
+            key,
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: system_program::ID,
+            executable: false,
+        }
+    }
+
+    /// An executable program account, like the token program.
+    pub fn executable(key: Pubkey) -> Self {
+        Self {
+            key,
+            lamports: 1,
+            data: vec![],
+            owner: Pubkey::default(),
+            executable: true,
+        }
+    }
+
+    /// An SPL Token mint.
+    pub fn mint(key: Pubkey) -> Self {
+        let mint = Mint {
+            decimals: 6,
+            is_initialized: true,
+            ..Mint::default()
+        };
+        let mut data = vec![0; Mint::LEN];
+        Mint::pack(mint, &mut data).unwrap();
+        Self::token_program_owned(key, data)
+    }
+
+    /// An SPL Token account of `owner` for `mint` holding `amount` tokens.
+    pub fn token_account(key: Pubkey, mint: Pubkey, owner: Pubkey, amount: u64) -> Self {
+        let account = TokenAccount {
+            mint,
+            owner,
+            amount,
+            state: AccountState::Initialized,
+            ..TokenAccount::default()
+        };
+        let mut data = vec![0; TokenAccount::LEN];
+        TokenAccount::pack(account, &mut data).unwrap();
+        Self::token_program_owned(key, data)
+    }
+
+    fn token_program_owned(key: Pubkey, data: Vec<u8>) -> Self {
+        Self {
+            key,
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: token::ID,
+            executable: false,
+        }
+    }
+
+    /// An account of the program holding `account`, padded to `len` bytes.
+    pub fn program<T: AccountSerialize>(key: Pubkey, account: &T, len: usize) -> Self {
+        let mut data = Vec::with_capacity(len);
+        account.try_serialize(&mut data).unwrap();
+        data.resize(len, 0);
+        Self {
+            key,
+            lamports: Rent::default().minimum_balance(len),
+            data,
+            owner: crate::ID,
+            executable: false,
+        }
+    }
+}
+
+/// Runs the instruction built from `ix_accounts` and `ix_data` on `accounts`, given in the order
+/// of the instruction's accounts. Signer and writable flags come from the instruction. The lamports,
+/// data and owner the program leaves in each account are written back to `accounts`.
+pub fn process_instruction(
+    ix_accounts: impl ToAccountMetas,
+    ix_data: impl InstructionData,
+    accounts: &mut [TestAccount],
+) -> ProgramResult {
+    init_syscall_stubs();
+    INVOKED_INSTRUCTIONS.with(|ixs| ixs.borrow_mut().clear());
+    let metas = ix_accounts.to_account_metas(None);
+    assert_eq!(metas.len(), accounts.len());
+
+    // Serialized like the runtime does for the BPF loader, with room to grow every account
+    let mut input = vec![];
+    input.extend_from_slice(&(accounts.len() as u64).to_le_bytes());
+    for (meta, account) in metas.iter().zip(accounts.iter()) {
+        assert_eq!(meta.pubkey, account.key);
+        input.extend_from_slice(&[
+            u8::MAX,
+            meta.is_signer.into(),
+            meta.is_writable.into(),
+            account.executable.into(),
+        ]);
+        input.extend_from_slice(&[0; 4]);
+        input.extend_from_slice(account.key.as_ref());
+        input.extend_from_slice(account.owner.as_ref());
+        input.extend_from_slice(&account.lamports.to_le_bytes());
+        input.extend_from_slice(&(account.data.len() as u64).to_le_bytes());
+        input.extend_from_slice(&account.data);
+        input.resize(input.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+        input.resize(input.len().next_multiple_of(8), 0);
+        input.extend_from_slice(&0u64.to_le_bytes());
+    }
+    let ix_data = ix_data.data();
+    input.extend_from_slice(&(ix_data.len() as u64).to_le_bytes());
+    input.extend_from_slice(&ix_data);
+    input.extend_from_slice(crate::ID.as_ref());
+
+    // Aligned like the runtime's input region
+    let mut aligned_input = vec![0u64; input.len().div_ceil(8)];
+    let input_ptr = aligned_input.as_mut_ptr() as *mut u8;
+    unsafe { std::ptr::copy_nonoverlapping(input.as_ptr(), input_ptr, input.len()) };
+
+    let (program_id, infos, ix_data) = unsafe { deserialize(input_ptr) };
+    let result = crate::entry(program_id, &infos, ix_data);
+
+    for (info, account) in infos.iter().zip(accounts.iter_mut()) {
+        account.lamports = info.lamports();
+        account.data = info.data.borrow().to_vec();
+        account.owner = *info.owner;
+    }
+
+    result
+}
@@ -0,0 +1,326 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        ed25519_program, instruction::Instruction,
+        sysvar::instructions::load_instruction_at_checked,
+    },
+    system_program,
+};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions},
+    state::Mint,
+};
+
+use crate::error::ErrorCode;
+
+/// Returns the fee the token program withholds in the current epoch when transferring `amount` of
+/// `mint`. Always 0 for SPL Token mints and Token-2022 mints without the transfer fee extension.
+/// The vault and total_amount_claimed are always debited the full `amount`, the claimant is credited
+/// `amount` less the fee.
+#[allow(clippy::result_large_err)]
+pub fn get_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+
+    match mint.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => Ok(transfer_fee_config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(ErrorCode::ArithmeticError)?),
+        Err(_) => Ok(0),
+    }
+}
+
+/// Grows the program owned `account` to `new_len` bytes, zeroing the new bytes, with `payer` topping
+/// up its rent. Migrates accounts created before fields were appended to their type.
+#[allow(clippy::result_large_err)]
+pub(crate) fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    require!(account.data_len() < new_len, ErrorCode::AlreadyMigrated);
+
+    let rent_top_up = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if rent_top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_top_up,
+        )?;
+    }
+
+    account.realloc(new_len, true)?;
+
+    Ok(())
+}
+
+/// Size of an attestation message: distributor, claimant and expiry.
+pub const ATTESTATION_MESSAGE_LEN: usize = 32 + 32 + 8;
+
+/// Size of the offsets of a single signature in an Ed25519 program instruction.
+const ED25519_SIGNATURE_OFFSETS_LEN: usize = 14;
+/// Offsets in an Ed25519 program instruction referring to its own data.
+const ED25519_CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Message the attestation signer of `distributor` signs to let `claimant` claim until `expiry_ts`.
+pub fn attestation_message(
+    distributor: &Pubkey,
+    claimant: &Pubkey,
+    expiry_ts: i64,
+) -> [u8; ATTESTATION_MESSAGE_LEN] {
+    let mut message = [0; ATTESTATION_MESSAGE_LEN];
+    message[..32].copy_from_slice(distributor.as_ref());
+    message[32..64].copy_from_slice(claimant.as_ref());
+    message[64..].copy_from_slice(&expiry_ts.to_le_bytes());
+    message
+}
+
+/// Builds the Ed25519 program instruction verifying `signature` of `message` by `signer`.
+/// Must be included in the claim transaction for distributors with an attestation signer.
+pub fn new_attestation_instruction(
+    signer: &Pubkey,
+    signature: &[u8; 64],
+    message: &[u8],
+) -> Instruction {
+    let header_len = 2 + ED25519_SIGNATURE_OFFSETS_LEN;
+    let public_key_offset = header_len as u16;
+    let signature_offset = public_key_offset + 32;
+    let message_data_offset = signature_offset + 64;
+
+    let mut data = Vec::with_capacity(header_len + 32 + 64 + message.len());
+    data.extend_from_slice(&[1, 0]);
+    for offset in [
+        signature_offset,
+        ED25519_CURRENT_INSTRUCTION,
+        public_key_offset,
+        ED25519_CURRENT_INSTRUCTION,
+        message_data_offset,
+        message.len() as u16,
+        ED25519_CURRENT_INSTRUCTION,
+    ] {
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Returns the message signed by `signer` in the data of an Ed25519 program instruction.
+/// Only single signature instructions whose public key, signature and message are all in their own
+/// data are accepted, so the signature the Ed25519 program verified is the one read here.
+fn ed25519_signed_message<'a>(data: &'a [u8], signer: &Pubkey) -> Option<&'a [u8]> {
+    if data.len() < 2 + ED25519_SIGNATURE_OFFSETS_LEN || data[0] != 1 {
+        return None;
+    }
+    let offsets: Vec<u16> = data[2..2 + ED25519_SIGNATURE_OFFSETS_LEN]
+        .chunks_exact(2)
+        .map(|offset| u16::from_le_bytes([offset[0], offset[1]]))
+        .collect();
+    let (public_key_offset, message_data_offset, message_data_size) = (
+        offsets[2] as usize,
+        offsets[4] as usize,
+        offsets[5] as usize,
+    );
+    if offsets[1] != ED25519_CURRENT_INSTRUCTION
+        || offsets[3] != ED25519_CURRENT_INSTRUCTION
+        || offsets[6] != ED25519_CURRENT_INSTRUCTION
+    {
+        return None;
+    }
+
+    let public_key = data.get(public_key_offset..public_key_offset + 32)?;
+    if public_key != signer.as_ref() {
+        return None;
+    }
+    data.get(message_data_offset..message_data_offset + message_data_size)
+}
+
+/// Returns the expiry of an attestation by `signer` for `claimant` to claim from `distributor`,
+/// if the Ed25519 program instruction data holds one.
+fn attestation_expiry(
+    data: &[u8],
+    signer: &Pubkey,
+    distributor: &Pubkey,
+    claimant: &Pubkey,
+) -> Option<i64> {
+    let message = ed25519_signed_message(data, signer)?;
+    if message.len() != ATTESTATION_MESSAGE_LEN
+        || &message[..32] != distributor.as_ref()
+        || &message[32..64] != claimant.as_ref()
+    {
+        return None;
+    }
+    Some(i64::from_le_bytes(message[64..].try_into().ok()?))
+}
+
+/// Checks the transaction holds an unexpired attestation by `signer` for `claimant` to claim from
+/// `distributor`, in an Ed25519 program instruction found through the instructions sysvar.
+/// The Ed25519 program fails the transaction if the signature is invalid.
+#[allow(clippy::result_large_err)]
+pub fn verify_attestation(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    distributor: &Pubkey,
+    claimant: &Pubkey,
+    curr_ts: i64,
+) -> Result<()> {
+    let mut index = 0;
+    while let Ok(instruction) = load_instruction_at_checked(index, instructions_sysvar) {
+        index += 1;
+        if instruction.program_id != ed25519_program::ID {
+            continue;
+        }
+        if let Some(expiry_ts) =
+            attestation_expiry(&instruction.data, signer, distributor, claimant)
+        {
+            require!(curr_ts < expiry_ts, ErrorCode::AttestationExpired);
+            return Ok(());
+        }
+    }
+
+    Err(ErrorCode::MissingAttestation.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_spl::token_2022::spl_token_2022::extension::{
+        ExtensionType, StateWithExtensionsMut,
+    };
+
+    use super::*;
+    use crate::test_utils::set_unix_timestamp;
+
+    #[test]
+    fn test_attestation_expiry() {
+        let (signer, distributor, claimant) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let message = attestation_message(&distributor, &claimant, 1_000);
+        let instruction = new_attestation_instruction(&signer, &[7; 64], &message);
+
+        assert_eq!(
+            attestation_expiry(&instruction.data, &signer, &distributor, &claimant),
+            Some(1_000)
+        );
+        // signed by someone else, or attesting another claimant or distributor
+        assert_eq!(
+            attestation_expiry(&instruction.data, &claimant, &distributor, &claimant),
+            None
+        );
+        assert_eq!(
+            attestation_expiry(&instruction.data, &signer, &distributor, &signer),
+            None
+        );
+        assert_eq!(
+            attestation_expiry(&instruction.data, &signer, &claimant, &claimant),
+            None
+        );
+    }
+
+    #[test]
+    fn test_attestation_must_be_in_instruction() {
+        let (signer, distributor, claimant) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let message = attestation_message(&distributor, &claimant, 1_000);
+        let mut data = new_attestation_instruction(&signer, &[7; 64], &message).data;
+
+        // the message is read from another instruction than the one holding it
+        data[14..16].copy_from_slice(&0u16.to_le_bytes());
+        assert_eq!(
+            attestation_expiry(&data, &signer, &distributor, &claimant),
+            None
+        );
+
+        // more than one signature
+        let mut data = new_attestation_instruction(&signer, &[7; 64], &message).data;
+        data[0] = 2;
+        assert_eq!(
+            attestation_expiry(&data, &signer, &distributor, &claimant),
+            None
+        );
+
+        // truncated message
+        let data = new_attestation_instruction(&signer, &[7; 64], &message[..64]).data;
+        assert_eq!(
+            attestation_expiry(&data, &signer, &distributor, &claimant),
+            None
+        );
+    }
+
+    fn mint_with_transfer_fee(bps: u16, maximum_fee: u64) -> Vec<u8> {
+        let mut data =
+            vec![0; ExtensionType::get_account_len::<Mint>(&[ExtensionType::TransferFeeConfig])];
+        let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        let transfer_fee_config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+        transfer_fee_config
+            .newer_transfer_fee
+            .transfer_fee_basis_points = bps.into();
+        transfer_fee_config.newer_transfer_fee.maximum_fee = maximum_fee.into();
+        state.base.is_initialized = true;
+        state.pack_base();
+        state.init_account_type().unwrap();
+        data
+    }
+
+    #[test]
+    fn test_get_transfer_fee() {
+        set_unix_timestamp(0);
+        let (key, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut lamports = 0;
+        let mut transfer_fee = |mut data: Vec<u8>, amount| {
+            let mint = AccountInfo::new(
+                &key,
+                false,
+                false,
+                &mut lamports,
+                &mut data,
+                &owner,
+                false,
+                0,
+            );
+            get_transfer_fee(&mint, amount).unwrap()
+        };
+
+        let mut spl_token_mint = vec![0; Mint::LEN];
+        Mint::pack(
+            Mint {
+                is_initialized: true,
+                ..Mint::default()
+            },
+            &mut spl_token_mint,
+        )
+        .unwrap();
+        assert_eq!(transfer_fee(spl_token_mint, 10_000), 0);
+        assert_eq!(transfer_fee(mint_with_transfer_fee(0, u64::MAX), 10_000), 0);
+        assert_eq!(
+            transfer_fee(mint_with_transfer_fee(50, u64::MAX), 10_000),
+            50
+        );
+        // rounds up, like the token program
+        assert_eq!(
+            transfer_fee(mint_with_transfer_fee(50, u64::MAX), 10_001),
+            51
+        );
+        assert_eq!(transfer_fee(mint_with_transfer_fee(50, 20), 10_000), 20);
+    }
+}
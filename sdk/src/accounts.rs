@@ -0,0 +1,214 @@
+//! Fetching and deserializing the program accounts. Missing accounts that are only created on
+//! demand, like claim statuses, are returned as `None`; a missing distributor is an error.
+
+use anchor_lang::{error::ErrorCode, AccountDeserialize, Discriminator};
+use merkle_distributor::state::{
+    claim_bitmap::ClaimBitmap, claim_recovery::ClaimRecovery, claim_status::ClaimStatus,
+    cumulative_claim_status::CumulativeClaimStatus, distributor_mint::DistributorMint,
+    merkle_distributor::MerkleDistributor,
+};
+use solana_program::pubkey::Pubkey;
+use solana_rpc_client::rpc_client::RpcClient;
+
+use crate::error::{Result, SdkError};
+
+/// Deserializes the data of an Anchor account of the program, checking its discriminator.
+pub fn deserialize_account<T: AccountDeserialize>(pubkey: &Pubkey, data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..]).map_err(|e| SdkError::AccountDeserializeError(*pubkey, e))
+}
+
+/// Deserializes the data of a [ClaimBitmap], which is zero copy.
+pub fn deserialize_claim_bitmap(pubkey: &Pubkey, data: &[u8]) -> Result<ClaimBitmap> {
+    if data.len() < ClaimBitmap::LEN {
+        return Err(SdkError::AccountDeserializeError(
+            *pubkey,
+            ErrorCode::AccountDidNotDeserialize.into(),
+        ));
+    }
+    if data[..8] != ClaimBitmap::discriminator() {
+        return Err(SdkError::AccountDeserializeError(
+            *pubkey,
+            ErrorCode::AccountDiscriminatorMismatch.into(),
+        ));
+    }
+    Ok(bytemuck::pod_read_unaligned(&data[8..ClaimBitmap::LEN]))
+}
+
+fn fetch_account_data(client: &RpcClient, pubkey: &Pubkey) -> Result<Option<Vec<u8>>> {
+    Ok(client
+        .get_account_with_commitment(pubkey, client.commitment())?
+        .value
+        .map(|account| account.data))
+}
+
+pub fn fetch_distributor(client: &RpcClient, pubkey: &Pubkey) -> Result<MerkleDistributor> {
+    let data = fetch_account_data(client, pubkey)?.ok_or(SdkError::AccountNotFound(*pubkey))?;
+    deserialize_account(pubkey, &data)
+}
+
+pub fn fetch_claim_status(client: &RpcClient, pubkey: &Pubkey) -> Result<Option<ClaimStatus>> {
+    fetch_account_data(client, pubkey)?
+        .map(|data| deserialize_account(pubkey, &data))
+        .transpose()
+}
+
+pub fn fetch_cumulative_claim_status(
+    client: &RpcClient,
+    pubkey: &Pubkey,
+) -> Result<Option<CumulativeClaimStatus>> {
+    fetch_account_data(client, pubkey)?
+        .map(|data| deserialize_account(pubkey, &data))
+        .transpose()
+}
+
+pub fn fetch_claim_bitmap(client: &RpcClient, pubkey: &Pubkey) -> Result<Option<ClaimBitmap>> {
+    fetch_account_data(client, pubkey)?
+        .map(|data| deserialize_claim_bitmap(pubkey, &data))
+        .transpose()
+}
+
+pub fn fetch_distributor_mint(
+    client: &RpcClient,
+    pubkey: &Pubkey,
+) -> Result<Option<DistributorMint>> {
+    fetch_account_data(client, pubkey)?
+        .map(|data| deserialize_account(pubkey, &data))
+        .transpose()
+}
+
+pub fn fetch_claim_recovery(client: &RpcClient, pubkey: &Pubkey) -> Result<Option<ClaimRecovery>> {
+    fetch_account_data(client, pubkey)?
+        .map(|data| deserialize_account(pubkey, &data))
+        .transpose()
+}
+
+/// Returns the token program (SPL Token or Token-2022) that owns `mint`.
+pub fn fetch_token_program(client: &RpcClient, mint: &Pubkey) -> Result<Pubkey> {
+    Ok(client
+        .get_account_with_commitment(mint, client.commitment())?
+        .value
+        .ok_or(SdkError::AccountNotFound(*mint))?
+        .owner)
+}
+
+/// Async versions of the fetch helpers, for the nonblocking [RpcClient](solana_rpc_client::nonblocking::rpc_client::RpcClient).
+pub mod nonblocking {
+    use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+
+    use super::*;
+
+    async fn fetch_account_data(client: &RpcClient, pubkey: &Pubkey) -> Result<Option<Vec<u8>>> {
+        Ok(client
+            .get_account_with_commitment(pubkey, client.commitment())
+            .await?
+            .value
+            .map(|account| account.data))
+    }
+
+    pub async fn fetch_distributor(
+        client: &RpcClient,
+        pubkey: &Pubkey,
+    ) -> Result<MerkleDistributor> {
+        let data = fetch_account_data(client, pubkey)
+            .await?
+            .ok_or(SdkError::AccountNotFound(*pubkey))?;
+        deserialize_account(pubkey, &data)
+    }
+
+    pub async fn fetch_claim_status(
+        client: &RpcClient,
+        pubkey: &Pubkey,
+    ) -> Result<Option<ClaimStatus>> {
+        fetch_account_data(client, pubkey)
+            .await?
+            .map(|data| deserialize_account(pubkey, &data))
+            .transpose()
+    }
+
+    pub async fn fetch_cumulative_claim_status(
+        client: &RpcClient,
+        pubkey: &Pubkey,
+    ) -> Result<Option<CumulativeClaimStatus>> {
+        fetch_account_data(client, pubkey)
+            .await?
+            .map(|data| deserialize_account(pubkey, &data))
+            .transpose()
+    }
+
+    pub async fn fetch_claim_bitmap(
+        client: &RpcClient,
+        pubkey: &Pubkey,
+    ) -> Result<Option<ClaimBitmap>> {
+        fetch_account_data(client, pubkey)
+            .await?
+            .map(|data| deserialize_claim_bitmap(pubkey, &data))
+            .transpose()
+    }
+
+    pub async fn fetch_distributor_mint(
+        client: &RpcClient,
+        pubkey: &Pubkey,
+    ) -> Result<Option<DistributorMint>> {
+        fetch_account_data(client, pubkey)
+            .await?
+            .map(|data| deserialize_account(pubkey, &data))
+            .transpose()
+    }
+
+    pub async fn fetch_claim_recovery(
+        client: &RpcClient,
+        pubkey: &Pubkey,
+    ) -> Result<Option<ClaimRecovery>> {
+        fetch_account_data(client, pubkey)
+            .await?
+            .map(|data| deserialize_account(pubkey, &data))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::AccountSerialize;
+    use bytemuck::Zeroable;
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize_account() {
+        let pubkey = Pubkey::new_unique();
+        let claim_status = ClaimStatus {
+            claimant: Pubkey::new_unique(),
+            unlocked_amount: 10,
+            ..ClaimStatus::default()
+        };
+        let mut data = vec![];
+        claim_status.try_serialize(&mut data).unwrap();
+
+        let deserialized: ClaimStatus = deserialize_account(&pubkey, &data).unwrap();
+        assert_eq!(deserialized.claimant, claim_status.claimant);
+        assert_eq!(deserialized.unlocked_amount, 10);
+
+        // wrong account type
+        assert!(matches!(
+            deserialize_account::<MerkleDistributor>(&pubkey, &data),
+            Err(SdkError::AccountDeserializeError(p, _)) if p == pubkey
+        ));
+    }
+
+    #[test]
+    fn test_deserialize_claim_bitmap() {
+        let pubkey = Pubkey::new_unique();
+        let mut claim_bitmap = ClaimBitmap::zeroed();
+        claim_bitmap.set_claimed(3);
+
+        let mut data = ClaimBitmap::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&claim_bitmap));
+        assert!(deserialize_claim_bitmap(&pubkey, &data)
+            .unwrap()
+            .is_claimed(3));
+
+        data[0] ^= 1;
+        assert!(deserialize_claim_bitmap(&pubkey, &data).is_err());
+        assert!(deserialize_claim_bitmap(&pubkey, &data[..8]).is_err());
+    }
+}
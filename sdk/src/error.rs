@@ -0,0 +1,26 @@
+use anchor_lang::prelude::Pubkey;
+use solana_rpc_client_api::client_error::Error as RpcError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SdkError {
+    #[error("Rpc Error: {0}")]
+    RpcError(#[from] RpcError),
+
+    #[error("Account {0} not found")]
+    AccountNotFound(Pubkey),
+
+    #[error("Failed deserializing account {0}: {1}")]
+    AccountDeserializeError(Pubkey, anchor_lang::error::Error),
+
+    #[error("Proof not found for claimant {0}")]
+    ProofNotFound(Pubkey),
+
+    #[error("Leaf of claimant {0} is not indexed")]
+    LeafNotIndexed(Pubkey),
+
+    #[error("Leaf of claimant {0} does not commit to a mint")]
+    LeafNotMultiMint(Pubkey),
+}
+
+pub type Result<T> = std::result::Result<T, SdkError>;
@@ -0,0 +1,1265 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use jito_merkle_tree::tree_node::TreeNode;
+use merkle_distributor::{
+    instructions::NewDistributorParams,
+    state::{
+        claim_bitmap::ClaimBitmap, claim_fee::ClaimFee, closed_claims::ClosedClaims,
+        distributor_mint::DistributorMint,
+    },
+    utils::{attestation_message, new_attestation_instruction},
+};
+use solana_program::{
+    instruction::Instruction, pubkey::Pubkey, system_program, sysvar::instructions,
+};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+use crate::{
+    error::{Result, SdkError},
+    pda::{
+        get_claim_bitmap_pda, get_claim_recovery_pda, get_claim_status_pda, get_closed_claims_pda,
+        get_cumulative_claim_status_pda, get_distributor_mint_pda, get_merkle_distributor_pda,
+        get_multi_mint_claim_status_pda, get_sol_vault_pda, get_token_vault,
+    },
+};
+
+/// Builds the instructions of a single distributor, identified by its mint and version.
+/// Native SOL distributors use [Pubkey::default] as their mint, see [new_native](Self::new_native).
+#[derive(Clone, Copy, Debug)]
+pub struct DistributorInstructions {
+    pub program_id: Pubkey,
+    pub mint: Pubkey,
+    pub version: u64,
+    /// SPL Token or Token-2022 program owning the mint, the system program for native SOL
+    pub token_program: Pubkey,
+    pub distributor: Pubkey,
+    /// Token vault, or SOL vault for native SOL
+    pub token_vault: Pubkey,
+    /// Claim fee of the distributor, new claims pass the accounts it is paid to.
+    /// See [with_claim_fee](Self::with_claim_fee)
+    pub claim_fee: ClaimFee,
+    pub fee_receiver: Pubkey,
+}
+
+impl DistributorInstructions {
+    pub fn new(program_id: Pubkey, mint: Pubkey, version: u64, token_program: Pubkey) -> Self {
+        let (distributor, _bump) = get_merkle_distributor_pda(&program_id, &mint, version);
+        Self {
+            program_id,
+            mint,
+            version,
+            token_program,
+            distributor,
+            token_vault: get_token_vault(&distributor, &mint, &token_program),
+            claim_fee: ClaimFee::None,
+            fee_receiver: Pubkey::default(),
+        }
+    }
+
+    /// Sets the claim fee of the distributor, as read from its account.
+    pub fn with_claim_fee(mut self, claim_fee: ClaimFee, fee_receiver: Pubkey) -> Self {
+        self.claim_fee = claim_fee;
+        self.fee_receiver = fee_receiver;
+        self
+    }
+
+    /// Instructions of the native SOL distributor `version`.
+    pub fn new_native(program_id: Pubkey, version: u64) -> Self {
+        let mint = Pubkey::default();
+        let (distributor, _bump) = get_merkle_distributor_pda(&program_id, &mint, version);
+        Self {
+            program_id,
+            mint,
+            version,
+            token_program: system_program::ID,
+            distributor,
+            token_vault: get_sol_vault_pda(&program_id, &distributor).0,
+            claim_fee: ClaimFee::None,
+            fee_receiver: Pubkey::default(),
+        }
+    }
+
+    /// Whether the distributor distributes native SOL.
+    pub fn is_native(&self) -> bool {
+        self.mint == Pubkey::default()
+    }
+
+    /// Associated token account of `owner` for the distributed mint.
+    pub fn associated_token_account(&self, owner: &Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(owner, &self.mint, &self.token_program)
+    }
+
+    /// Associated token account of the fee receiver, token claim fees are paid to.
+    pub fn fee_receiver_token_account(&self) -> Pubkey {
+        self.associated_token_account(&self.fee_receiver)
+    }
+
+    /// Fee receiver wallet and token account passed to new claims, each only if the claim fee is
+    /// paid to it.
+    fn fee_receiver_accounts(&self) -> (Option<Pubkey>, Option<Pubkey>) {
+        match self.claim_fee {
+            ClaimFee::None => (None, None),
+            ClaimFee::Lamports { .. } => (Some(self.fee_receiver), None),
+            ClaimFee::Bps { .. } => (None, Some(self.fee_receiver_token_account())),
+        }
+    }
+
+    pub fn claim_status(&self, claimant: &Pubkey) -> Pubkey {
+        get_claim_status_pda(&self.program_id, claimant, &self.distributor).0
+    }
+
+    pub fn cumulative_claim_status(&self, claimant: &Pubkey) -> Pubkey {
+        get_cumulative_claim_status_pda(&self.program_id, claimant, &self.distributor).0
+    }
+
+    pub fn claim_recovery(&self, claimant: &Pubkey) -> Pubkey {
+        get_claim_recovery_pda(&self.program_id, claimant, &self.distributor).0
+    }
+
+    /// Chunk of the closed claims recording `claimant`.
+    pub fn closed_claims(&self, claimant: &Pubkey) -> Pubkey {
+        self.closed_claims_chunk(ClosedClaims::chunk_index_of(claimant))
+    }
+
+    pub fn closed_claims_chunk(&self, chunk_index: u8) -> Pubkey {
+        get_closed_claims_pda(&self.program_id, &self.distributor, chunk_index).0
+    }
+
+    pub fn claim_bitmap(&self, chunk_index: u64) -> Pubkey {
+        get_claim_bitmap_pda(&self.program_id, &self.distributor, chunk_index).0
+    }
+
+    pub fn distributor_mint(&self, mint_index: u8) -> Pubkey {
+        get_distributor_mint_pda(&self.program_id, &self.distributor, mint_index).0
+    }
+
+    pub fn multi_mint_claim_status(&self, claimant: &Pubkey, mint_index: u8) -> Pubkey {
+        get_multi_mint_claim_status_pda(&self.program_id, claimant, &self.distributor, mint_index).0
+    }
+
+    fn instruction(
+        &self,
+        accounts: impl ToAccountMetas,
+        data: impl InstructionData,
+    ) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: accounts.to_account_metas(None),
+            data: data.data(),
+        }
+    }
+
+    pub fn new_distributor_v2(
+        &self,
+        admin: &Pubkey,
+        clawback_receiver: &Pubkey,
+        params: NewDistributorParams,
+    ) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::NewDistributor {
+                distributor: self.distributor,
+                clawback_receiver: *clawback_receiver,
+                mint: self.mint,
+                token_vault: self.token_vault,
+                admin: *admin,
+                system_program: system_program::ID,
+                associated_token_program: spl_associated_token_account::ID,
+                token_program: self.token_program,
+            },
+            merkle_distributor::instruction::NewDistributorV2 {
+                version: self.version,
+                params,
+            },
+        )
+    }
+
+    pub fn new_distributor_native(
+        &self,
+        admin: &Pubkey,
+        clawback_receiver: &Pubkey,
+        params: NewDistributorParams,
+    ) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::NewDistributorNative {
+                distributor: self.distributor,
+                sol_vault: self.token_vault,
+                clawback_receiver: *clawback_receiver,
+                admin: *admin,
+                system_program: system_program::ID,
+            },
+            merkle_distributor::instruction::NewDistributorNative {
+                version: self.version,
+                params,
+            },
+        )
+    }
+
+    /// Transfers `amount` lamports from the admin into the SOL vault.
+    pub fn fund_distributor_native(&self, admin: &Pubkey, amount: u64) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::FundDistributorNative {
+                distributor: self.distributor,
+                sol_vault: self.token_vault,
+                admin: *admin,
+                system_program: system_program::ID,
+            },
+            merkle_distributor::instruction::FundDistributorNative { amount },
+        )
+    }
+
+    /// Claims the leaf of `node.claimant` from a native SOL distributor, sending the unlocked
+    /// lamports to the claimant.
+    pub fn new_claim_native(&self, node: &TreeNode) -> Result<Instruction> {
+        Ok(self.instruction(
+            merkle_distributor::accounts::NewClaimNative {
+                distributor: self.distributor,
+                claim_status: self.claim_status(&node.claimant),
+                closed_claims: self.closed_claims(&node.claimant),
+                sol_vault: self.token_vault,
+                claimant: node.claimant,
+                system_program: system_program::ID,
+                instructions_sysvar: instructions::ID,
+            },
+            merkle_distributor::instruction::NewClaimNative {
+                amount_unlocked: node.amount_unlocked(),
+                amount_locked: node.amount_locked(),
+                proof: proof(node)?,
+                start_ts: node.start_ts,
+                end_ts: node.end_ts,
+            },
+        ))
+    }
+
+    pub fn claim_locked_native(&self, claimant: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::ClaimLockedNative {
+                distributor: self.distributor,
+                claim_status: self.claim_status(claimant),
+                sol_vault: self.token_vault,
+                claimant: *claimant,
+            },
+            merkle_distributor::instruction::ClaimLockedNative {},
+        )
+    }
+
+    /// Sends every lamport in the SOL vault above its rent exempt minimum to the clawback
+    /// receiver, signed by `claimant`, anyone.
+    pub fn clawback_native(&self, claimant: &Pubkey, clawback_receiver: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::ClawbackNative {
+                distributor: self.distributor,
+                sol_vault: self.token_vault,
+                to: *clawback_receiver,
+                claimant: *claimant,
+            },
+            merkle_distributor::instruction::ClawbackNative {},
+        )
+    }
+
+    /// Transfers `amount` tokens from the admin's `from` token account into the vault.
+    pub fn fund_distributor(&self, admin: &Pubkey, from: &Pubkey, amount: u64) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::FundDistributor {
+                distributor: self.distributor,
+                token_vault: self.token_vault,
+                from: *from,
+                admin: *admin,
+                mint: self.mint,
+                token_program: self.token_program,
+            },
+            merkle_distributor::instruction::FundDistributor { amount },
+        )
+    }
+
+    /// Claims the leaf of `node.claimant`, sending the unlocked tokens to `to`, a token account
+    /// owned by the claimant. The claim fee, if any, is paid by the claimant.
+    pub fn new_claim(&self, node: &TreeNode, to: &Pubkey) -> Result<Instruction> {
+        let (fee_receiver, fee_receiver_token_account) = self.fee_receiver_accounts();
+        Ok(self.instruction(
+            merkle_distributor::accounts::NewClaim {
+                distributor: self.distributor,
+                claim_status: self.claim_status(&node.claimant),
+                closed_claims: self.closed_claims(&node.claimant),
+                from: self.token_vault,
+                to: *to,
+                claimant: node.claimant,
+                mint: self.mint,
+                token_program: self.token_program,
+                system_program: system_program::ID,
+                instructions_sysvar: instructions::ID,
+                fee_receiver,
+                fee_receiver_token_account,
+            },
+            merkle_distributor::instruction::NewClaim {
+                amount_unlocked: node.amount_unlocked(),
+                amount_locked: node.amount_locked(),
+                proof: proof(node)?,
+                start_ts: node.start_ts,
+                end_ts: node.end_ts,
+            },
+        ))
+    }
+
+    /// Claims the leaf of `node.claimant`, sending the unlocked tokens to any token account.
+    pub fn new_claim_to(&self, node: &TreeNode, to: &Pubkey) -> Result<Instruction> {
+        let (fee_receiver, fee_receiver_token_account) = self.fee_receiver_accounts();
+        Ok(self.instruction(
+            merkle_distributor::accounts::NewClaimTo {
+                distributor: self.distributor,
+                claim_status: self.claim_status(&node.claimant),
+                closed_claims: self.closed_claims(&node.claimant),
+                from: self.token_vault,
+                to: *to,
+                claimant: node.claimant,
+                mint: self.mint,
+                token_program: self.token_program,
+                system_program: system_program::ID,
+                instructions_sysvar: instructions::ID,
+                fee_receiver,
+                fee_receiver_token_account,
+            },
+            merkle_distributor::instruction::NewClaimTo {
+                amount_unlocked: node.amount_unlocked(),
+                amount_locked: node.amount_locked(),
+                proof: proof(node)?,
+                start_ts: node.start_ts,
+                end_ts: node.end_ts,
+            },
+        ))
+    }
+
+    /// Claims the leaf of `node.claimant` paid by `payer`, sending the unlocked tokens to the
+    /// claimant's associated token account.
+    pub fn new_claim_on_behalf(&self, node: &TreeNode, payer: &Pubkey) -> Result<Instruction> {
+        let (fee_receiver, fee_receiver_token_account) = self.fee_receiver_accounts();
+        Ok(self.instruction(
+            merkle_distributor::accounts::NewClaimOnBehalf {
+                distributor: self.distributor,
+                claim_status: self.claim_status(&node.claimant),
+                closed_claims: self.closed_claims(&node.claimant),
+                from: self.token_vault,
+                to: self.associated_token_account(&node.claimant),
+                claimant: node.claimant,
+                payer: *payer,
+                mint: self.mint,
+                token_program: self.token_program,
+                system_program: system_program::ID,
+                instructions_sysvar: instructions::ID,
+                fee_receiver,
+                fee_receiver_token_account,
+            },
+            merkle_distributor::instruction::NewClaimOnBehalf {
+                amount_unlocked: node.amount_unlocked(),
+                amount_locked: node.amount_locked(),
+                proof: proof(node)?,
+                start_ts: node.start_ts,
+                end_ts: node.end_ts,
+            },
+        ))
+    }
+
+    /// Claims the indexed leaf of `node.claimant` from a distributor with bitmap claims.
+    pub fn new_claim_indexed(&self, node: &TreeNode, to: &Pubkey) -> Result<Instruction> {
+        let index = node.index.ok_or(SdkError::LeafNotIndexed(node.claimant))?;
+        Ok(self.instruction(
+            merkle_distributor::accounts::NewClaimIndexed {
+                distributor: self.distributor,
+                claim_bitmap: self.claim_bitmap(ClaimBitmap::chunk_index_of(index)),
+                from: self.token_vault,
+                to: *to,
+                claimant: node.claimant,
+                mint: self.mint,
+                token_program: self.token_program,
+                instructions_sysvar: instructions::ID,
+            },
+            merkle_distributor::instruction::NewClaimIndexed {
+                index,
+                amount_unlocked: node.amount_unlocked(),
+                proof: proof(node)?,
+            },
+        ))
+    }
+
+    /// First claim of `node.claimant` from a cumulative distributor.
+    pub fn new_cumulative_claim(&self, node: &TreeNode, to: &Pubkey) -> Result<Instruction> {
+        Ok(self.instruction(
+            merkle_distributor::accounts::NewCumulativeClaim {
+                distributor: self.distributor,
+                claim_status: self.cumulative_claim_status(&node.claimant),
+                from: self.token_vault,
+                to: *to,
+                claimant: node.claimant,
+                mint: self.mint,
+                token_program: self.token_program,
+                system_program: system_program::ID,
+                instructions_sysvar: instructions::ID,
+            },
+            merkle_distributor::instruction::NewCumulativeClaim {
+                cumulative_amount: node.amount_unlocked(),
+                proof: proof(node)?,
+            },
+        ))
+    }
+
+    /// Claims what `node.claimant` earned since their last claim from a cumulative distributor.
+    pub fn claim_cumulative(&self, node: &TreeNode, to: &Pubkey) -> Result<Instruction> {
+        Ok(self.instruction(
+            merkle_distributor::accounts::ClaimCumulative {
+                distributor: self.distributor,
+                claim_status: self.cumulative_claim_status(&node.claimant),
+                from: self.token_vault,
+                to: *to,
+                claimant: node.claimant,
+                mint: self.mint,
+                token_program: self.token_program,
+                instructions_sysvar: instructions::ID,
+            },
+            merkle_distributor::instruction::ClaimCumulative {
+                cumulative_amount: node.amount_unlocked(),
+                proof: proof(node)?,
+            },
+        ))
+    }
+
+    /// Registers `mint`, owned by `token_program`, as mint `mint_index` of a multi-mint distributor.
+    /// The vault, the distributor's associated token account of the mint, must exist beforehand.
+    pub fn add_distributor_mint(
+        &self,
+        admin: &Pubkey,
+        mint_index: u8,
+        mint: &Pubkey,
+        token_program: &Pubkey,
+        max_total_claim: u64,
+    ) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::AddDistributorMint {
+                distributor: self.distributor,
+                distributor_mint: self.distributor_mint(mint_index),
+                mint: *mint,
+                token_vault: get_token_vault(&self.distributor, mint, token_program),
+                admin: *admin,
+                system_program: system_program::ID,
+                associated_token_program: spl_associated_token_account::ID,
+                token_program: *token_program,
+            },
+            merkle_distributor::instruction::AddDistributorMint {
+                mint_index,
+                max_total_claim,
+            },
+        )
+    }
+
+    /// Claims the leaf of `node.claimant` for the mint it commits to from a multi-mint distributor,
+    /// sending the unlocked tokens to `to`, a token account of the claimant for the mint.
+    /// The leaves of every mint of the claimant can be claimed in the same transaction.
+    pub fn new_claim_multi_mint(
+        &self,
+        node: &TreeNode,
+        distributor_mint: &DistributorMint,
+        token_program: &Pubkey,
+        to: &Pubkey,
+    ) -> Result<Instruction> {
+        let mint_index = node
+            .mint_index
+            .ok_or(SdkError::LeafNotMultiMint(node.claimant))?;
+        Ok(self.instruction(
+            merkle_distributor::accounts::NewClaimMultiMint {
+                distributor: self.distributor,
+                distributor_mint: self.distributor_mint(mint_index),
+                claim_status: self.multi_mint_claim_status(&node.claimant, mint_index),
+                from: distributor_mint.token_vault,
+                to: *to,
+                claimant: node.claimant,
+                mint: distributor_mint.mint,
+                token_program: *token_program,
+                system_program: system_program::ID,
+                instructions_sysvar: instructions::ID,
+            },
+            merkle_distributor::instruction::NewClaimMultiMint {
+                mint_index,
+                amount_unlocked: node.amount_unlocked(),
+                amount_locked: node.amount_locked(),
+                proof: proof(node)?,
+            },
+        ))
+    }
+
+    /// Withdraws the unlocked locked tokens of `claimant` for one mint of a multi-mint distributor.
+    pub fn claim_locked_multi_mint(
+        &self,
+        claimant: &Pubkey,
+        distributor_mint: &DistributorMint,
+        token_program: &Pubkey,
+        to: &Pubkey,
+    ) -> Instruction {
+        let mint_index = distributor_mint.mint_index;
+        self.instruction(
+            merkle_distributor::accounts::ClaimLockedMultiMint {
+                distributor: self.distributor,
+                distributor_mint: self.distributor_mint(mint_index),
+                claim_status: self.multi_mint_claim_status(claimant, mint_index),
+                from: distributor_mint.token_vault,
+                to: *to,
+                claimant: *claimant,
+                mint: distributor_mint.mint,
+                token_program: *token_program,
+            },
+            merkle_distributor::instruction::ClaimLockedMultiMint { mint_index },
+        )
+    }
+
+    /// Sends every token in the vault of one mint of a multi-mint distributor to `to`, a token
+    /// account of the clawback receiver's owner, signed by `claimant`, anyone.
+    pub fn clawback_multi_mint(
+        &self,
+        claimant: &Pubkey,
+        distributor_mint: &DistributorMint,
+        token_program: &Pubkey,
+        clawback_receiver: &Pubkey,
+        to: &Pubkey,
+    ) -> Instruction {
+        let mint_index = distributor_mint.mint_index;
+        self.instruction(
+            merkle_distributor::accounts::ClawbackMultiMint {
+                distributor: self.distributor,
+                distributor_mint: self.distributor_mint(mint_index),
+                from: distributor_mint.token_vault,
+                clawback_receiver: *clawback_receiver,
+                to: *to,
+                claimant: *claimant,
+                mint: distributor_mint.mint,
+                token_program: *token_program,
+            },
+            merkle_distributor::instruction::ClawbackMultiMint { mint_index },
+        )
+    }
+
+    /// Ed25519 program instruction carrying the attestation of `claimant` by `signer`, which must
+    /// precede the claim in the same transaction.
+    pub fn attestation(
+        &self,
+        signer: &Pubkey,
+        signature: &[u8; 64],
+        claimant: &Pubkey,
+        expiry_ts: i64,
+    ) -> Instruction {
+        new_attestation_instruction(
+            signer,
+            signature,
+            &attestation_message(&self.distributor, claimant, expiry_ts),
+        )
+    }
+
+    /// Withdraws the unlocked locked tokens of `claimant` to `to`, a token account owned by the claimant.
+    pub fn claim_locked(&self, claimant: &Pubkey, to: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::ClaimLocked {
+                distributor: self.distributor,
+                claim_status: self.claim_status(claimant),
+                from: self.token_vault,
+                to: *to,
+                claimant: *claimant,
+                mint: self.mint,
+                token_program: self.token_program,
+            },
+            merkle_distributor::instruction::ClaimLocked {},
+        )
+    }
+
+    /// Withdraws the unlocked locked tokens of `claimant` to any token account.
+    pub fn claim_locked_to(&self, claimant: &Pubkey, to: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::ClaimLockedTo {
+                distributor: self.distributor,
+                claim_status: self.claim_status(claimant),
+                from: self.token_vault,
+                to: *to,
+                claimant: *claimant,
+                mint: self.mint,
+                token_program: self.token_program,
+            },
+            merkle_distributor::instruction::ClaimLockedTo {},
+        )
+    }
+
+    /// Withdraws the unlocked locked tokens of `claimant` to their associated token account.
+    pub fn claim_locked_on_behalf(&self, claimant: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::ClaimLockedOnBehalf {
+                distributor: self.distributor,
+                claim_status: self.claim_status(claimant),
+                from: self.token_vault,
+                to: self.associated_token_account(claimant),
+                claimant: *claimant,
+                mint: self.mint,
+                token_program: self.token_program,
+            },
+            merkle_distributor::instruction::ClaimLockedOnBehalf {},
+        )
+    }
+
+    pub fn close_claim_status(&self, claimant: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::CloseClaimStatus {
+                distributor: self.distributor,
+                claim_status: self.claim_status(claimant),
+                closed_claims: self.closed_claims(claimant),
+                claimant: *claimant,
+                system_program: system_program::ID,
+            },
+            merkle_distributor::instruction::CloseClaimStatus {},
+        )
+    }
+
+    pub fn new_claim_bitmap(&self, payer: &Pubkey, chunk_index: u64) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::NewClaimBitmap {
+                distributor: self.distributor,
+                claim_bitmap: self.claim_bitmap(chunk_index),
+                payer: *payer,
+                system_program: system_program::ID,
+            },
+            merkle_distributor::instruction::NewClaimBitmap { chunk_index },
+        )
+    }
+
+    pub fn close_claim_bitmap(
+        &self,
+        admin: &Pubkey,
+        chunk_index: u64,
+        receiver: &Pubkey,
+    ) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::CloseClaimBitmap {
+                distributor: self.distributor,
+                claim_bitmap: self.claim_bitmap(chunk_index),
+                admin: *admin,
+                receiver: *receiver,
+            },
+            merkle_distributor::instruction::CloseClaimBitmap {},
+        )
+    }
+
+    pub fn close_closed_claims(
+        &self,
+        admin: &Pubkey,
+        chunk_index: u8,
+        receiver: &Pubkey,
+    ) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::CloseClosedClaims {
+                distributor: self.distributor,
+                closed_claims: self.closed_claims_chunk(chunk_index),
+                admin: *admin,
+                receiver: *receiver,
+            },
+            merkle_distributor::instruction::CloseClosedClaims {},
+        )
+    }
+
+    /// Sends every token in the vault to the clawback receiver, signed by `claimant`, anyone.
+    pub fn clawback(&self, claimant: &Pubkey, clawback_receiver: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::Clawback {
+                distributor: self.distributor,
+                from: self.token_vault,
+                to: *clawback_receiver,
+                claimant: *claimant,
+                system_program: system_program::ID,
+                mint: self.mint,
+                token_program: self.token_program,
+            },
+            merkle_distributor::instruction::Clawback {},
+        )
+    }
+
+    pub fn clawback_surplus(&self, admin: &Pubkey, clawback_receiver: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::ClawbackSurplus {
+                distributor: self.distributor,
+                from: self.token_vault,
+                to: *clawback_receiver,
+                admin: *admin,
+                mint: self.mint,
+                token_program: self.token_program,
+            },
+            merkle_distributor::instruction::ClawbackSurplus {},
+        )
+    }
+
+    /// Revokes the unvested locked tokens of the opened claim of `claimant`, sending them to the
+    /// clawback receiver.
+    pub fn revoke(
+        &self,
+        admin: &Pubkey,
+        claimant: &Pubkey,
+        clawback_receiver: &Pubkey,
+    ) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::Revoke {
+                distributor: self.distributor,
+                claim_status: self.claim_status(claimant),
+                from: self.token_vault,
+                to: *clawback_receiver,
+                claimant: *claimant,
+                admin: *admin,
+                mint: self.mint,
+                token_program: self.token_program,
+            },
+            merkle_distributor::instruction::Revoke {},
+        )
+    }
+
+    /// Opens the unclaimed leaf of `node.claimant`, sending its unlocked tokens to the claimant's
+    /// associated token account, and revokes its unvested locked tokens. The admin pays the rent of
+    /// the claim status.
+    pub fn revoke_unclaimed(
+        &self,
+        admin: &Pubkey,
+        node: &TreeNode,
+        clawback_receiver: &Pubkey,
+    ) -> Result<Instruction> {
+        Ok(self.instruction(
+            merkle_distributor::accounts::RevokeUnclaimed {
+                distributor: self.distributor,
+                claim_status: self.claim_status(&node.claimant),
+                closed_claims: self.closed_claims(&node.claimant),
+                from: self.token_vault,
+                to: *clawback_receiver,
+                claimant_token_account: self.associated_token_account(&node.claimant),
+                claimant: node.claimant,
+                admin: *admin,
+                mint: self.mint,
+                token_program: self.token_program,
+                system_program: system_program::ID,
+            },
+            merkle_distributor::instruction::RevokeUnclaimed {
+                amount_unlocked: node.amount_unlocked(),
+                amount_locked: node.amount_locked(),
+                proof: proof(node)?,
+                start_ts: node.start_ts,
+                end_ts: node.end_ts,
+            },
+        ))
+    }
+
+    /// Moves the claim of `claimant`, who signs and pays, to `new_claimant`, who signs as well.
+    pub fn transfer_claim(&self, claimant: &Pubkey, new_claimant: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::TransferClaim {
+                distributor: self.distributor,
+                claim_status: self.claim_status(claimant),
+                closed_claims: self.closed_claims(claimant),
+                new_claim_status: self.claim_status(new_claimant),
+                claimant: *claimant,
+                new_claimant: *new_claimant,
+                system_program: system_program::ID,
+            },
+            merkle_distributor::instruction::TransferClaim {},
+        )
+    }
+
+    pub fn propose_claim_recovery(
+        &self,
+        admin: &Pubkey,
+        claimant: &Pubkey,
+        new_claimant: &Pubkey,
+    ) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::ProposeClaimRecovery {
+                distributor: self.distributor,
+                claim_status: self.claim_status(claimant),
+                claim_recovery: self.claim_recovery(claimant),
+                claimant: *claimant,
+                admin: *admin,
+                system_program: system_program::ID,
+            },
+            merkle_distributor::instruction::ProposeClaimRecovery {
+                new_claimant: *new_claimant,
+            },
+        )
+    }
+
+    pub fn cancel_claim_recovery(&self, admin: &Pubkey, claimant: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::CancelClaimRecovery {
+                distributor: self.distributor,
+                claim_recovery: self.claim_recovery(claimant),
+                admin: *admin,
+            },
+            merkle_distributor::instruction::CancelClaimRecovery {},
+        )
+    }
+
+    /// Executes the claim recovery of `claimant` to `new_claimant`, the new claimant it proposed,
+    /// who signs along with the admin.
+    pub fn recover_claim(
+        &self,
+        admin: &Pubkey,
+        claimant: &Pubkey,
+        new_claimant: &Pubkey,
+    ) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::RecoverClaim {
+                distributor: self.distributor,
+                claim_recovery: self.claim_recovery(claimant),
+                claim_status: self.claim_status(claimant),
+                closed_claims: self.closed_claims(claimant),
+                new_claim_status: self.claim_status(new_claimant),
+                claimant: *claimant,
+                new_claimant: *new_claimant,
+                admin: *admin,
+                system_program: system_program::ID,
+            },
+            merkle_distributor::instruction::RecoverClaim {},
+        )
+    }
+
+    pub fn close_distributor(&self, admin: &Pubkey, receiver: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::CloseDistributor {
+                distributor: self.distributor,
+                token_vault: self.token_vault,
+                admin: *admin,
+                receiver: *receiver,
+                token_program: self.token_program,
+            },
+            merkle_distributor::instruction::CloseDistributor {},
+        )
+    }
+
+    pub fn set_admin(&self, admin: &Pubkey, new_admin: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::SetAdmin {
+                distributor: self.distributor,
+                admin: *admin,
+                new_admin: *new_admin,
+            },
+            merkle_distributor::instruction::SetAdmin {},
+        )
+    }
+
+    pub fn accept_admin(&self, pending_admin: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::AcceptAdmin {
+                distributor: self.distributor,
+                pending_admin: *pending_admin,
+            },
+            merkle_distributor::instruction::AcceptAdmin {},
+        )
+    }
+
+    pub fn cancel_pending_admin(&self, admin: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::CancelPendingAdmin {
+                distributor: self.distributor,
+                admin: *admin,
+            },
+            merkle_distributor::instruction::CancelPendingAdmin {},
+        )
+    }
+
+    pub fn set_clawback_receiver(
+        &self,
+        admin: &Pubkey,
+        new_clawback_account: &Pubkey,
+    ) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::SetClawbackReceiver {
+                distributor: self.distributor,
+                new_clawback_account: *new_clawback_account,
+                admin: *admin,
+            },
+            merkle_distributor::instruction::SetClawbackReceiver {},
+        )
+    }
+
+    /// Accepts the pending clawback receiver, signed by `owner`, the owner of the token account.
+    pub fn accept_clawback_receiver(
+        &self,
+        new_clawback_account: &Pubkey,
+        owner: &Pubkey,
+    ) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::AcceptClawbackReceiver {
+                distributor: self.distributor,
+                new_clawback_account: *new_clawback_account,
+                owner: *owner,
+            },
+            merkle_distributor::instruction::AcceptClawbackReceiver {},
+        )
+    }
+
+    /// Proposes the wallet `new_clawback_receiver` as clawback receiver of a native SOL distributor.
+    pub fn set_clawback_receiver_native(
+        &self,
+        admin: &Pubkey,
+        new_clawback_receiver: &Pubkey,
+    ) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::SetClawbackReceiverNative {
+                distributor: self.distributor,
+                new_clawback_receiver: *new_clawback_receiver,
+                admin: *admin,
+            },
+            merkle_distributor::instruction::SetClawbackReceiverNative {},
+        )
+    }
+
+    /// Accepts the pending clawback receiver of a native SOL distributor, signed by the wallet.
+    pub fn accept_clawback_receiver_native(&self, new_clawback_receiver: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::AcceptClawbackReceiverNative {
+                distributor: self.distributor,
+                new_clawback_receiver: *new_clawback_receiver,
+            },
+            merkle_distributor::instruction::AcceptClawbackReceiverNative {},
+        )
+    }
+
+    pub fn cancel_pending_clawback_receiver(&self, admin: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::CancelPendingClawbackReceiver {
+                distributor: self.distributor,
+                admin: *admin,
+            },
+            merkle_distributor::instruction::CancelPendingClawbackReceiver {},
+        )
+    }
+
+    pub fn update_root(
+        &self,
+        admin: &Pubkey,
+        root: [u8; 32],
+        max_total_claim: u64,
+        max_num_nodes: u64,
+    ) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::UpdateRoot {
+                distributor: self.distributor,
+                admin: *admin,
+            },
+            merkle_distributor::instruction::UpdateRoot {
+                root,
+                max_total_claim,
+                max_num_nodes,
+            },
+        )
+    }
+
+    pub fn update_timestamps(
+        &self,
+        admin: &Pubkey,
+        start_vesting_ts: i64,
+        end_vesting_ts: i64,
+        clawback_start_ts: i64,
+    ) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::UpdateTimestamps {
+                distributor: self.distributor,
+                admin: *admin,
+            },
+            merkle_distributor::instruction::UpdateTimestamps {
+                start_vesting_ts,
+                end_vesting_ts,
+                clawback_start_ts,
+            },
+        )
+    }
+
+    pub fn migrate_distributor(&self, payer: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::MigrateDistributor {
+                distributor: self.distributor,
+                payer: *payer,
+                system_program: system_program::ID,
+            },
+            merkle_distributor::instruction::MigrateDistributor {},
+        )
+    }
+
+    pub fn migrate_claim_status(&self, claim_status: &Pubkey, payer: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::MigrateClaimStatus {
+                claim_status: *claim_status,
+                payer: *payer,
+                system_program: system_program::ID,
+            },
+            merkle_distributor::instruction::MigrateClaimStatus {},
+        )
+    }
+
+    pub fn pause(&self, admin: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::Pause {
+                distributor: self.distributor,
+                admin: *admin,
+            },
+            merkle_distributor::instruction::Pause {},
+        )
+    }
+
+    pub fn unpause(&self, admin: &Pubkey) -> Instruction {
+        self.instruction(
+            merkle_distributor::accounts::Unpause {
+                distributor: self.distributor,
+                admin: *admin,
+            },
+            merkle_distributor::instruction::Unpause {},
+        )
+    }
+}
+
+fn proof(node: &TreeNode) -> Result<Vec<[u8; 32]>> {
+    node.proof
+        .clone()
+        .ok_or(SdkError::ProofNotFound(node.claimant))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(claimant: Pubkey) -> TreeNode {
+        TreeNode {
+            claimant,
+            proof: Some(vec![[1; 32]]),
+            total_unlocked_staker: 10,
+            total_locked_staker: 20,
+            ..TreeNode::default()
+        }
+    }
+
+    #[test]
+    fn test_new_claim_accounts() {
+        let ixs = DistributorInstructions::new(
+            merkle_distributor::id(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+        );
+        let claimant = Pubkey::new_unique();
+        let to = ixs.associated_token_account(&claimant);
+
+        let ix = ixs.new_claim(&node(claimant), &to).unwrap();
+        let accounts: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+        assert_eq!(
+            accounts,
+            vec![
+                ixs.distributor,
+                ixs.claim_status(&claimant),
+                ixs.closed_claims(&claimant),
+                ixs.token_vault,
+                to,
+                claimant,
+                ixs.mint,
+                ixs.token_program,
+                system_program::ID,
+                instructions::ID,
+                merkle_distributor::id(),
+                merkle_distributor::id(),
+            ]
+        );
+        assert!(ix.accounts[5].is_signer);
+    }
+
+    #[test]
+    fn test_claim_fee_accounts() {
+        let fee_receiver = Pubkey::new_unique();
+        let ixs = DistributorInstructions::new(
+            merkle_distributor::id(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+        );
+        let claimant = Pubkey::new_unique();
+        let to = ixs.associated_token_account(&claimant);
+
+        let ix = ixs
+            .with_claim_fee(ClaimFee::Lamports { amount: 5_000 }, fee_receiver)
+            .new_claim(&node(claimant), &to)
+            .unwrap();
+        assert_eq!(ix.accounts[10].pubkey, fee_receiver);
+        assert!(ix.accounts[10].is_writable);
+        assert_eq!(ix.accounts[11].pubkey, merkle_distributor::id());
+
+        let ixs = ixs.with_claim_fee(ClaimFee::Bps { bps: 100 }, fee_receiver);
+        let ix = ixs.new_claim_to(&node(claimant), &to).unwrap();
+        assert_eq!(ix.accounts[10].pubkey, merkle_distributor::id());
+        assert_eq!(
+            ix.accounts[11].pubkey,
+            get_associated_token_address_with_program_id(
+                &fee_receiver,
+                &ixs.mint,
+                &ixs.token_program
+            )
+        );
+    }
+
+    #[test]
+    fn test_revoke_accounts() {
+        let ixs = DistributorInstructions::new(
+            merkle_distributor::id(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+        );
+        let admin = Pubkey::new_unique();
+        let claimant = Pubkey::new_unique();
+        let clawback_receiver = Pubkey::new_unique();
+
+        let ix = ixs.revoke(&admin, &claimant, &clawback_receiver);
+        assert_eq!(ix.accounts[1].pubkey, ixs.claim_status(&claimant));
+        assert!(ix.accounts[5].is_signer && ix.accounts[5].pubkey == admin);
+
+        let ix = ixs
+            .revoke_unclaimed(&admin, &node(claimant), &clawback_receiver)
+            .unwrap();
+        assert_eq!(ix.accounts[2].pubkey, ixs.closed_claims(&claimant));
+        assert_eq!(ix.accounts[4].pubkey, clawback_receiver);
+        assert_eq!(
+            ix.accounts[5].pubkey,
+            ixs.associated_token_account(&claimant)
+        );
+        assert!(ix.accounts[7].is_signer && ix.accounts[7].is_writable);
+    }
+
+    #[test]
+    fn test_transfer_claim_accounts() {
+        let ixs = DistributorInstructions::new(
+            merkle_distributor::id(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+        );
+        let admin = Pubkey::new_unique();
+        let claimant = Pubkey::new_unique();
+        let new_claimant = Pubkey::new_unique();
+
+        let ix = ixs.transfer_claim(&claimant, &new_claimant);
+        assert_eq!(ix.accounts[1].pubkey, ixs.claim_status(&claimant));
+        assert_eq!(ix.accounts[2].pubkey, ixs.closed_claims(&claimant));
+        assert_eq!(ix.accounts[3].pubkey, ixs.claim_status(&new_claimant));
+        assert!(ix.accounts[4].is_signer && ix.accounts[4].is_writable);
+        assert!(ix.accounts[5].is_signer && ix.accounts[5].pubkey == new_claimant);
+
+        let ix = ixs.recover_claim(&admin, &claimant, &new_claimant);
+        assert_eq!(
+            ix.accounts[1].pubkey,
+            get_claim_recovery_pda(&merkle_distributor::id(), &claimant, &ixs.distributor).0
+        );
+        assert_eq!(ix.accounts[4].pubkey, ixs.claim_status(&new_claimant));
+        assert!(ix.accounts[5].is_writable && ix.accounts[5].pubkey == claimant);
+        assert!(ix.accounts[6].is_signer && ix.accounts[6].pubkey == new_claimant);
+        assert!(ix.accounts[7].is_signer && ix.accounts[7].pubkey == admin);
+    }
+
+    #[test]
+    fn test_native_accounts() {
+        let ixs = DistributorInstructions::new_native(merkle_distributor::id(), 3);
+        assert!(ixs.is_native());
+        assert_eq!(
+            ixs.distributor,
+            get_merkle_distributor_pda(&merkle_distributor::id(), &Pubkey::default(), 3).0
+        );
+        assert_eq!(
+            ixs.token_vault,
+            get_sol_vault_pda(&merkle_distributor::id(), &ixs.distributor).0
+        );
+
+        let claimant = Pubkey::new_unique();
+        let ix = ixs.new_claim_native(&node(claimant)).unwrap();
+        let accounts: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+        assert_eq!(
+            accounts,
+            vec![
+                ixs.distributor,
+                ixs.claim_status(&claimant),
+                ixs.closed_claims(&claimant),
+                ixs.token_vault,
+                claimant,
+                system_program::ID,
+                instructions::ID,
+            ]
+        );
+        assert!(ix.accounts[4].is_signer && ix.accounts[4].is_writable);
+    }
+
+    #[test]
+    fn test_multi_mint_accounts() {
+        let ixs = DistributorInstructions::new(
+            merkle_distributor::id(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+        );
+        let token_program = Pubkey::new_unique();
+        let distributor_mint = DistributorMint {
+            distributor: ixs.distributor,
+            mint_index: 1,
+            mint: Pubkey::new_unique(),
+            token_vault: Pubkey::new_unique(),
+            ..DistributorMint::default()
+        };
+        let claimant = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+
+        let mut node = node(claimant);
+        assert!(matches!(
+            ixs.new_claim_multi_mint(&node, &distributor_mint, &token_program, &to),
+            Err(SdkError::LeafNotMultiMint(c)) if c == claimant
+        ));
+
+        node.mint_index = Some(1);
+        let ix = ixs
+            .new_claim_multi_mint(&node, &distributor_mint, &token_program, &to)
+            .unwrap();
+        let accounts: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+        assert_eq!(
+            accounts,
+            vec![
+                ixs.distributor,
+                get_distributor_mint_pda(&ixs.program_id, &ixs.distributor, 1).0,
+                ixs.multi_mint_claim_status(&claimant, 1),
+                distributor_mint.token_vault,
+                to,
+                claimant,
+                distributor_mint.mint,
+                token_program,
+                system_program::ID,
+                instructions::ID,
+            ]
+        );
+        // claim statuses of different mints don't collide, nor with single mint claims
+        assert_ne!(
+            ixs.multi_mint_claim_status(&claimant, 0),
+            ixs.multi_mint_claim_status(&claimant, 1)
+        );
+        assert_ne!(
+            ixs.multi_mint_claim_status(&claimant, 0),
+            ixs.claim_status(&claimant)
+        );
+    }
+
+    #[test]
+    fn test_claim_errors() {
+        let ixs = DistributorInstructions::new(
+            merkle_distributor::id(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+        );
+        let claimant = Pubkey::new_unique();
+
+        let mut node = node(claimant);
+        assert!(matches!(
+            ixs.new_claim_indexed(&node, &claimant),
+            Err(SdkError::LeafNotIndexed(c)) if c == claimant
+        ));
+
+        node.proof = None;
+        assert!(matches!(
+            ixs.new_claim(&node, &claimant),
+            Err(SdkError::ProofNotFound(c)) if c == claimant
+        ));
+    }
+}
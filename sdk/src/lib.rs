@@ -0,0 +1,14 @@
+//! Client library for the merkle distributor program: instruction builders, PDA derivation and
+//! account fetching, shared by the CLI and the API.
+
+// RPC client errors are large, same as in the program
+#![allow(clippy::result_large_err)]
+
+pub mod accounts;
+pub mod error;
+pub mod instructions;
+pub mod pda;
+
+pub use error::{Result, SdkError};
+pub use instructions::DistributorInstructions;
+pub use merkle_distributor::instructions::NewDistributorParams;
@@ -0,0 +1,12 @@
+pub use jito_merkle_tree::utils::{
+    get_claim_bitmap_pda, get_claim_recovery_pda, get_claim_status_pda, get_closed_claims_pda,
+    get_cumulative_claim_status_pda, get_distributor_mint_pda, get_merkle_distributor_pda,
+    get_multi_mint_claim_status_pda, get_sol_vault_pda,
+};
+use solana_program::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+/// Returns the token vault of `distributor`, its associated token account for `mint`.
+pub fn get_token_vault(distributor: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(distributor, mint, token_program)
+}